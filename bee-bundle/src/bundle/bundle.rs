@@ -9,23 +9,103 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use crate::transaction::{Address, Hash, Transaction, TransactionField, Transactions};
+use crate::{
+    constants::{ADDRESS, IOTA_SUPPLY, LAST_INDEX},
+    transaction::{Address, Hash, Transaction, TransactionField, Transactions},
+};
 
-use std::collections::HashMap;
+use bee_crypto::{CurlP81, Kerl, Sponge};
+use bee_ternary::{T1B1Buf, TritBuf};
+
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug)]
+pub enum BundleError {
+    Empty,
+    InvalidIndex(usize),
+    InvalidLastIndex(usize),
+    InvalidValue(i64),
+    InvalidTrunk(usize),
+    InvalidBranch(usize),
+    InvalidBundleHash(usize),
+    Kerl(<Kerl as Sponge>::Error),
+}
 
 pub struct Bundle(pub(crate) Transactions);
 
 impl Bundle {
+    pub fn validate(transactions: Transactions) -> Result<Self, BundleError> {
+        if transactions.len() == 0 {
+            return Err(BundleError::Empty);
+        }
+
+        let last_index = transactions.len() - 1;
+        let head_trunk = transactions.0[last_index].trunk();
+        let mut kerl = Kerl::default();
+        let mut sum: i64 = 0;
+        let mut next_hash: Option<Hash> = None;
+
+        // Iterating from head to tail so that each transaction hash is known when checking the trunk pointing to it
+        for (index, transaction) in transactions.0.iter().enumerate().rev() {
+            if *transaction.index().to_inner() != index {
+                return Err(BundleError::InvalidIndex(index));
+            }
+            if *transaction.last_index().to_inner() != last_index {
+                return Err(BundleError::InvalidLastIndex(index));
+            }
+
+            sum += *transaction.value().to_inner();
+            if sum.abs() > IOTA_SUPPLY {
+                return Err(BundleError::InvalidValue(sum));
+            }
+
+            if let Some(next_hash) = next_hash {
+                if *transaction.trunk() != next_hash {
+                    return Err(BundleError::InvalidTrunk(index));
+                }
+                if transaction.branch() != head_trunk {
+                    return Err(BundleError::InvalidBranch(index));
+                }
+            }
+
+            next_hash.replace(transaction_hash(transaction));
+        }
+
+        if sum != 0 {
+            return Err(BundleError::InvalidValue(sum));
+        }
+
+        for transaction in &transactions.0 {
+            kerl.absorb(&transaction_essence(transaction))
+                .map_err(BundleError::Kerl)?;
+        }
+
+        let bundle_hash = kerl.squeeze().map_err(BundleError::Kerl)?;
+
+        for (index, transaction) in transactions.0.iter().enumerate() {
+            if bundle_hash.as_i8_slice() != transaction.bundle().as_bytes() {
+                return Err(BundleError::InvalidBundleHash(index));
+            }
+        }
+
+        Ok(Self(transactions))
+    }
+
     // TODO TEST
     pub fn get(&self, index: usize) -> Option<&Transaction> {
         self.0.get(index)
     }
 
-    // TODO TEST
+    /// Returns the number of transactions of the bundle, see also `Bundle::is_empty`.
     pub fn len(&self) -> usize {
         self.0.len()
     }
 
+    /// Returns whether the bundle has no transactions, which is never the case since empty bundles can't be built.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     // TODO TEST
     pub fn hash(&self) -> &Hash {
         // Safe to unwrap because empty bundles can't be built
@@ -54,6 +134,18 @@ impl Bundle {
         self.head().branch()
     }
 
+    /// Returns the total value transferred by the bundle, i.e. the sum of its outputs.
+    ///
+    /// Since the values of a bundle sum to zero, this is also the absolute sum of its inputs. Unlike
+    /// `Bundle::ledger_diff`, outputs to and inputs from a same address do not cancel each other out.
+    pub fn total_value(&self) -> u64 {
+        self.into_iter()
+            .map(|transaction| *transaction.value().to_inner())
+            .filter(|value| *value > 0)
+            .map(|value| value as u64)
+            .sum()
+    }
+
     // TODO TEST
     pub fn ledger_diff(&self) -> HashMap<Address, i64> {
         let mut diff = HashMap::new();
@@ -66,6 +158,44 @@ impl Bundle {
 
         diff
     }
+
+    /// Returns the same entries as `Bundle::ledger_diff`, sorted by address trits so that applying them is
+    /// deterministic.
+    pub fn ledger_diff_sorted(&self) -> Vec<(Address, i64)> {
+        let mut diff = self.ledger_diff().into_iter().collect::<Vec<_>>();
+
+        diff.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        diff
+    }
+}
+
+fn transaction_trits(transaction: &Transaction) -> TritBuf<T1B1Buf> {
+    let mut trits = TritBuf::<T1B1Buf>::zeros(Transaction::trit_len());
+
+    transaction.into_trits_allocated(&mut trits);
+
+    trits
+}
+
+fn transaction_essence(transaction: &Transaction) -> TritBuf<T1B1Buf> {
+    // The essence fields are contiguous, from the address to the last index
+    transaction_trits(transaction)
+        [ADDRESS.trit_offset.start..LAST_INDEX.trit_offset.start + LAST_INDEX.trit_offset.length]
+        .to_buf()
+}
+
+fn transaction_hash(transaction: &Transaction) -> Hash {
+    let mut hash = Hash::zeros();
+
+    hash.0.copy_from_slice(
+        CurlP81::new()
+            .digest(&transaction_trits(transaction))
+            .unwrap_or_else(|_| panic!("Panicked when unwrapping the sponge hash function."))
+            .as_i8_slice(),
+    );
+
+    hash
 }
 
 impl IntoIterator for Bundle {
@@ -88,6 +218,24 @@ impl<'a> IntoIterator for &'a Bundle {
     }
 }
 
+impl fmt::Display for Bundle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Bundle {} ({} transactions)", self.hash(), self.len())?;
+
+        for (index, transaction) in self.into_iter().enumerate() {
+            writeln!(
+                f,
+                "{}: trunk {}, branch {}",
+                index,
+                transaction.trunk(),
+                transaction.branch()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 impl std::ops::Index<usize> for Bundle {
     type Output = Transaction;
 
@@ -99,4 +247,211 @@ impl std::ops::Index<usize> for Bundle {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+
+    use super::*;
+    use crate::transaction::{Index, Nonce, Payload, Tag, Timestamp, TransactionBuilder, Value};
+    use bee_ternary::Btrit;
+
+    fn transaction_builder(index: usize, last_index: usize, value: i64) -> TransactionBuilder {
+        TransactionBuilder::new()
+            .with_payload(Payload::zeros())
+            .with_address(Address::zeros())
+            .with_value(Value::from_inner_unchecked(value))
+            .with_obsolete_tag(Tag::zeros())
+            .with_timestamp(Timestamp::from_inner_unchecked(0))
+            .with_index(Index::from_inner_unchecked(index))
+            .with_last_index(Index::from_inner_unchecked(last_index))
+            .with_tag(Tag::zeros())
+            .with_attachment_ts(Timestamp::from_inner_unchecked(0))
+            .with_attachment_lbts(Timestamp::from_inner_unchecked(0))
+            .with_attachment_ubts(Timestamp::from_inner_unchecked(0))
+            .with_nonce(Nonce::zeros())
+    }
+
+    // Builds a consistent bundle out of the given values, the head trunk and branch being zeros.
+    fn transactions(values: &[i64]) -> Transactions {
+        let last_index = values.len() - 1;
+        let builders = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| transaction_builder(index, last_index, *value))
+            .collect::<Vec<TransactionBuilder>>();
+
+        let mut kerl = Kerl::default();
+        for builder in &builders {
+            let _ = kerl.absorb(&builder.essence());
+        }
+        let mut bundle_hash = Hash::zeros();
+        bundle_hash.0.copy_from_slice(kerl.squeeze().unwrap().as_i8_slice());
+
+        let mut transactions = Vec::new();
+        let mut next_hash: Option<Hash> = None;
+
+        for builder in builders.into_iter().rev() {
+            let (trunk, branch) = match next_hash {
+                Some(next_hash) => (next_hash, Hash::zeros()),
+                None => (Hash::zeros(), Hash::zeros()),
+            };
+            let transaction = builder
+                .with_bundle(bundle_hash)
+                .with_trunk(trunk)
+                .with_branch(branch)
+                .build()
+                .unwrap();
+
+            next_hash.replace(transaction_hash(&transaction));
+            transactions.push(transaction);
+        }
+
+        let mut bundle = Transactions::new();
+        for transaction in transactions.into_iter().rev() {
+            bundle.push(transaction);
+        }
+
+        bundle
+    }
+
+    #[test]
+    fn validate_valid() {
+        let bundle = Bundle::validate(transactions(&[0, 10, -10])).unwrap();
+
+        assert_eq!(bundle.len(), 3);
+        assert!(!bundle.is_empty());
+    }
+
+    #[test]
+    fn display() {
+        let bundle = Bundle::validate(transactions(&[0, 10, -10])).unwrap();
+        let display = bundle.to_string();
+        let lines = display.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], format!("Bundle {} (3 transactions)", bundle.hash()));
+        for (index, transaction) in bundle.into_iter().enumerate() {
+            assert_eq!(
+                lines[index + 1],
+                format!(
+                    "{}: trunk {}, branch {}",
+                    index,
+                    transaction.trunk(),
+                    transaction.branch()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn total_value() {
+        assert_eq!(Bundle::validate(transactions(&[0, 10, -10])).unwrap().total_value(), 10);
+        assert_eq!(Bundle::validate(transactions(&[-15, 10, 5])).unwrap().total_value(), 15);
+        assert_eq!(Bundle::validate(transactions(&[0])).unwrap().total_value(), 0);
+    }
+
+    #[test]
+    fn ledger_diff_sorted() {
+        let address = |trit| {
+            let mut address = Address::zeros();
+            address.0.set(0, trit);
+            address
+        };
+        let mut transactions = transactions(&[-15, 10, 5]);
+        transactions.0[0].address = address(Btrit::PlusOne);
+        transactions.0[1].address = address(Btrit::NegOne);
+        transactions.0[2].address = address(Btrit::Zero);
+
+        assert_eq!(
+            Bundle(transactions).ledger_diff_sorted(),
+            vec![
+                (address(Btrit::NegOne), 10),
+                (address(Btrit::Zero), 5),
+                (address(Btrit::PlusOne), -15)
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_empty() {
+        match Bundle::validate(Transactions::new()) {
+            Err(BundleError::Empty) => (),
+            _ => panic!("Expected an empty bundle error."),
+        }
+    }
+
+    #[test]
+    fn validate_invalid_value() {
+        match Bundle::validate(transactions(&[0, 10, -5])) {
+            Err(BundleError::InvalidValue(sum)) => assert_eq!(sum, 5),
+            _ => panic!("Expected an invalid value error."),
+        }
+    }
+
+    #[test]
+    fn validate_invalid_trunk() {
+        let mut transactions = transactions(&[0, 10, -10]);
+        transactions.0[0].trunk = Hash::zeros();
+
+        match Bundle::validate(transactions) {
+            Err(BundleError::InvalidTrunk(index)) => assert_eq!(index, 0),
+            _ => panic!("Expected an invalid trunk error."),
+        }
+    }
+
+    #[test]
+    fn validate_invalid_branch() {
+        let mut transactions = transactions(&[0, 10, -10]);
+        transactions.0[1].branch = Hash([1; 243]);
+
+        match Bundle::validate(transactions) {
+            Err(BundleError::InvalidBranch(index)) => assert_eq!(index, 1),
+            _ => panic!("Expected an invalid branch error."),
+        }
+    }
+
+    #[test]
+    fn validate_invalid_bundle_hash() {
+        let mut transactions = transactions(&[0, 10, -10]);
+        transactions.0[0].bundle = Hash::zeros();
+
+        match Bundle::validate(transactions) {
+            Err(BundleError::InvalidBundleHash(index)) => assert_eq!(index, 0),
+            _ => panic!("Expected an invalid bundle hash error."),
+        }
+    }
+
+    #[test]
+    fn validate_invalid_non_tail_bundle_hash() {
+        let mut transactions = transactions(&[0, 10, -10]);
+        transactions.0[2].bundle = Hash::zeros();
+        // Keeps the trunks pointing to the modified head.
+        transactions.0[1].trunk = transaction_hash(&transactions.0[2]);
+        transactions.0[0].trunk = transaction_hash(&transactions.0[1]);
+
+        match Bundle::validate(transactions) {
+            Err(BundleError::InvalidBundleHash(index)) => assert_eq!(index, 2),
+            _ => panic!("Expected an invalid bundle hash error."),
+        }
+    }
+
+    #[test]
+    fn validate_invalid_index() {
+        let mut transactions = transactions(&[0, 10, -10]);
+        transactions.0[1].index = Index::from_inner_unchecked(2);
+
+        match Bundle::validate(transactions) {
+            Err(BundleError::InvalidIndex(index)) => assert_eq!(index, 1),
+            _ => panic!("Expected an invalid index error."),
+        }
+    }
+
+    #[test]
+    fn validate_invalid_last_index() {
+        let mut transactions = transactions(&[0, 10, -10]);
+        transactions.0[1].last_index = Index::from_inner_unchecked(3);
+
+        match Bundle::validate(transactions) {
+            Err(BundleError::InvalidLastIndex(index)) => assert_eq!(index, 1),
+            _ => panic!("Expected an invalid last index error."),
+        }
+    }
+}
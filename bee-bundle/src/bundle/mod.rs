@@ -13,6 +13,6 @@ mod bundle;
 mod incoming_bundle_builder;
 mod outgoing_bundle_builder;
 
-pub use bundle::Bundle;
+pub use bundle::{Bundle, BundleError};
 pub use incoming_bundle_builder::{IncomingBundleBuilder, IncomingBundleBuilderError};
 pub use outgoing_bundle_builder::{OutgoingBundleBuilder, OutgoingBundleBuilderError};
@@ -11,7 +11,7 @@
 
 use crate::{
     bundle::Bundle,
-    constants::{IOTA_SUPPLY, PAYLOAD_TRIT_LEN},
+    constants::{IOTA_SUPPLY, SIGNATURE_FRAGMENT_TRIT_LEN},
     transaction::{
         Address, Hash, Index, Payload, Tag, TransactionBuilder, TransactionBuilders, TransactionError,
         TransactionField, Transactions,
@@ -280,7 +280,7 @@ impl<E: Sponge + Default> StagedOutgoingBundleBuilder<E, OutgoingSealed> {
                 .map_err(|_| OutgoingBundleBuilderError::FailedSigningOperation)?;
 
             // Split signature into fragments
-            for fragment in signature.trits().chunks(PAYLOAD_TRIT_LEN) {
+            for fragment in signature.trits().chunks(SIGNATURE_FRAGMENT_TRIT_LEN) {
                 signature_fragments.push(Payload::from_inner_unchecked(fragment.to_owned()));
             }
         }
@@ -421,12 +421,16 @@ mod tests {
             WotsSecurityLevel::Medium => 2,
             WotsSecurityLevel::High => 3,
         };
-        let mut signature = TritBuf::<T1B1Buf>::zeros(PAYLOAD_TRIT_LEN * security);
+        let mut signature = TritBuf::<T1B1Buf>::zeros(SIGNATURE_FRAGMENT_TRIT_LEN * security);
         let mut offset = 0;
         for i in 1..security + 1 {
             let input = bundle.0.get(i).unwrap();
-            signature.copy_raw_bytes(&input.payload.to_inner().to_owned(), offset, PAYLOAD_TRIT_LEN);
-            offset += PAYLOAD_TRIT_LEN;
+            signature.copy_raw_bytes(
+                &input.payload.to_inner().to_owned(),
+                offset,
+                SIGNATURE_FRAGMENT_TRIT_LEN,
+            );
+            offset += SIGNATURE_FRAGMENT_TRIT_LEN;
         }
         let res = WotsSignature::<Kerl>::from_buf(signature)
             .recover_public_key(normalize_hash(bundle.0.get(1).unwrap().bundle.to_inner()).as_i8_slice())
@@ -501,12 +505,16 @@ mod tests {
             .unwrap();
         assert_eq!(address_low.to_inner().as_slice(), res_low.trits());
 
-        let mut signature = TritBuf::<T1B1Buf>::zeros(PAYLOAD_TRIT_LEN * 2);
+        let mut signature = TritBuf::<T1B1Buf>::zeros(SIGNATURE_FRAGMENT_TRIT_LEN * 2);
         let mut offset = 0;
         for i in 2..4 {
             let input = bundle.0.get(i).unwrap();
-            signature.copy_raw_bytes(&input.payload.to_inner().to_owned(), offset, PAYLOAD_TRIT_LEN);
-            offset += PAYLOAD_TRIT_LEN;
+            signature.copy_raw_bytes(
+                &input.payload.to_inner().to_owned(),
+                offset,
+                SIGNATURE_FRAGMENT_TRIT_LEN,
+            );
+            offset += SIGNATURE_FRAGMENT_TRIT_LEN;
         }
         let res_medium = WotsSignature::<Kerl>::from_buf(signature)
             .recover_public_key(normalize_hash(bundle.0.get(2).unwrap().bundle.to_inner()).as_i8_slice())
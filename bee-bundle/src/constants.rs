@@ -65,17 +65,30 @@ macro_rules! offsets_from_previous_field {
 
 pub const IOTA_SUPPLY: i64 = 2_779_530_283_277_761;
 
+/// Number of trits of a transaction.
 pub const TRANSACTION_TRIT_LEN: usize = 8019;
+/// Number of trytes of a transaction.
 pub const TRANSACTION_TRYT_LEN: usize = TRANSACTION_TRIT_LEN / 3; // 2673
+/// Number of bytes of a transaction encoded with 5 trits per byte.
 pub const TRANSACTION_BYTE_LEN: usize = TRANSACTION_TRIT_LEN / 5 + 1; // 1604
 
+/// Number of trits of the payload field.
 pub const PAYLOAD_TRIT_LEN: usize = 6561;
+/// Number of trits of a signature fragment, which fills the payload field of a transaction.
+pub const SIGNATURE_FRAGMENT_TRIT_LEN: usize = PAYLOAD_TRIT_LEN;
+/// Number of trits of the address field.
 pub const ADDRESS_TRIT_LEN: usize = 243;
+/// Number of trits of the value field.
 pub const VALUE_TRIT_LEN: usize = 81;
+/// Number of trits of the tag and obsolete tag fields.
 pub const TAG_TRIT_LEN: usize = 81;
+/// Number of trits of the timestamp fields.
 pub const TIMESTAMP_TRIT_LEN: usize = 27;
+/// Number of trits of the index and last index fields.
 pub const INDEX_TRIT_LEN: usize = 27;
+/// Number of trits of the bundle, trunk and branch hash fields.
 pub const HASH_TRIT_LEN: usize = 243;
+/// Number of trits of the nonce field.
 pub const NONCE_TRIT_LEN: usize = 81;
 
 pub(crate) const PAYLOAD: Field = offsets_from_trits!(0, PAYLOAD_TRIT_LEN);
@@ -140,4 +153,22 @@ mod tests {
 
         assert_eq!(total_tryte_length, TRANSACTION_TRYT_LEN);
     }
+
+    #[test]
+    fn field_lengths_are_consistent() {
+        let total_trit_length = PAYLOAD_TRIT_LEN
+            + ADDRESS_TRIT_LEN
+            + VALUE_TRIT_LEN
+            + 2 * TAG_TRIT_LEN
+            + 4 * TIMESTAMP_TRIT_LEN
+            + 2 * INDEX_TRIT_LEN
+            + 3 * HASH_TRIT_LEN
+            + NONCE_TRIT_LEN;
+
+        assert_eq!(total_trit_length, TRANSACTION_TRIT_LEN);
+        assert_eq!(NONCE.trit_offset.start + NONCE.trit_offset.length, TRANSACTION_TRIT_LEN);
+        assert_eq!(TRANSACTION_TRYT_LEN * 3, TRANSACTION_TRIT_LEN);
+        assert_eq!(TRANSACTION_BYTE_LEN, (TRANSACTION_TRIT_LEN + 4) / 5);
+        assert_eq!(SIGNATURE_FRAGMENT_TRIT_LEN, PAYLOAD.trit_offset.length);
+    }
 }
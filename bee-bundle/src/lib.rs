@@ -17,13 +17,14 @@ mod constants;
 mod transaction;
 
 pub use crate::bundle::{
-    Bundle, IncomingBundleBuilder, IncomingBundleBuilderError, OutgoingBundleBuilder, OutgoingBundleBuilderError,
+    Bundle, BundleError, IncomingBundleBuilder, IncomingBundleBuilderError, OutgoingBundleBuilder,
+    OutgoingBundleBuilderError,
 };
 pub use constants::{
-    ADDRESS_TRIT_LEN, HASH_TRIT_LEN, NONCE_TRIT_LEN, PAYLOAD_TRIT_LEN, TAG_TRIT_LEN, TRANSACTION_BYTE_LEN,
-    TRANSACTION_TRIT_LEN, TRANSACTION_TRYT_LEN,
+    ADDRESS_TRIT_LEN, HASH_TRIT_LEN, INDEX_TRIT_LEN, NONCE_TRIT_LEN, PAYLOAD_TRIT_LEN, SIGNATURE_FRAGMENT_TRIT_LEN,
+    TAG_TRIT_LEN, TIMESTAMP_TRIT_LEN, TRANSACTION_BYTE_LEN, TRANSACTION_TRIT_LEN, TRANSACTION_TRYT_LEN, VALUE_TRIT_LEN,
 };
 pub use transaction::{
     Address, Hash, Index, Nonce, Payload, Tag, Timestamp, Transaction, TransactionBuilder, TransactionBuilders,
-    TransactionError, TransactionField, Transactions, Value,
+    TransactionError, TransactionField, TransactionFieldError, Transactions, Value,
 };
@@ -12,11 +12,23 @@
 use crate::{
     constants::{ADDRESS, INDEX, IOTA_SUPPLY, LAST_INDEX, OBSOLETE_TAG, TIMESTAMP, VALUE},
     transaction::{
-        Address, Hash, Index, Nonce, Payload, Tag, Timestamp, Transaction, TransactionError, TransactionField, Value,
+        Address, Hash, Index, Nonce, Payload, Tag, Timestamp, Transaction, TransactionError, TransactionField,
+        TransactionFieldError, Value,
     },
 };
 
-use bee_ternary::{Btrit, T1B1Buf, TritBuf};
+use bee_ternary::{Btrit, T1B1Buf, TritBuf, TryteBuf};
+
+// Builds a trit field from a tryte string, validating its alphabet and length.
+fn field_from_trytes<F>(trytes: &str) -> Result<F, TransactionFieldError>
+where
+    F: TransactionField,
+    F::Inner: ToOwned<Owned = TritBuf<T1B1Buf>>,
+{
+    let trytes = TryteBuf::try_from_str(trytes).map_err(|_| TransactionFieldError::FieldDeserializationError)?;
+
+    F::try_from_inner(trytes.as_trits().encode::<T1B1Buf>())
+}
 
 #[derive(Default)]
 pub struct TransactionBuilder {
@@ -159,6 +171,38 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn with_payload_trytes(self, payload: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_payload(field_from_trytes(payload)?))
+    }
+
+    pub fn with_address_trytes(self, address: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_address(field_from_trytes(address)?))
+    }
+
+    pub fn with_obsolete_tag_trytes(self, obsolete_tag: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_obsolete_tag(field_from_trytes(obsolete_tag)?))
+    }
+
+    pub fn with_tag_trytes(self, tag: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_tag(field_from_trytes(tag)?))
+    }
+
+    pub fn with_bundle_trytes(self, bundle: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_bundle(field_from_trytes(bundle)?))
+    }
+
+    pub fn with_trunk_trytes(self, trunk: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_trunk(field_from_trytes(trunk)?))
+    }
+
+    pub fn with_branch_trytes(self, branch: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_branch(field_from_trytes(branch)?))
+    }
+
+    pub fn with_nonce_trytes(self, nonce: &str) -> Result<Self, TransactionFieldError> {
+        Ok(self.with_nonce(field_from_trytes(nonce)?))
+    }
+
     pub fn build(self) -> Result<Transaction, TransactionError> {
         let value = self.value.as_ref().ok_or(TransactionError::MissingField("value"))?.0;
         let address = self.address.ok_or(TransactionError::MissingField("address"))?;
@@ -283,4 +327,38 @@ mod tests {
         assert_eq!(tx.index, tx2.index);
         assert_eq!(tx.last_index, tx2.last_index);
     }
+
+    #[test]
+    fn set_fields_from_trytes() {
+        let address = "EQSAUZXULTTYZCLNJNTXQTQHOMOFZERHTCGTXOLTVAHKSA9OGAZDEKECURBRIXIJWNPFCQIOVFVVXJVD9";
+        let builder = TransactionBuilder::new()
+            .with_address_trytes(address)
+            .unwrap()
+            .with_tag_trytes("BEE9TEST9999999999999999999")
+            .unwrap();
+
+        assert_eq!(
+            *builder.address.unwrap().to_inner(),
+            TryteBuf::try_from_str(address).unwrap().as_trits().encode::<T1B1Buf>()
+        );
+        assert_eq!(
+            *builder.tag.unwrap().to_inner(),
+            TryteBuf::try_from_str("BEE9TEST9999999999999999999")
+                .unwrap()
+                .as_trits()
+                .encode::<T1B1Buf>()
+        );
+    }
+
+    #[test]
+    fn reject_invalid_trytes() {
+        assert!(matches!(
+            TransactionBuilder::new().with_address_trytes("EQSAUZXULTTYZCLNJNTXQTQHOMOFZERHTCGTXOLT"),
+            Err(TransactionFieldError::FieldWrongLength)
+        ));
+        assert!(matches!(
+            TransactionBuilder::new().with_tag_trytes("bee9test9999999999999999999"),
+            Err(TransactionFieldError::FieldDeserializationError)
+        ));
+    }
 }
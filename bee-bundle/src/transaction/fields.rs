@@ -15,7 +15,10 @@ use crate::constants::{
 
 use bee_ternary::{T1B1Buf, TritBuf, Trits, T1B1};
 
-use std::{cmp::PartialEq, fmt, hash};
+use std::{
+    cmp::{self, PartialEq},
+    fmt, hash,
+};
 
 #[derive(Debug)]
 pub enum TransactionFieldError {
@@ -88,6 +91,11 @@ impl Address {
         Self(TritBuf::zeros(ADDRESS.trit_offset.length))
     }
 
+    /// Returns whether all trits of the address are zero.
+    pub fn is_null(&self) -> bool {
+        self.0.as_i8_slice().iter().all(|trit| *trit == 0)
+    }
+
     pub fn trit_len() -> usize {
         ADDRESS_TRIT_LEN
     }
@@ -95,6 +103,19 @@ impl Address {
 
 impl Eq for Address {}
 
+impl PartialOrd for Address {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Addresses are ordered by their trits, which is the canonical order of ledger diffs and snapshot states.
+impl Ord for Address {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.as_i8_slice().cmp(other.0.as_i8_slice())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Value(pub(crate) i64);
 
@@ -137,21 +158,30 @@ impl Index {
 
 #[derive(Copy, Clone)]
 // TODO pub ?
-pub struct Hash(pub [i8; 243]);
+pub struct Hash(pub [i8; HASH_TRIT_LEN]);
 
 impl Hash {
     pub fn zeros() -> Self {
-        Self([0; 243])
+        Self([0; HASH_TRIT_LEN])
     }
 
     pub fn as_bytes(&self) -> &[i8] {
         &self.0
     }
 
+    /// Returns whether all trits of the hash are zero.
+    pub fn is_null(&self) -> bool {
+        self.0.iter().all(|trit| *trit == 0)
+    }
+
     pub fn as_trits(&self) -> &Trits<T1B1> {
-        unsafe { Trits::from_raw_unchecked(self.as_bytes(), 243) }
+        unsafe { Trits::from_raw_unchecked(self.as_bytes(), HASH_TRIT_LEN) }
     }
 
+    /// Returns the number of trailing zero trits of the hash.
+    ///
+    /// A hash satisfies a minimum weight magnitude `mwm` if and only if `weight() >= mwm`, i.e. a weight equal to the
+    /// minimum weight magnitude is sufficient.
     pub fn weight(&self) -> u8 {
         let mut weight = 0u8;
 
@@ -212,7 +242,7 @@ impl TransactionField for Hash {
     }
 
     fn trit_len() -> usize {
-        243
+        HASH_TRIT_LEN
     }
 
     fn try_from_inner(buf: <Self::Inner as ToOwned>::Owned) -> Result<Self, TransactionFieldError> {
@@ -224,7 +254,7 @@ impl TransactionField for Hash {
     }
 
     fn from_inner_unchecked(buf: <Self::Inner as ToOwned>::Owned) -> Self {
-        let mut trits = [0; 243];
+        let mut trits = [0; HASH_TRIT_LEN];
         trits.copy_from_slice(buf.as_i8_slice());
 
         Self(trits)
@@ -328,6 +358,7 @@ impl_hash_trait!(Address);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bee_ternary::Btrit;
 
     #[test]
     fn hash_weigth() {
@@ -340,4 +371,22 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn null_address() {
+        let mut address = Address::zeros();
+        assert!(address.is_null());
+
+        address.0.set(ADDRESS_TRIT_LEN - 1, Btrit::PlusOne);
+        assert!(!address.is_null());
+    }
+
+    #[test]
+    fn null_hash() {
+        let mut hash = Hash::zeros();
+        assert!(hash.is_null());
+
+        hash.0[0] = -1;
+        assert!(!hash.is_null());
+    }
 }
@@ -14,5 +14,7 @@ mod fields;
 mod transaction;
 
 pub use builder::{TransactionBuilder, TransactionBuilders};
-pub use fields::{Address, Hash, Index, Nonce, Payload, Tag, Timestamp, TransactionField, Value};
+pub use fields::{
+    Address, Hash, Index, Nonce, Payload, Tag, Timestamp, TransactionField, TransactionFieldError, Value,
+};
 pub use transaction::{Transaction, TransactionError, Transactions};
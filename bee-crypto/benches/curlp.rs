@@ -0,0 +1,46 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use bee_crypto::{CurlP81, SimdCurlP81, Sponge};
+use bee_ternary::{Btrit, T1B1Buf, TritBuf};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::Rng;
+
+use std::convert::TryFrom;
+
+fn random_input(length: usize) -> TritBuf<T1B1Buf> {
+    let mut rng = rand::thread_rng();
+
+    (0..length)
+        .map(|_| Btrit::try_from(rng.gen_range(-1i8, 2)).unwrap())
+        .collect()
+}
+
+fn curlp81_digest(c: &mut Criterion) {
+    // The length of a transaction.
+    let input = random_input(8019);
+    let mut group = c.benchmark_group("CurlP81 digest");
+
+    group.bench_function("scalar", |b| {
+        let mut curlp81 = CurlP81::new();
+        b.iter(|| curlp81.digest(black_box(&input)).unwrap())
+    });
+    group.bench_function("simd", |b| {
+        let mut curlp81 = SimdCurlP81::new();
+        b.iter(|| curlp81.digest(black_box(&input)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, curlp81_digest);
+criterion_main!(benches);
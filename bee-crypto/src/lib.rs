@@ -11,4 +11,12 @@
 
 mod sponge;
 
-pub use sponge::{CurlP, CurlP27, CurlP81, Kerl, Sponge, SpongeType};
+pub mod prelude;
+
+pub use sponge::{CurlP, CurlP27, CurlP81, DynSponge, Kerl, NotMultipleOfInputLength, Sponge, SpongeError, SpongeType};
+
+#[cfg(feature = "identity")]
+pub use sponge::IdentitySponge;
+
+#[cfg(feature = "simd")]
+pub use sponge::SimdCurlP81;
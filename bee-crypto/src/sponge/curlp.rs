@@ -9,9 +9,11 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use std::convert::{Infallible, TryInto};
+use std::convert::TryInto;
 
-use crate::Sponge;
+#[cfg(feature = "simd")]
+use crate::sponge::simd;
+use crate::{sponge::NotMultipleOfInputLength, Sponge};
 use bee_ternary::{Btrit, TritBuf, Trits};
 
 /// The length of a hash as returned by the hash functions implemented in this RFC (in
@@ -20,11 +22,51 @@ const HASH_LEN: usize = 243;
 
 /// The length internal state of the `CurlP` sponge construction (in units of binary-coded,
 /// balanced trits).
-const STATE_LEN: usize = HASH_LEN * 3;
-const HALF_STATE_LEN: usize = STATE_LEN / 2;
+pub(super) const STATE_LEN: usize = HASH_LEN * 3;
+pub(super) const HALF_STATE_LEN: usize = STATE_LEN / 2;
 
-const TRUTH_TABLE: [i8; 11] = [1, 0, -1, 2, 1, -1, 0, 2, -1, 1, 0];
+pub(super) const TRUTH_TABLE: [i8; 11] = [1, 0, -1, 2, 1, -1, 0, 2, -1, 1, 0];
 
+fn calculate_truth_table_index(xs: &Trits, p: usize, q: usize) -> usize {
+    let idx = xs.get(p).unwrap() as i8 + ((xs.get(q).unwrap() as i8) << 2) + 5;
+    idx as usize
+}
+
+fn apply_substitution_box(input: &Trits, output: &mut Trits) {
+    assert!(input.len() <= STATE_LEN);
+    assert!(output.len() <= STATE_LEN);
+
+    // Unwrapping here and below is acceptable because we have verified that
+    // `calculate_truth_table_index` and `TRUTH_TABLE` always yield a value in {-1, 0, 1}
+    output.set(
+        0,
+        TRUTH_TABLE[calculate_truth_table_index(input, 0, HALF_STATE_LEN)]
+            .try_into()
+            .unwrap(),
+    );
+
+    for state_index in 0..HALF_STATE_LEN {
+        let left_idx = HALF_STATE_LEN - state_index;
+        let right_idx = STATE_LEN - state_index - 1;
+
+        output.set(
+            2 * state_index + 1,
+            TRUTH_TABLE[calculate_truth_table_index(input, left_idx, right_idx)]
+                .try_into()
+                .unwrap(),
+        );
+
+        let left_idx = left_idx - 1;
+        output.set(
+            2 * state_index + 2,
+            TRUTH_TABLE[calculate_truth_table_index(input, right_idx, left_idx)]
+                .try_into()
+                .unwrap(),
+        );
+    }
+}
+
+#[derive(Clone)]
 pub struct CurlP {
     /// The number of rounds of hashing to apply before a hash is squeezed.
     rounds: usize,
@@ -34,6 +76,9 @@ pub struct CurlP {
 
     /// Workspace for performing transformations
     work_state: TritBuf,
+
+    /// The substitution box applied to the internal state at each round.
+    substitution_box: fn(&Trits, &mut Trits),
 }
 
 impl CurlP {
@@ -43,6 +88,19 @@ impl CurlP {
             rounds,
             state: TritBuf::zeros(STATE_LEN),
             work_state: TritBuf::zeros(STATE_LEN),
+            substitution_box: apply_substitution_box,
+        }
+    }
+
+    /// Create a new `CurlP` sponge with `rounds` of iterations, whose substitution box processes many trits per
+    /// instruction where the CPU allows it.
+    ///
+    /// Hashes are identical to the ones of a `CurlP` created with `CurlP::new`.
+    #[cfg(feature = "simd")]
+    pub fn new_simd(rounds: usize) -> Self {
+        Self {
+            substitution_box: simd::apply_substitution_box,
+            ..Self::new(rounds)
         }
     }
 
@@ -57,49 +115,11 @@ impl CurlP {
     /// The essence of this transformation is the application of a so-called substitution box to
     /// the internal state, which happens `round` number of times.
     fn transform(&mut self) {
-        fn calculate_truth_table_index(xs: &Trits, p: usize, q: usize) -> usize {
-            let idx = xs.get(p).unwrap() as i8 + ((xs.get(q).unwrap() as i8) << 2) + 5;
-            idx as usize
-        }
-
-        fn apply_substitution_box(input: &Trits, output: &mut Trits) {
-            assert!(input.len() <= STATE_LEN);
-            assert!(output.len() <= STATE_LEN);
-
-            // Unwrapping here and below is acceptable because we have verified that
-            // `calculate_truth_table_index` and `TRUTH_TABLE` always yield a value in {-1, 0, 1}
-            output.set(
-                0,
-                TRUTH_TABLE[calculate_truth_table_index(input, 0, HALF_STATE_LEN)]
-                    .try_into()
-                    .unwrap(),
-            );
-
-            for state_index in 0..HALF_STATE_LEN {
-                let left_idx = HALF_STATE_LEN - state_index;
-                let right_idx = STATE_LEN - state_index - 1;
-
-                output.set(
-                    2 * state_index + 1,
-                    TRUTH_TABLE[calculate_truth_table_index(input, left_idx, right_idx)]
-                        .try_into()
-                        .unwrap(),
-                );
-
-                let left_idx = left_idx - 1;
-                output.set(
-                    2 * state_index + 2,
-                    TRUTH_TABLE[calculate_truth_table_index(input, right_idx, left_idx)]
-                        .try_into()
-                        .unwrap(),
-                );
-            }
-        }
-
+        let substitution_box = self.substitution_box;
         let (lhs, rhs) = (&mut self.state, &mut self.work_state);
 
         for _ in 0..self.rounds {
-            apply_substitution_box(&lhs, rhs);
+            substitution_box(lhs, rhs);
             std::mem::swap(lhs, rhs);
         }
 
@@ -115,16 +135,18 @@ impl Sponge for CurlP {
     const IN_LEN: usize = HASH_LEN;
     const OUT_LEN: usize = HASH_LEN;
 
-    type Error = Infallible;
+    type Error = NotMultipleOfInputLength;
 
     /// Absorb `input` into the sponge by copying `HASH_LEN` chunks of it into its internal
     /// state and transforming the state before moving on to the next chunk.
     ///
-    /// If `input` is not a multiple of `HASH_LEN` with the last chunk having `n < HASH_LEN` trits,
-    /// the last chunk will be copied to the first `n` slots of the internal state. The remaining
-    /// data in the internal state is then just the result of the last transformation before the
-    /// data was copied, and will be reused for the next transformation.
+    /// Returns `NotMultipleOfInputLength` without touching the state if `input` is not a multiple
+    /// of `HASH_LEN`.
     fn absorb(&mut self, input: &Trits) -> Result<(), Self::Error> {
+        if input.len() % Self::IN_LEN != 0 {
+            return Err(NotMultipleOfInputLength);
+        }
+
         for chunk in input.chunks(Self::IN_LEN) {
             self.state[0..chunk.len()].copy_from(chunk);
             self.transform();
@@ -152,6 +174,7 @@ impl Sponge for CurlP {
 }
 
 /// `CurlP` with a fixed number of 27 rounds.
+#[derive(Clone)]
 pub struct CurlP27(CurlP);
 
 impl CurlP27 {
@@ -167,6 +190,7 @@ impl Default for CurlP27 {
 }
 
 /// `CurlP` with a fixed number of 81 rounds.
+#[derive(Clone)]
 pub struct CurlP81(CurlP);
 
 impl CurlP81 {
@@ -181,6 +205,25 @@ impl Default for CurlP81 {
     }
 }
 
+/// A `CurlP81` sponge whose transform is vectorized, see `CurlP::new_simd`.
+#[cfg(feature = "simd")]
+#[derive(Clone)]
+pub struct SimdCurlP81(CurlP);
+
+#[cfg(feature = "simd")]
+impl SimdCurlP81 {
+    pub fn new() -> Self {
+        Self(CurlP::new_simd(81))
+    }
+}
+
+#[cfg(feature = "simd")]
+impl Default for SimdCurlP81 {
+    fn default() -> Self {
+        SimdCurlP81::new()
+    }
+}
+
 macro_rules! forward_sponge_impl {
     ($($t:ty),+) => {
 
@@ -196,7 +239,7 @@ macro_rules! forward_sponge_impl {
             const IN_LEN: usize = 243;
             const OUT_LEN: usize = 243;
 
-            type Error = Infallible;
+            type Error = NotMultipleOfInputLength;
 
             fn absorb(&mut self, input: &Trits) -> Result<(), Self::Error> {
                 self.0.absorb(input)
@@ -216,6 +259,8 @@ macro_rules! forward_sponge_impl {
 }
 
 forward_sponge_impl!(CurlP27, CurlP81);
+#[cfg(feature = "simd")]
+forward_sponge_impl!(SimdCurlP81);
 
 #[cfg(test)]
 mod tests {
@@ -298,7 +343,7 @@ KXRVLFETGUTUWBCNCC9DWO99JQTEI9YXVOZHWELSYP9SG9KN9WCKXOVTEFHFH9EFZJKFYCZKQPPBXYSG
         let calculated_hash = curlp27.digest(&input_trit_buf);
         assert!(
             calculated_hash.is_ok(),
-            "<CurlP27 as Sponge>::Error is Infallible and this assert should never fail"
+            "the input is a multiple of the hash length and this assert should never fail"
         );
         let calculated_hash = calculated_hash.unwrap().encode::<T3B1Buf>();
 
@@ -317,8 +362,116 @@ KXRVLFETGUTUWBCNCC9DWO99JQTEI9YXVOZHWELSYP9SG9KN9WCKXOVTEFHFH9EFZJKFYCZKQPPBXYSG
         let calculated_hash = curlp27.digest(&input_trits);
         assert!(
             calculated_hash.is_ok(),
-            "<CurlP27 as Sponge>::Error is Infallible and this assert should never fail"
+            "the input is a multiple of the hash length and this assert should never fail"
         );
         assert_eq!(expected_hash, &*calculated_hash.unwrap());
     }
+
+    #[test]
+    fn verify_curlp27_hash_chunked() {
+        let mut curlp27 = CurlP27::new();
+
+        let input_trit_buf = TryteBuf::try_from_str(INPUT_TRYTES)
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+        let expected_hash = TryteBuf::try_from_str(EXPECTED_CURLP27_HASH_TRYTES).unwrap();
+
+        assert!(curlp27
+            .absorb_chunked(input_trit_buf.chunks(CurlP27::IN_LEN * 4))
+            .is_ok());
+        let calculated_hash = curlp27.squeeze().unwrap().encode::<T3B1Buf>();
+
+        assert_eq!(calculated_hash.as_slice(), expected_hash.as_trits());
+    }
+
+    #[test]
+    fn absorb_chunked_rejects_partial_chunk() {
+        let input_trits = unsafe { Trits::<T1B1>::from_raw_unchecked(INPUT_TRITS, INPUT_TRITS.len()) };
+
+        assert_eq!(
+            CurlP27::new().absorb_chunked(input_trits.chunks(CurlP27::IN_LEN - 1)),
+            Err(NotMultipleOfInputLength)
+        );
+    }
+
+    #[test]
+    fn absorb_chunked_rejects_misaligned_chunk() {
+        let input_trits = TritBuf::<T1B1Buf>::zeros(CurlP27::IN_LEN * 2 + 1);
+
+        // The first chunk is aligned but the second one only holds the trailing trit
+        assert_eq!(
+            CurlP27::new().absorb_chunked(input_trits.chunks(CurlP27::IN_LEN * 2)),
+            Err(NotMultipleOfInputLength)
+        );
+    }
+
+    #[test]
+    fn digest_rejects_mis_sized_input() {
+        let input_trits = unsafe { Trits::<T1B1>::from_raw_unchecked(INPUT_TRITS, INPUT_TRITS.len()) };
+        let mut curlp27 = CurlP27::new();
+        let mut buf = TritBuf::<T1B1Buf>::zeros(CurlP27::OUT_LEN);
+
+        assert_eq!(curlp27.digest(&input_trits[0..100]), Err(NotMultipleOfInputLength));
+        assert_eq!(
+            curlp27.digest_into(&input_trits[0..CurlP27::IN_LEN - 1], &mut buf),
+            Err(NotMultipleOfInputLength)
+        );
+        assert_eq!(curlp27.absorb(&input_trits[0..1]), Err(NotMultipleOfInputLength));
+
+        // The rejected inputs have left the sponge untouched.
+        assert_eq!(
+            curlp27.digest(input_trits).unwrap(),
+            CurlP27::new().digest(input_trits).unwrap()
+        );
+    }
+
+    #[test]
+    fn digest_many_matches_digest() {
+        let input_trit_buf = TryteBuf::try_from_str(INPUT_TRYTES)
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+        let inputs = input_trit_buf.chunks(CurlP81::IN_LEN * 3).collect::<Vec<_>>();
+
+        let mut curlp81 = CurlP81::new();
+        let hashes = curlp81.digest_many(&inputs).unwrap();
+
+        assert_eq!(hashes.len(), inputs.len());
+        for (input, hash) in inputs.iter().zip(hashes.iter()) {
+            assert_eq!(*hash, curlp81.digest(input).unwrap());
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_digest_matches_scalar() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        let mut curlp81 = CurlP81::new();
+        let mut simd_curlp81 = SimdCurlP81::new();
+
+        for _ in 0..100 {
+            let length = CurlP81::IN_LEN * rng.gen_range(1, 5);
+            let input_trits = (0..length).map(|_| rng.gen_range(-1i8, 2)).collect::<Vec<_>>();
+            let input = unsafe { Trits::<T1B1>::from_raw_unchecked(&input_trits, length) };
+
+            assert_eq!(simd_curlp81.digest(input).unwrap(), curlp81.digest(input).unwrap());
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_hash_trytes() {
+        let input_trit_buf = TryteBuf::try_from_str(INPUT_TRYTES)
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+        let expected_hash = TryteBuf::try_from_str(EXPECTED_CURLP27_HASH_TRYTES).unwrap();
+
+        let calculated_hash = CurlP::new_simd(27).digest(&input_trit_buf).unwrap().encode::<T3B1Buf>();
+
+        assert_eq!(calculated_hash.as_slice(), expected_hash.as_trits());
+    }
 }
@@ -0,0 +1,98 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use crate::Sponge;
+
+use bee_ternary::Trits;
+
+use std::fmt::Debug;
+
+/// An error indicating that a failure has occured in a sponge used through `DynSponge`.
+///
+/// It holds the debug representation of the error of the underlying sponge.
+#[derive(Debug)]
+pub struct SpongeError(pub String);
+
+/// An object-safe version of `Sponge`, allowing different sponges to be stored as `Box<dyn DynSponge>`.
+pub trait DynSponge {
+    /// Absorb `input` into the sponge.
+    fn absorb(&mut self, input: &Trits) -> Result<(), SpongeError>;
+
+    /// Reset the inner state of the sponge.
+    fn reset(&mut self);
+
+    /// Squeeze the sponge into a buffer
+    fn squeeze_into(&mut self, buf: &mut Trits) -> Result<(), SpongeError>;
+}
+
+impl<S> DynSponge for S
+where
+    S: Sponge,
+    S::Error: Debug,
+{
+    fn absorb(&mut self, input: &Trits) -> Result<(), SpongeError> {
+        Sponge::absorb(self, input).map_err(|e| SpongeError(format!("{:?}", e)))
+    }
+
+    fn reset(&mut self) {
+        Sponge::reset(self)
+    }
+
+    fn squeeze_into(&mut self, buf: &mut Trits) -> Result<(), SpongeError> {
+        Sponge::squeeze_into(self, buf).map_err(|e| SpongeError(format!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CurlP81, Kerl};
+
+    use bee_ternary::{Btrit, T1B1Buf, TritBuf};
+
+    fn dyn_digest(sponge: &mut dyn DynSponge, input: &Trits) -> TritBuf {
+        let mut output = TritBuf::<T1B1Buf>::zeros(243);
+
+        sponge.absorb(input).unwrap();
+        sponge.squeeze_into(&mut output).unwrap();
+        sponge.reset();
+
+        output
+    }
+
+    #[test]
+    fn boxed_sponges_digest() {
+        let input = TritBuf::<T1B1Buf>::filled(243, Btrit::PlusOne);
+        let mut sponges: Vec<Box<dyn DynSponge>> = vec![Box::new(CurlP81::new()), Box::new(Kerl::new())];
+
+        assert_eq!(
+            dyn_digest(sponges[0].as_mut(), &input),
+            CurlP81::new().digest(&input).unwrap()
+        );
+        assert_eq!(
+            dyn_digest(sponges[1].as_mut(), &input),
+            Kerl::new().digest(&input).unwrap()
+        );
+        // Digesting twice checks that the sponges have been reset
+        assert_eq!(
+            dyn_digest(sponges[1].as_mut(), &input),
+            Kerl::new().digest(&input).unwrap()
+        );
+    }
+
+    #[test]
+    fn boxed_sponge_error() {
+        let input = TritBuf::<T1B1Buf>::zeros(42);
+        let mut sponge: Box<dyn DynSponge> = Box::new(Kerl::new());
+
+        assert!(sponge.absorb(&input).is_err());
+    }
+}
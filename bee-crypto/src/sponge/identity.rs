@@ -0,0 +1,137 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use crate::{sponge::NotMultipleOfInputLength, Sponge};
+use bee_ternary::{Btrit, TritBuf, Trits};
+
+const HASH_LEN: usize = 243;
+
+/// A sponge whose hash is simply the first `HASH_LEN` trits absorbed since the last reset.
+///
+/// It is not a cryptographic hash function and is only meant to make tests of code that is generic over `Sponge`
+/// cheap and deterministic.
+#[derive(Clone)]
+pub struct IdentitySponge {
+    /// The first chunk absorbed since the last reset.
+    state: TritBuf,
+
+    /// Whether a chunk has been absorbed since the last reset.
+    absorbed: bool,
+}
+
+impl IdentitySponge {
+    /// Create a new `IdentitySponge` with an all zero state.
+    pub fn new() -> Self {
+        Self {
+            state: TritBuf::zeros(HASH_LEN),
+            absorbed: false,
+        }
+    }
+}
+
+impl Default for IdentitySponge {
+    fn default() -> Self {
+        IdentitySponge::new()
+    }
+}
+
+impl Sponge for IdentitySponge {
+    const IN_LEN: usize = HASH_LEN;
+    const OUT_LEN: usize = HASH_LEN;
+
+    type Error = NotMultipleOfInputLength;
+
+    /// Absorb `input` into the sponge by keeping its first `HASH_LEN` trits if nothing has been absorbed since the
+    /// last reset, and ignoring it otherwise.
+    fn absorb(&mut self, input: &Trits) -> Result<(), Self::Error> {
+        if input.len() % Self::IN_LEN != 0 {
+            return Err(NotMultipleOfInputLength);
+        }
+
+        if !self.absorbed && input.len() > 0 {
+            self.state.copy_from(&input[0..Self::IN_LEN]);
+            self.absorbed = true;
+        }
+
+        Ok(())
+    }
+
+    /// Reset the internal state by overwriting it with zeros.
+    fn reset(&mut self) {
+        self.state.fill(Btrit::Zero);
+        self.absorbed = false;
+    }
+
+    /// Squeeze the sponge by copying the kept trits into each `HASH_LEN` chunk of the provided `buf`.
+    ///
+    /// If the last chunk is smaller than `HASH_LEN`, then only the fraction that fits is written
+    /// into it.
+    fn squeeze_into(&mut self, buf: &mut Trits) -> Result<(), Self::Error> {
+        for chunk in buf.chunks_mut(Self::OUT_LEN) {
+            chunk.copy_from(&self.state[0..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bee_ternary::{T1B1Buf, TryteBuf};
+
+    const INPUT_TRYTES: &str = "\
+RSWWSFXPQJUBJROQBRQZWZXZJWMUBVIVMHPPTYSNW9YQIQQF9RCSJJCVZG9ZWITXNCSBBDHEEKDRBHVTWCZ9SZOOZHVB\
+PCQNPKTWFNZAWGCZ9QDIMKRVINMIRZBPKRKQAIPGOHBTHTGYXTBJLSURDSPEOJ9UKJECUKCCPVIQQHDUYKVKISCEIEGV\
+";
+
+    #[test]
+    fn digest_is_first_chunk() {
+        let input = TryteBuf::try_from_str(INPUT_TRYTES)
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+        let input = &input[0..IdentitySponge::IN_LEN * 2];
+        let mut sponge = IdentitySponge::new();
+
+        assert_eq!(
+            sponge.digest(input).unwrap(),
+            input[0..IdentitySponge::IN_LEN].to_buf::<T1B1Buf>()
+        );
+        assert_eq!(
+            sponge.digest(&input[IdentitySponge::IN_LEN..input.len()]).unwrap(),
+            input[IdentitySponge::IN_LEN..input.len()].to_buf::<T1B1Buf>()
+        );
+    }
+
+    #[test]
+    fn squeeze_repeats_hash() {
+        let input = TryteBuf::try_from_str(INPUT_TRYTES)
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+        let input = &input[0..IdentitySponge::IN_LEN];
+        let mut sponge = IdentitySponge::new();
+        let mut buf = TritBuf::<T1B1Buf>::zeros(IdentitySponge::OUT_LEN * 2);
+
+        sponge.absorb(input).unwrap();
+        sponge.squeeze_into(&mut buf).unwrap();
+
+        assert_eq!(&buf[0..IdentitySponge::OUT_LEN], input);
+        assert_eq!(&buf[IdentitySponge::OUT_LEN..buf.len()], input);
+    }
+
+    #[test]
+    fn reject_mis_sized_input() {
+        let input = TritBuf::<T1B1Buf>::zeros(IdentitySponge::IN_LEN + 1);
+
+        assert_eq!(IdentitySponge::new().digest(&input), Err(NotMultipleOfInputLength));
+    }
+}
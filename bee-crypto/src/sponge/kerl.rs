@@ -19,7 +19,7 @@ use bee_ternary::{
     Btrit, Trits, T1B1,
 };
 
-use crate::Sponge;
+use crate::{sponge::NotMultipleOfInputLength, Sponge};
 
 const HASH_LEN: usize = 243;
 
@@ -58,6 +58,12 @@ impl From<bee_ternary::bigint::common::Error> for Error {
     }
 }
 
+impl From<NotMultipleOfInputLength> for Error {
+    fn from(_: NotMultipleOfInputLength) -> Self {
+        Error::NotMultipleOfHashLength
+    }
+}
+
 impl Sponge for Kerl {
     const IN_LEN: usize = HASH_LEN;
     const OUT_LEN: usize = HASH_LEN;
@@ -67,10 +73,8 @@ impl Sponge for Kerl {
     /// Absorb `input` into the sponge by copying `HASH_LEN` chunks of it into its internal
     /// state and transforming the state before moving on to the next chunk.
     ///
-    /// If `input` is not a multiple of `HASH_LEN` with the last chunk having `n < HASH_LEN` trits,
-    /// the last chunk will be copied to the first `n` slots of the internal state. The remaining
-    /// data in the internal state is then just the result of the last transformation before the
-    /// data was copied, and will be reused for the next transformation.
+    /// Returns `Error::NotMultipleOfHashLength` without touching the state if `input` is not a
+    /// multiple of `HASH_LEN`.
     fn absorb(&mut self, input: &Trits) -> Result<(), Self::Error> {
         if input.len() % Self::IN_LEN != 0 {
             return Err(Error::NotMultipleOfHashLength);
@@ -10,11 +10,21 @@
 // See the License for the specific language governing permissions and limitations under the License.
 
 mod curlp;
+mod dyn_sponge;
+#[cfg(feature = "identity")]
+mod identity;
 mod kerl;
+#[cfg(feature = "simd")]
+mod simd;
 mod sponge;
 mod r#type;
 
+#[cfg(feature = "simd")]
+pub use curlp::SimdCurlP81;
 pub use curlp::{CurlP, CurlP27, CurlP81};
+pub use dyn_sponge::{DynSponge, SpongeError};
+#[cfg(feature = "identity")]
+pub use identity::IdentitySponge;
 pub use kerl::Kerl;
 pub use r#type::SpongeType;
-pub use sponge::Sponge;
+pub use sponge::{NotMultipleOfInputLength, Sponge};
@@ -0,0 +1,116 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+//! Vectorized substitution box of the `CurlP` transform.
+
+use super::curlp::{HALF_STATE_LEN, STATE_LEN, TRUTH_TABLE};
+use bee_ternary::Trits;
+
+/// Applies the `CurlP` substitution box to `input`, writing the result into `output`.
+///
+/// Output trits `2k + 1` and `2k + 2` are respectively computed from the pairs (`input[364 - k]`, `input[728 - k]`) and
+/// (`input[728 - k]`, `input[363 - k]`), so each vector of pairs is made of contiguous runs of the input read in
+/// reverse. The pairs that don't fill a whole vector, or all of them if the CPU lacks the required instructions, are
+/// processed one at a time.
+pub(super) fn apply_substitution_box(input: &Trits, output: &mut Trits) {
+    let input = input.as_i8_slice();
+    // Only valid trits are written to the buffer, so its encoding is preserved.
+    let output = unsafe { output.as_i8_slice_mut() };
+
+    assert_eq!(input.len(), STATE_LEN);
+    assert_eq!(output.len(), STATE_LEN);
+
+    output[0] = substitute(input[0], input[HALF_STATE_LEN]);
+
+    #[allow(unused_mut)]
+    let mut start = 0;
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("ssse3") {
+            // The CPU supports SSSE3 and both buffers have been checked to hold a whole state.
+            start = unsafe { x86::apply_substitution_box(input, output) };
+        }
+    }
+
+    for k in start..HALF_STATE_LEN {
+        output[2 * k + 1] = substitute(input[HALF_STATE_LEN - k], input[STATE_LEN - 1 - k]);
+        output[2 * k + 2] = substitute(input[STATE_LEN - 1 - k], input[HALF_STATE_LEN - 1 - k]);
+    }
+}
+
+fn substitute(p: i8, q: i8) -> i8 {
+    TRUTH_TABLE[(p + (q << 2) + 5) as usize]
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    use super::{HALF_STATE_LEN, STATE_LEN, TRUTH_TABLE};
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    const LANES: usize = 16;
+
+    /// Loads the `LANES` trits of `input` ending at `last`, in reverse order.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    unsafe fn load_reversed(input: &[i8], last: usize) -> __m128i {
+        let reverse = _mm_setr_epi8(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+        let trits = _mm_loadu_si128(input.as_ptr().add(last + 1 - LANES) as *const __m128i);
+
+        _mm_shuffle_epi8(trits, reverse)
+    }
+
+    /// Looks up the truth table for each lane, the index being in `0..11` and thus within the table.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    unsafe fn substitute(p: __m128i, q: __m128i) -> __m128i {
+        let t = TRUTH_TABLE;
+        let table = _mm_setr_epi8(
+            t[0], t[1], t[2], t[3], t[4], t[5], t[6], t[7], t[8], t[9], t[10], 0, 0, 0, 0, 0,
+        );
+        let q2 = _mm_add_epi8(q, q);
+        let index = _mm_add_epi8(_mm_add_epi8(p, _mm_add_epi8(q2, q2)), _mm_set1_epi8(5));
+
+        _mm_shuffle_epi8(table, index)
+    }
+
+    /// Processes the pairs `LANES` at a time and returns the index of the first pair left over.
+    ///
+    /// Both slices must hold `STATE_LEN` trits.
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn apply_substitution_box(input: &[i8], output: &mut [i8]) -> usize {
+        debug_assert!(input.len() == STATE_LEN && output.len() == STATE_LEN);
+
+        let mut k = 0;
+
+        while k + LANES <= HALF_STATE_LEN {
+            let left = load_reversed(input, HALF_STATE_LEN - k);
+            let right = load_reversed(input, STATE_LEN - 1 - k);
+            let next_left = load_reversed(input, HALF_STATE_LEN - 1 - k);
+
+            let odd = substitute(left, right);
+            let even = substitute(right, next_left);
+
+            // Interleaving puts the outputs of pair `k` at `2k + 1` and `2k + 2`.
+            let out = output.as_mut_ptr().add(2 * k + 1) as *mut __m128i;
+            _mm_storeu_si128(out, _mm_unpacklo_epi8(odd, even));
+            _mm_storeu_si128(out.add(1), _mm_unpackhi_epi8(odd, even));
+
+            k += LANES;
+        }
+
+        k
+    }
+}
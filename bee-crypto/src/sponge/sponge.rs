@@ -11,6 +11,13 @@
 
 use bee_ternary::{TritBuf, Trits};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The error returned when the length of the input to a sponge is not a multiple of `Sponge::IN_LEN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotMultipleOfInputLength;
+
 /// The common interface of cryptographic hash functions that follow the sponge construction,
 /// and that absorb and return binary-coded, balanced ternary.
 pub trait Sponge {
@@ -21,11 +28,37 @@ pub trait Sponge {
     const OUT_LEN: usize;
 
     /// An error indicating a that a failure has occured during `absorb`.
-    type Error;
+    ///
+    /// It must be constructible from `NotMultipleOfInputLength`, which is what the default methods return for inputs
+    /// whose length is not a multiple of `IN_LEN`.
+    type Error: From<NotMultipleOfInputLength>;
 
     /// Absorb `input` into the sponge.
+    ///
+    /// Returns an error converted from `NotMultipleOfInputLength` if the length of `input` is not a multiple of
+    /// `IN_LEN`.
     fn absorb(&mut self, input: &Trits) -> Result<(), Self::Error>;
 
+    /// Absorb each chunk of `inputs` in sequence, as if their concatenation had been passed to `Sponge::absorb`.
+    ///
+    /// The state carries over from one chunk to the next and is not reset at the end, so more input can still be
+    /// absorbed before squeezing. Call `Sponge::reset` before starting to absorb an unrelated input.
+    ///
+    /// Returns an error converted from `NotMultipleOfInputLength` if the length of a chunk is not a multiple of
+    /// `IN_LEN`, in which case the chunks preceding it have already been absorbed.
+    fn absorb_chunked<'a>(&mut self, inputs: impl Iterator<Item = &'a Trits>) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        for input in inputs {
+            if input.len() % Self::IN_LEN != 0 {
+                return Err(NotMultipleOfInputLength.into());
+            }
+            self.absorb(input)?;
+        }
+        Ok(())
+    }
+
     /// Reset the inner state of the sponge.
     fn reset(&mut self);
 
@@ -42,7 +75,12 @@ pub trait Sponge {
 
     /// Convenience function to absorb `input`, squeeze the sponge into a
     /// buffer, and reset the sponge in one go.
+    ///
+    /// Fails without touching the sponge if the length of `input` is not a multiple of `IN_LEN`.
     fn digest_into(&mut self, input: &Trits, buf: &mut Trits) -> Result<(), Self::Error> {
+        if input.len() % Self::IN_LEN != 0 {
+            return Err(NotMultipleOfInputLength.into());
+        }
         self.absorb(input)?;
         self.squeeze_into(buf)?;
         self.reset();
@@ -51,10 +89,33 @@ pub trait Sponge {
 
     /// Convenience function to absorb `input`, squeeze the sponge, and reset the sponge in one go.
     /// Returns an owned versin of the hash.
+    ///
+    /// Fails without touching the sponge if the length of `input` is not a multiple of `IN_LEN`.
     fn digest(&mut self, input: &Trits) -> Result<TritBuf, Self::Error> {
+        if input.len() % Self::IN_LEN != 0 {
+            return Err(NotMultipleOfInputLength.into());
+        }
         self.absorb(input)?;
         let output = self.squeeze()?;
         self.reset();
         Ok(output)
     }
+
+    /// Convenience function to digest each of the independent `inputs` with a fresh clone of the sponge, returning
+    /// the hashes in the same order. The sponge itself is left untouched.
+    ///
+    /// With the `parallel` feature the inputs are hashed in parallel, otherwise one after the other; both yield the
+    /// same hashes.
+    fn digest_many(&self, inputs: &[&Trits]) -> Result<Vec<TritBuf>, Self::Error>
+    where
+        Self: Clone + Send + Sync,
+        Self::Error: Send,
+    {
+        #[cfg(feature = "parallel")]
+        let inputs = inputs.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let inputs = inputs.iter();
+
+        inputs.map(|input| self.clone().digest(input)).collect()
+    }
 }
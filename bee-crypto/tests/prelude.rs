@@ -0,0 +1,29 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use bee_crypto::prelude::*;
+use bee_ternary::{T1B1Buf, TritBuf};
+
+fn digest_generic<S: Sponge + Default>()
+where
+    S::Error: std::fmt::Debug,
+{
+    let input = TritBuf::<T1B1Buf>::zeros(S::IN_LEN * 2);
+
+    assert_eq!(S::default().digest(&input).unwrap().len(), S::OUT_LEN);
+}
+
+#[test]
+fn digest_with_prelude() {
+    digest_generic::<CurlP27>();
+    digest_generic::<CurlP81>();
+    digest_generic::<Kerl>();
+}
@@ -9,6 +9,8 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
+mod white_flag;
 mod worker;
 
+pub use white_flag::{milestone_ledger_diff, WhiteFlagError};
 pub use worker::{LedgerWorker, LedgerWorkerEvent};
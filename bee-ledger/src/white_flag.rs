@@ -0,0 +1,151 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use bee_bundle::{Address, Hash};
+use bee_tangle::tangle;
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub enum WhiteFlagError {
+    /// The bundle of the tail at this position is not complete in the tangle.
+    IncompleteBundle(usize),
+}
+
+/// Computes the ledger diff produced by a milestone from the tails of the bundles it confirms, given in white-flag
+/// order. The bundles are loaded from the tangle, and those marked as conflicting are left out of the ledger.
+///
+/// Returns the net balance change of every address, along with the set of addresses that were spent from.
+pub fn milestone_ledger_diff(tails: &[Hash]) -> Result<(HashMap<Address, i64>, HashSet<Address>), WhiteFlagError> {
+    let mut diff = HashMap::new();
+    let mut spent = HashSet::new();
+
+    for (index, tail) in tails.iter().enumerate() {
+        // Conflicting bundles come back with an empty diff, so they neither move funds nor spend from addresses.
+        let bundle_diff = tangle()
+            .get_bundle_diff(*tail)
+            .ok_or(WhiteFlagError::IncompleteBundle(index))?;
+
+        for (address, value) in bundle_diff {
+            if value < 0 {
+                spent.insert(address.clone());
+            }
+            *diff.entry(address).or_insert(0) += value;
+        }
+    }
+
+    diff.retain(|_, value| *value != 0);
+
+    Ok((diff, spent))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use bee_test::{
+        field::{rand_trits_field, rand_value_address},
+        transaction::create_random_value_bundle,
+    };
+
+    use async_std::task::block_on;
+    use serial_test::serial;
+
+    // Inserts a bundle with the given transfers into the tangle and returns its tail.
+    fn insert_bundle(transfers: &[(&Address, i64)]) -> Hash {
+        let entries = transfers
+            .iter()
+            .map(|(address, value)| ((*address).clone(), *value))
+            .collect::<Vec<(Address, i64)>>();
+        let bundle = create_random_value_bundle(&entries);
+        let tail = bundle[0].0;
+
+        block_on(async {
+            for (hash, transaction) in bundle {
+                tangle().insert_transaction(transaction, hash).await;
+            }
+        });
+
+        tail
+    }
+
+    #[test]
+    #[serial]
+    fn two_value_bundles_diff() {
+        bee_tangle::init();
+
+        let a = rand_value_address();
+        let b = rand_value_address();
+        let c = rand_value_address();
+
+        // a sends 100 to b, then b sends 40 to c and 60 back to a.
+        let first = insert_bundle(&[(&b, 100), (&a, -100)]);
+        let second = insert_bundle(&[(&c, 40), (&a, 60), (&b, -100)]);
+
+        let (diff, spent) = milestone_ledger_diff(&[first, second]).unwrap();
+
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff.get(&a), Some(&-40));
+        assert_eq!(diff.get(&b), None);
+        assert_eq!(diff.get(&c), Some(&40));
+
+        assert_eq!(spent.len(), 2);
+        assert!(spent.contains(&a));
+        assert!(spent.contains(&b));
+        assert!(!spent.contains(&c));
+
+        bee_tangle::drop();
+    }
+
+    #[test]
+    #[serial]
+    fn conflicting_bundle_is_left_out() {
+        bee_tangle::init();
+
+        let a = rand_value_address();
+        let b = rand_value_address();
+        let c = rand_value_address();
+
+        let first = insert_bundle(&[(&b, 100), (&a, -100)]);
+        let second = insert_bundle(&[(&c, 150), (&b, -150)]);
+        tangle().set_conflicting(&second);
+
+        let (diff, spent) = milestone_ledger_diff(&[first, second]).unwrap();
+
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff.get(&a), Some(&-100));
+        assert_eq!(diff.get(&b), Some(&100));
+        assert_eq!(spent.len(), 1);
+        assert!(spent.contains(&a));
+
+        bee_tangle::drop();
+    }
+
+    #[test]
+    #[serial]
+    fn incomplete_bundle() {
+        bee_tangle::init();
+
+        let a = rand_value_address();
+        let b = rand_value_address();
+
+        let first = insert_bundle(&[(&b, 100), (&a, -100)]);
+        let missing = rand_trits_field::<Hash>();
+
+        match milestone_ledger_diff(&[first, missing]) {
+            Err(WhiteFlagError::IncompleteBundle(index)) => assert_eq!(index, 1),
+            _ => panic!("Expected an incomplete bundle error."),
+        }
+
+        bee_tangle::drop();
+    }
+}
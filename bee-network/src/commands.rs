@@ -41,6 +41,17 @@ pub enum Command {
         responder: Option<Responder<bool>>,
     },
 
+    /// Adds an `Endpoint` by its url string (e.g. "tcp://example.com:15600").
+    ///
+    /// If the url contains a hostname, it will periodically be re-resolved to follow address changes.
+    AddHostEndpoint {
+        /// The url string of the `Endpoint`.
+        url: String,
+
+        /// Result responder.
+        responder: Option<Responder<bool>>,
+    },
+
     /// Removes an `Endpoint`.
     RemoveEndpoint {
         /// The id of the `Endpoint` to remove.
@@ -108,6 +119,8 @@ impl fmt::Display for Command {
         match self {
             Command::AddEndpoint { url, .. } => write!(f, "Command::AddEndpoint {{ {} }}", url),
 
+            Command::AddHostEndpoint { url, .. } => write!(f, "Command::AddHostEndpoint {{ {} }}", url),
+
             Command::RemoveEndpoint { epid, .. } => write!(f, "Command::RemoveEndpoint {{ {} }}", epid),
 
             Command::Connect { epid, .. } => write!(f, "Command::Connect {{ {} }}", epid),
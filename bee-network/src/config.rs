@@ -9,20 +9,38 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use crate::address::{Address, Port};
+use crate::{
+    address::{Address, Port},
+    tcp::{ConnectionTimeouts, ListenerConfig},
+};
 
 use serde::Deserialize;
 
-use std::net::{IpAddr, Ipv4Addr};
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+};
 
 const DEFAULT_BINDING_PORT: u16 = 15600;
 const DEFAULT_BINDING_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
+const DEFAULT_RESOLVE_INTERVAL: u64 = 60;
+const DEFAULT_REUSE_ADDRESS: bool = true;
+const DEFAULT_LISTEN_BACKLOG: u32 = 128;
+const DEFAULT_READ_TIMEOUT: u64 = 0;
+const DEFAULT_WRITE_TIMEOUT: u64 = 60;
 
 /// Network configuration builder.
 #[derive(Default, Deserialize)]
 pub struct NetworkConfigBuilder {
     binding_port: Option<u16>,
     binding_addr: Option<IpAddr>,
+    #[serde(default)]
+    additional_binding_addrs: Vec<IpAddr>,
+    resolve_interval: Option<u64>,
+    reuse_address: Option<bool>,
+    listen_backlog: Option<u32>,
+    read_timeout: Option<u64>,
+    write_timeout: Option<u64>,
 }
 
 impl NetworkConfigBuilder {
@@ -48,20 +66,72 @@ impl NetworkConfigBuilder {
         self
     }
 
+    /// Adds an additional binding address for the network (e.g. to listen on both IPv4 and IPv6).
+    pub fn add_binding_addr(mut self, addr: &str) -> Self {
+        match addr.parse() {
+            Ok(addr) => self.additional_binding_addrs.push(addr),
+            Err(e) => panic!("Error parsing address: {:?}", e),
+        }
+        self
+    }
+
+    /// Sets the interval, in seconds, at which endpoints added by hostname are re-resolved.
+    pub fn resolve_interval(mut self, interval: u64) -> Self {
+        self.resolve_interval.replace(interval);
+        self
+    }
+
+    /// Sets whether the listening sockets are bound with `SO_REUSEADDR`, allowing a restarted node to bind to its
+    /// port while connections of the previous run linger in `TIME_WAIT`.
+    pub fn reuse_address(mut self, reuse_address: bool) -> Self {
+        self.reuse_address.replace(reuse_address);
+        self
+    }
+
+    /// Sets the maximum number of pending incoming connections queued by the listening sockets.
+    pub fn listen_backlog(mut self, backlog: u32) -> Self {
+        self.listen_backlog.replace(backlog);
+        self
+    }
+
+    /// Sets the time, in seconds, after which a connection that didn't receive any bytes is closed, 0 meaning never.
+    pub fn read_timeout(mut self, timeout: u64) -> Self {
+        self.read_timeout.replace(timeout);
+        self
+    }
+
+    /// Sets the time, in seconds, after which a connection that couldn't send its bytes is closed, 0 meaning never.
+    pub fn write_timeout(mut self, timeout: u64) -> Self {
+        self.write_timeout.replace(timeout);
+        self
+    }
+
     /// Builds the network config.
     pub fn finish(self) -> NetworkConfig {
         NetworkConfig {
             binding_port: self.binding_port.unwrap_or(DEFAULT_BINDING_PORT),
             binding_addr: self.binding_addr.unwrap_or(DEFAULT_BINDING_ADDR),
+            additional_binding_addrs: self.additional_binding_addrs,
+            resolve_interval: self.resolve_interval.unwrap_or(DEFAULT_RESOLVE_INTERVAL),
+            reuse_address: self.reuse_address.unwrap_or(DEFAULT_REUSE_ADDRESS),
+            listen_backlog: self.listen_backlog.unwrap_or(DEFAULT_LISTEN_BACKLOG),
+            read_timeout: self.read_timeout.unwrap_or(DEFAULT_READ_TIMEOUT),
+            write_timeout: self.write_timeout.unwrap_or(DEFAULT_WRITE_TIMEOUT),
         }
     }
 }
 
 /// Network configuration.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct NetworkConfig {
     pub(crate) binding_port: u16,
     pub(crate) binding_addr: IpAddr,
+    pub(crate) additional_binding_addrs: Vec<IpAddr>,
+    pub(crate) resolve_interval: u64,
+    pub(crate) reuse_address: bool,
+    pub(crate) listen_backlog: u32,
+    pub(crate) read_timeout: u64,
+    pub(crate) write_timeout: u64,
 }
 
 impl NetworkConfig {
@@ -70,10 +140,36 @@ impl NetworkConfig {
         NetworkConfigBuilder::new()
     }
 
-    pub(crate) fn socket_addr(&self) -> Address {
-        match self.binding_addr {
-            IpAddr::V4(addr) => Address::from_v4_addr_and_port(addr, Port(self.binding_port)),
-            IpAddr::V6(addr) => Address::from_v6_addr_and_port(addr, Port(self.binding_port)),
+    pub(crate) fn socket_addrs(&self) -> Vec<Address> {
+        std::iter::once(&self.binding_addr)
+            .chain(self.additional_binding_addrs.iter())
+            .map(|binding_addr| match *binding_addr {
+                IpAddr::V4(addr) => Address::from_v4_addr_and_port(addr, Port(self.binding_port)),
+                IpAddr::V6(addr) => Address::from_v6_addr_and_port(addr, Port(self.binding_port)),
+            })
+            .collect()
+    }
+
+    pub(crate) fn connection_timeouts(&self) -> ConnectionTimeouts {
+        let timeout = |secs| {
+            if secs == 0 {
+                None
+            } else {
+                Some(Duration::from_secs(secs))
+            }
+        };
+
+        ConnectionTimeouts {
+            read: timeout(self.read_timeout),
+            write: timeout(self.write_timeout),
+        }
+    }
+
+    pub(crate) fn listener_config(&self) -> ListenerConfig {
+        ListenerConfig {
+            reuse_address: self.reuse_address,
+            backlog: self.listen_backlog,
+            timeouts: self.connection_timeouts(),
         }
     }
 }
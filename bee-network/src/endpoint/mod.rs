@@ -11,6 +11,7 @@
 
 pub mod origin;
 pub mod outbox;
+pub mod resolver;
 pub mod store;
 pub mod whitelist;
 pub mod worker;
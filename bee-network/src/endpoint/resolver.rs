@@ -0,0 +1,245 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use crate::{
+    address::url::Url,
+    endpoint::{whitelist, EndpointId as EpId},
+    errors::Result,
+    events::{Event, EventPublisher as Notifier},
+    shutdown::ShutdownListener as Shutdown,
+};
+
+use async_std::{sync::Arc, task};
+use dashmap::DashMap;
+use futures::{future::BoxFuture, prelude::*, select};
+use log::*;
+
+use std::time::Duration;
+
+/// Maps the url strings of endpoints added by hostname to their currently resolved `Url`s.
+pub(crate) type HostEndpoints = Arc<DashMap<String, Url>>;
+
+/// Resolves url strings (e.g. "tcp://example.com:15600") into `Url`s.
+pub(crate) trait Resolver: Send + Sync + 'static {
+    fn resolve<'a>(&'a self, url: &'a str) -> BoxFuture<'a, Option<Url>>;
+}
+
+/// A `Resolver` relying on the system's DNS resolution.
+pub(crate) struct DnsResolver;
+
+impl Resolver for DnsResolver {
+    fn resolve<'a>(&'a self, url: &'a str) -> BoxFuture<'a, Option<Url>> {
+        Url::from_url_str(url).map(|url| url.ok()).boxed()
+    }
+}
+
+pub(crate) struct ResolverWorker<R: Resolver> {
+    resolver: R,
+    hosts: HostEndpoints,
+    interval: Duration,
+    notifier: Notifier,
+    shutdown: Shutdown,
+}
+
+impl<R: Resolver> ResolverWorker<R> {
+    pub fn new(resolver: R, hosts: HostEndpoints, interval: Duration, notifier: Notifier, shutdown: Shutdown) -> Self {
+        Self {
+            resolver,
+            hosts,
+            interval,
+            notifier,
+            shutdown,
+        }
+    }
+
+    pub async fn run(mut self) -> Result<()> {
+        debug!("[Rslv ] Starting resolver worker...");
+
+        let shutdown = &mut self.shutdown;
+
+        loop {
+            select! {
+                _ = task::sleep(self.interval).fuse() => {
+                    // NOTE: collected first so that no reference into the map is held while resolving.
+                    let hosts = self
+                        .hosts
+                        .iter()
+                        .map(|entry| (entry.key().clone(), *entry.value()))
+                        .collect::<Vec<(String, Url)>>();
+
+                    for (host, url) in hosts {
+                        match self.resolver.resolve(&host).await {
+                            Some(resolved) if resolved != url => {
+                                info!("[Rslv ] {} now resolves to {} instead of {}.", host, resolved, url);
+
+                                let epid = EpId::from(url);
+
+                                // Accept connections from the new address right away instead of waiting for the
+                                // endpoint to be re-added.
+                                whitelist::get().update(&epid, resolved.address().ip());
+
+                                self.notifier
+                                    .send(Event::EndpointResolved {
+                                        epid,
+                                        host,
+                                        url: resolved,
+                                    })
+                                    .await?;
+                            }
+                            Some(_) => (),
+                            None => warn!("[Rslv ] Resolving {} failed.", host),
+                        }
+                    }
+                },
+                _ = shutdown.fuse() => {
+                    break;
+                }
+            }
+        }
+
+        debug!("[Rslv ] Stopped resolver worker.");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        address::Address,
+        commands::{command_channel, response_channel, Command},
+        endpoint::worker::EndpointWorker,
+        events::event_channel,
+        tcp::ConnectionTimeouts,
+    };
+
+    use async_std::{
+        future::timeout,
+        net::TcpListener,
+        task::{block_on, spawn},
+    };
+    use futures::channel::oneshot;
+    use serial_test::serial;
+
+    use std::{
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc as StdArc,
+        },
+    };
+
+    const HOST: &str = "tcp://peer.example.com:15600";
+
+    struct StubResolver {
+        old: Url,
+        new: Url,
+        moved: StdArc<AtomicBool>,
+    }
+
+    impl Resolver for StubResolver {
+        fn resolve<'a>(&'a self, _url: &'a str) -> BoxFuture<'a, Option<Url>> {
+            let url = if self.moved.load(Ordering::Relaxed) {
+                self.new
+            } else {
+                self.old
+            };
+
+            async move { Some(url) }.boxed()
+        }
+    }
+
+    fn tcp_url(addr: SocketAddr) -> Url {
+        Url::Tcp(Address::from(addr))
+    }
+
+    #[test]
+    #[serial]
+    fn reconnect_to_moved_host() {
+        whitelist::init();
+
+        block_on(async {
+            // Only the address the host moves to accepts connections.
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let new_addr = listener.local_addr().unwrap();
+            let old_addr = SocketAddr::new("127.0.0.2".parse().unwrap(), new_addr.port());
+
+            let moved = StdArc::new(AtomicBool::new(false));
+            let hosts: HostEndpoints = Arc::new(DashMap::new());
+            let (mut commands, command_receiver) = command_channel();
+            let (notifier, events) = event_channel();
+            let (publisher, _published) = event_channel();
+            let (endpoint_sd_sender, endpoint_sd_listener) = oneshot::channel();
+            let (resolver_sd_sender, resolver_sd_listener) = oneshot::channel();
+
+            let endpoint_worker = spawn(
+                EndpointWorker::new(
+                    command_receiver,
+                    events,
+                    endpoint_sd_listener,
+                    notifier.clone(),
+                    publisher,
+                    hosts.clone(),
+                    ConnectionTimeouts::default(),
+                )
+                .run(),
+            );
+
+            let (responder, requester) = response_channel();
+            commands
+                .send(Command::AddEndpoint {
+                    url: tcp_url(old_addr),
+                    responder: Some(responder),
+                })
+                .await
+                .unwrap();
+            assert!(requester.await.unwrap());
+            hosts.insert(HOST.to_string(), tcp_url(old_addr));
+
+            let resolver_worker = spawn(
+                ResolverWorker::new(
+                    StubResolver {
+                        old: tcp_url(old_addr),
+                        new: tcp_url(new_addr),
+                        moved: moved.clone(),
+                    },
+                    hosts.clone(),
+                    Duration::from_millis(10),
+                    notifier,
+                    resolver_sd_listener,
+                )
+                .run(),
+            );
+
+            // As long as the address doesn't change, no connection attempt is made.
+            assert!(timeout(Duration::from_millis(50), listener.accept()).await.is_err());
+
+            moved.store(true, Ordering::Relaxed);
+
+            assert!(
+                timeout(Duration::from_secs(1), listener.accept()).await.is_ok(),
+                "Expected a connection attempt to the new address."
+            );
+
+            let whitelist = whitelist::get();
+            assert!(whitelist.contains_address(&new_addr.ip()));
+            assert!(!whitelist.contains_address(&old_addr.ip()));
+            assert_eq!(*hosts.get(HOST).unwrap().value(), tcp_url(new_addr));
+
+            resolver_sd_sender.send(()).unwrap();
+            resolver_worker.await.unwrap();
+            endpoint_sd_sender.send(()).unwrap();
+            endpoint_worker.await.unwrap();
+        });
+
+        whitelist::drop();
+    }
+}
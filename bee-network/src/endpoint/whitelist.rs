@@ -72,10 +72,16 @@ impl WhiteList {
         self.inner.remove(epid).is_some()
     }
 
-    // TODO: re-resolve domain names
-    #[allow(dead_code)]
-    pub async fn refresh(&self) {
-        todo!("implement refresh")
+    /// Replaces the IP address of an endpoint that is already whitelisted, e.g. because its hostname now resolves to
+    /// another address, and returns whether it was whitelisted.
+    pub fn update(&self, epid: &EpId, addr: IpAddr) -> bool {
+        match self.inner.get_mut(epid) {
+            Some(mut whitelisted) => {
+                *whitelisted = addr;
+                true
+            }
+            None => false,
+        }
     }
 
     pub fn contains_address(&self, addr: &IpAddr) -> bool {
@@ -86,8 +92,14 @@ impl WhiteList {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::address::Address;
+
     use serial_test::serial;
 
+    fn epid(addr: &str) -> EpId {
+        Address::from(addr.parse::<std::net::SocketAddr>().unwrap()).into()
+    }
+
     #[test]
     #[serial]
     fn init_get_and_drop() {
@@ -127,4 +139,25 @@ mod tests {
         let _ = get();
         drop();
     }
+
+    #[test]
+    fn update_replaces_address() {
+        let whitelist = WhiteList::new();
+        let epid = epid("10.0.0.1:15600");
+        whitelist.insert(epid, "10.0.0.1".parse().unwrap());
+
+        assert!(whitelist.update(&epid, "10.0.0.2".parse().unwrap()));
+
+        assert!(whitelist.contains_address(&"10.0.0.2".parse().unwrap()));
+        assert!(!whitelist.contains_address(&"10.0.0.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn update_ignores_removed_endpoints() {
+        let whitelist = WhiteList::new();
+
+        assert!(!whitelist.update(&epid("10.0.0.1:15600"), "10.0.0.2".parse().unwrap()));
+
+        assert!(!whitelist.contains_address(&"10.0.0.2".parse().unwrap()));
+    }
 }
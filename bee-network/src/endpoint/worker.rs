@@ -15,11 +15,11 @@ use crate::{
     address::url::{Protocol, Url},
     commands::{Command, CommandReceiver as Commands, Responder},
     constants::CONNECT_INTERVAL,
-    endpoint::{outbox::Outbox, store::Endpoints, Endpoint as Ep, EndpointId as EpId},
+    endpoint::{outbox::Outbox, resolver::HostEndpoints, store::Endpoints, Endpoint as Ep, EndpointId as EpId},
     errors::Result,
     events::{Event, EventPublisher as Notifier, EventPublisher as Publisher, EventSubscriber as Events},
     shutdown::ShutdownListener as Shutdown,
-    tcp,
+    tcp::{self, ConnectionTimeouts},
     utils::time,
 };
 
@@ -29,6 +29,7 @@ use async_std::{
 };
 use futures::{select, sink::SinkExt, FutureExt};
 use log::*;
+use url::Url as ExternUrl;
 
 use std::time::Duration;
 
@@ -38,6 +39,8 @@ pub struct EndpointWorker {
     shutdown: Shutdown,
     notifier: Notifier,
     publisher: Publisher,
+    hosts: HostEndpoints,
+    timeouts: ConnectionTimeouts,
 }
 
 impl EndpointWorker {
@@ -47,6 +50,8 @@ impl EndpointWorker {
         shutdown: Shutdown,
         notifier: Notifier,
         publisher: Publisher,
+        hosts: HostEndpoints,
+        timeouts: ConnectionTimeouts,
     ) -> Self {
         Self {
             commands,
@@ -54,6 +59,8 @@ impl EndpointWorker {
             shutdown,
             notifier,
             publisher,
+            hosts,
+            timeouts,
         }
     }
 
@@ -94,10 +101,22 @@ impl EndpointWorker {
                                 };
                             }
                         },
+                        Command::AddHostEndpoint { url, responder } => {
+                            let res = add_host_endpoint(&mut contacts, url, &self.hosts, &mut self.notifier).await?;
+
+                            if let Some(responder) = responder {
+                                if responder.send(res).is_err() {
+                                    warn!("[Endp ] Error sending command response");
+                                };
+                            }
+                        },
                         Command::RemoveEndpoint { epid, responder } => {
                             let res = rmv_endpoint(epid, &mut contacts, &mut connected, &mut outbox,
                                 &mut self.notifier).await?;
 
+                            // Stop re-resolving the endpoint if it was added by hostname
+                            self.hosts.retain(|_, url| EpId::from(*url) != epid);
+
                             if let Some(responder) = responder {
                                 if responder.send(res).is_err() {
                                     warn!("[Endp ] Error sending command response");
@@ -105,7 +124,8 @@ impl EndpointWorker {
                             }
                         },
                         Command::Connect { epid, responder } => {
-                            try_connect(epid, &mut contacts, &mut connected, responder, &mut self.notifier).await?;
+                            try_connect(epid, &mut contacts, &mut connected, responder,
+                                self.timeouts, &mut self.notifier).await?;
                         },
                         Command::Disconnect { epid, responder } => {
                             let is_disconnected = disconnect(epid, &mut connected, &mut outbox).await;
@@ -202,7 +222,8 @@ impl EndpointWorker {
 
                             // TODO: do not try to reconnect to duplicate endpoints
                             // NOTE: 'try_connect' will check if 'epid' is part of the contact list
-                            try_connect(epid, &mut contacts, &mut connected, None, &mut self.notifier).await?;
+                            try_connect(epid, &mut contacts, &mut connected, None,
+                                self.timeouts, &mut self.notifier).await?;
                         }
                         Event::MessageSent { epid, num_bytes } => {
                             publisher.send(Event::MessageSent {
@@ -217,7 +238,27 @@ impl EndpointWorker {
                             }).await?
                         },
                         Event::TryConnect { epid, responder } => {
-                            try_connect(epid, &mut contacts, &mut connected, responder, &mut self.notifier).await?;
+                            try_connect(epid, &mut contacts, &mut connected, responder,
+                                self.timeouts, &mut self.notifier).await?;
+                        }
+                        Event::EndpointResolved { epid, host, url } => {
+                            // NOTE: the endpoint might have been removed or already been updated in the mean time.
+                            let is_current = self
+                                .hosts
+                                .get(&host)
+                                .is_some_and(|current| EpId::from(*current.value()) == epid);
+
+                            if is_current {
+                                // Replacing the endpoint also replaces its IP in the whitelist
+                                rmv_endpoint(epid, &mut contacts, &mut connected, &mut outbox,
+                                    &mut self.notifier).await?;
+                                add_endpoint(&mut contacts, url, &mut self.notifier).await?;
+
+                                self.hosts.insert(host, url);
+
+                                try_connect(url.into(), &mut contacts, &mut connected, None,
+                                    self.timeouts, &mut self.notifier).await?;
+                            }
                         }
                         _ => (),
                     }
@@ -257,6 +298,36 @@ async fn add_endpoint(contacts: &mut Endpoints, url: Url, notifier: &mut Notifie
     }
 }
 
+#[inline(always)]
+async fn add_host_endpoint(
+    contacts: &mut Endpoints,
+    url: String,
+    hosts: &HostEndpoints,
+    notifier: &mut Notifier,
+) -> Result<bool> {
+    match Url::from_url_str(&url).await {
+        Ok(resolved) => {
+            let is_added = add_endpoint(contacts, resolved, notifier).await?;
+
+            // Only hostnames need to be re-resolved, IP addresses won't change
+            let is_hostname = ExternUrl::parse(&url)
+                .ok()
+                .and_then(|url| url.domain().map(|_| ()))
+                .is_some();
+
+            if is_added && is_hostname {
+                hosts.insert(url, resolved);
+            }
+
+            Ok(is_added)
+        }
+        Err(e) => {
+            warn!("[Endp ] Resolving {} failed: {:?}.", url, e);
+            Ok(false)
+        }
+    }
+}
+
 #[inline(always)]
 async fn rmv_endpoint(
     epid: EpId,
@@ -298,6 +369,7 @@ async fn try_connect(
     contacts: &mut Endpoints,
     connected: &mut Endpoints,
     responder: Option<Responder<bool>>,
+    timeouts: ConnectionTimeouts,
     notifier: &mut Notifier,
 ) -> Result<bool> {
     // Try to find the endpoint in our servers list.
@@ -316,7 +388,10 @@ async fn try_connect(
         } else {
             match ep.protocol {
                 Protocol::Tcp => {
-                    if tcp::try_connect(&ep.id, &ep.address, notifier.clone()).await.is_ok() {
+                    if tcp::try_connect(&ep.id, &ep.address, timeouts, notifier.clone())
+                        .await
+                        .is_ok()
+                    {
                         connected.insert(ep.clone());
                         if let Some(responder) = responder {
                             match responder.send(true) {
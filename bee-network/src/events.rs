@@ -10,7 +10,7 @@
 // See the License for the specific language governing permissions and limitations under the License.
 
 use crate::{
-    address::Address,
+    address::{url::Url, Address},
     commands::Responder,
     endpoint::{origin::Origin, outbox::BytesSender, Endpoint, EndpointId},
 };
@@ -110,6 +110,18 @@ pub enum Event {
         /// The success responder.
         responder: Option<Responder<bool>>,
     },
+
+    /// Signals that an `Endpoint` added by hostname now resolves to a different address.
+    EndpointResolved {
+        /// The id of the `Endpoint` before the address change.
+        epid: EndpointId,
+
+        /// The url string the `Endpoint` was added with.
+        host: String,
+
+        /// The `Url` with the newly resolved address.
+        url: Url,
+    },
 }
 
 impl fmt::Display for Event {
@@ -153,6 +165,10 @@ impl fmt::Display for Event {
             }
 
             Event::TryConnect { epid, .. } => write!(f, "Event::TryConnect {{ {} }}", epid),
+
+            Event::EndpointResolved { epid, url, .. } => {
+                write!(f, "Event::EndpointResolved {{ {}, url: {} }}", epid, url)
+            }
         }
     }
 }
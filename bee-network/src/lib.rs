@@ -47,14 +47,21 @@ mod tcp;
 mod config;
 mod utils;
 
-use endpoint::{whitelist, worker::EndpointWorker as EpWorker};
+use endpoint::{
+    resolver::{DnsResolver, ResolverWorker},
+    whitelist,
+    worker::EndpointWorker as EpWorker,
+};
 use events::EventSubscriber as Events;
 use tcp::worker::TcpWorker;
 // use udp::worker::UdpWorker;
 
-use async_std::task::spawn;
+use async_std::{sync::Arc, task::spawn};
+use dashmap::DashMap;
 use futures::channel::oneshot;
 
+use std::time::Duration;
+
 /// Initializes the network layer.
 pub fn init(config: NetworkConfig) -> (Network, Shutdown, Events) {
     let (command_sender, commands) = commands::command_channel();
@@ -64,7 +71,9 @@ pub fn init(config: NetworkConfig) -> (Network, Shutdown, Events) {
     let mut shutdown = Shutdown::new();
 
     let (epw_sd_sender, epw_shutdown) = oneshot::channel();
-    let (tcp_sd_sender, tcp_shutdown) = oneshot::channel();
+    let (rslv_sd_sender, rslv_shutdown) = oneshot::channel();
+
+    let hosts = Arc::new(DashMap::new());
     // let (udp_sd_sender, udp_shutdown) = oneshot::channel();
 
     let ep_worker = EpWorker::new(
@@ -73,19 +82,44 @@ pub fn init(config: NetworkConfig) -> (Network, Shutdown, Events) {
         epw_shutdown,
         internal_event_sender.clone(),
         event_sender,
+        hosts.clone(),
+        config.connection_timeouts(),
+    );
+
+    let rslv_worker = ResolverWorker::new(
+        DnsResolver,
+        hosts,
+        Duration::from_secs(config.resolve_interval),
+        internal_event_sender.clone(),
+        rslv_shutdown,
     );
 
-    let tcp_worker = TcpWorker::new(config.socket_addr(), internal_event_sender, tcp_shutdown);
     // let udp_worker = UdpWorker::new(binding_addr, internal_event_sender.clone(), udp_shutdown);
 
     shutdown.add_notifier(epw_sd_sender);
-    shutdown.add_notifier(tcp_sd_sender);
     // shutdown.add_notifier(udp_sd_sender);
 
     shutdown.add_task(spawn(ep_worker.run()));
-    shutdown.add_task(spawn(tcp_worker.run()));
+
+    shutdown.add_notifier(rslv_sd_sender);
+    shutdown.add_task(spawn(rslv_worker.run()));
     // shutdown.add_task(spawn(udp_worker.run()));
 
+    // NOTE: one TCP worker per binding address, all publishing to the same internal event channel.
+    for binding_addr in config.socket_addrs() {
+        let (tcp_sd_sender, tcp_shutdown) = oneshot::channel();
+
+        let tcp_worker = TcpWorker::new(
+            binding_addr,
+            config.listener_config(),
+            internal_event_sender.clone(),
+            tcp_shutdown,
+        );
+
+        shutdown.add_notifier(tcp_sd_sender);
+        shutdown.add_task(spawn(tcp_worker.run()));
+    }
+
     whitelist::init();
 
     (Network::new(command_sender), shutdown, events)
@@ -50,7 +50,32 @@ impl fmt::Display for TcpConnection {
 impl Eq for TcpConnection {}
 impl PartialEq for TcpConnection {
     fn eq(&self, other: &Self) -> bool {
-        // TODO: use socket address instead of IP
-        self.remote_addr.ip() == other.remote_addr.ip()
+        self.remote_addr == other.remote_addr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_std::{net::TcpListener, task::block_on};
+
+    #[test]
+    fn connections_differing_only_by_port_are_not_equal() {
+        block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+
+            let _first = TcpStream::connect(addr).await.unwrap();
+            let _second = TcpStream::connect(addr).await.unwrap();
+
+            let first = TcpConnection::new(listener.accept().await.unwrap().0, Origin::Inbound).unwrap();
+            let second = TcpConnection::new(listener.accept().await.unwrap().0, Origin::Inbound).unwrap();
+
+            assert_eq!(first.remote_addr.ip(), second.remote_addr.ip());
+            assert_ne!(first.remote_addr.port(), second.remote_addr.port());
+            assert!(first != second);
+            assert!(first == first.clone());
+        });
     }
 }
@@ -26,12 +26,39 @@ use crate::{
     events::{Event, EventPublisher as Notifier},
 };
 
-use async_std::{net::TcpStream, sync::Arc, task::spawn};
+use async_std::{io, net::TcpStream, sync::Arc, task::spawn};
 use futures::{channel::oneshot, prelude::*, select};
 use log::*;
 
+use std::time::Duration;
+
+/// Read and write timeouts of TCP connections, `None` meaning no timeout.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ConnectionTimeouts {
+    /// Time after which a connection that didn't receive any bytes is closed.
+    pub(crate) read: Option<Duration>,
+    /// Time after which a connection that couldn't send its bytes is closed.
+    pub(crate) write: Option<Duration>,
+}
+
+/// Options of the socket listening for incoming TCP connections, and of the connections it accepts.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ListenerConfig {
+    /// Whether to set `SO_REUSEADDR`, so that the port can be bound again while old connections are in `TIME_WAIT`.
+    pub(crate) reuse_address: bool,
+    /// Maximum number of pending connections.
+    pub(crate) backlog: u32,
+    /// Read and write timeouts of accepted connections.
+    pub(crate) timeouts: ConnectionTimeouts,
+}
+
 /// Tries to connect to an endpoint.
-pub(crate) async fn try_connect(epid: &EpId, addr: &Address, notifier: Notifier) -> ConnectionResult<()> {
+pub(crate) async fn try_connect(
+    epid: &EpId,
+    addr: &Address,
+    timeouts: ConnectionTimeouts,
+    notifier: Notifier,
+) -> ConnectionResult<()> {
     info!("[TCP  ] Trying to connect to {}...", epid);
 
     match TcpStream::connect(**addr).await {
@@ -51,7 +78,7 @@ pub(crate) async fn try_connect(epid: &EpId, addr: &Address, notifier: Notifier)
                 Origin::Outbound
             );
 
-            Ok(spawn_connection_workers(conn, notifier).await?)
+            Ok(spawn_connection_workers(conn, timeouts, notifier).await?)
         }
         Err(e) => {
             warn!("[TCP  ] Connecting to {} failed (Endpoint offline?).", epid);
@@ -61,7 +88,11 @@ pub(crate) async fn try_connect(epid: &EpId, addr: &Address, notifier: Notifier)
     }
 }
 
-pub(crate) async fn spawn_connection_workers(conn: TcpConnection, mut notifier: Notifier) -> ConnectionResult<()> {
+pub(crate) async fn spawn_connection_workers(
+    conn: TcpConnection,
+    timeouts: ConnectionTimeouts,
+    mut notifier: Notifier,
+) -> ConnectionResult<()> {
     debug!("[TCP  ] Spawning TCP connection workers...");
 
     let addr: Address = conn.remote_addr.into();
@@ -73,13 +104,41 @@ pub(crate) async fn spawn_connection_workers(conn: TcpConnection, mut notifier:
     let (sender, receiver) = bytes_channel();
     let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
 
-    spawn(writer(ep.id, conn.stream.clone(), receiver, shutdown_sender));
-    spawn(reader(ep.id, conn.stream.clone(), notifier.clone(), shutdown_receiver));
+    spawn(writer(
+        ep.id,
+        conn.stream.clone(),
+        receiver,
+        timeouts.write,
+        notifier.clone(),
+        shutdown_sender,
+    ));
+    spawn(reader(
+        ep.id,
+        conn.stream.clone(),
+        timeouts.read,
+        notifier.clone(),
+        shutdown_receiver,
+    ));
 
     Ok(notifier.send(Event::NewConnection { ep, origin, sender }).await?)
 }
 
-async fn writer(epid: EpId, stream: Arc<TcpStream>, bytes_rx: BytesReceiver, sd: oneshot::Sender<()>) {
+/// Runs `future`, failing with `TimedOut` if `duration` is set and it doesn't complete in time.
+async fn with_timeout<T>(duration: Option<Duration>, future: impl Future<Output = io::Result<T>>) -> io::Result<T> {
+    match duration {
+        Some(duration) => io::timeout(duration, future).await,
+        None => future.await,
+    }
+}
+
+async fn writer(
+    epid: EpId,
+    stream: Arc<TcpStream>,
+    bytes_rx: BytesReceiver,
+    write_timeout: Option<Duration>,
+    mut notifier: Notifier,
+    sd: oneshot::Sender<()>,
+) {
     debug!("[TCP  ] Starting connection writer task for {}...", epid);
 
     let mut stream = &*stream;
@@ -90,10 +149,20 @@ async fn writer(epid: EpId, stream: Arc<TcpStream>, bytes_rx: BytesReceiver, sd:
             bytes_out = bytes_rx.next() => {
                 if let Some(bytes_out) = bytes_out {
 
-                    match stream.write_all(&*bytes_out).await {
+                    match with_timeout(write_timeout, stream.write_all(&bytes_out)).await {
                         Ok(_) => {
                             // NOTE: if we should need it, we can raise [`Event::BytesSent`] here.
                         },
+                        Err(e) if e.kind() == io::ErrorKind::TimedOut => {
+                            warn!("[TCP  ] Sending bytes to {} timed out.", epid);
+
+                            if notifier.send(Event::LostConnection { epid }).await.is_err() {
+                                warn!("[TCP  ] Failed to send 'LostConnection' notification.");
+                            }
+
+                            // NOTE: the peer stalled, shutting down the reader closes the connection
+                            break;
+                        },
                         Err(e) => {
                             error!("[TCP  ] Sending bytes failed.");
                             error!("[TCP  ] Error was: {:?}.", e);
@@ -115,7 +184,13 @@ async fn writer(epid: EpId, stream: Arc<TcpStream>, bytes_rx: BytesReceiver, sd:
     debug!("[TCP  ] Connection writer event loop for {} stopped.", epid);
 }
 
-async fn reader(epid: EpId, stream: Arc<TcpStream>, mut notifier: Notifier, mut sd: oneshot::Receiver<()>) {
+async fn reader(
+    epid: EpId,
+    stream: Arc<TcpStream>,
+    read_timeout: Option<Duration>,
+    mut notifier: Notifier,
+    mut sd: oneshot::Receiver<()>,
+) {
     debug!("[TCP  ] Starting connection reader event loop for {}...", epid);
 
     let mut stream = &*stream;
@@ -124,7 +199,7 @@ async fn reader(epid: EpId, stream: Arc<TcpStream>, mut notifier: Notifier, mut
 
     loop {
         select! {
-            num_read = stream.read(&mut buffer).fuse() => {
+            num_read = with_timeout(read_timeout, stream.read(&mut buffer)).fuse() => {
                 match num_read {
                     Ok(num_read) => {
                         if num_read == 0 {
@@ -145,6 +220,16 @@ async fn reader(epid: EpId, stream: Arc<TcpStream>, mut notifier: Notifier, mut
                             }
                         }
                     },
+                    Err(e) if e.kind() == io::ErrorKind::TimedOut => {
+                        warn!("[TCP  ] Receiving bytes from {} timed out.", epid);
+
+                        if notifier.send(Event::LostConnection { epid }).await.is_err() {
+                            warn!("[TCP  ] Failed to send 'LostConnection' notification.");
+                        }
+
+                        // NOTE: the peer stalled, the writer shuts down once the connection is dropped
+                        break;
+                    },
                     Err(e) => {
                         error!("[TCP  ] Receiveing bytes failed.");
                         error!("[TCP  ] Error was: {:?}.", e);
@@ -159,3 +244,74 @@ async fn reader(epid: EpId, stream: Arc<TcpStream>, mut notifier: Notifier, mut
     }
     debug!("[TCP  ] Connection reader event loop for {} stopped.", epid);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::event_channel;
+
+    use async_std::{future::timeout, net::TcpListener, task::block_on};
+
+    // Returns both ends of a local connection, the accepted one wrapped into a `TcpConnection`.
+    async fn connection() -> (TcpConnection, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+        let (server, _) = listener.accept().await.unwrap();
+
+        (TcpConnection::new(server, Origin::Inbound).unwrap(), client)
+    }
+
+    #[test]
+    fn close_connection_after_read_timeout() {
+        let (notifier, mut events) = event_channel();
+
+        block_on(async {
+            let (conn, _client) = connection().await;
+            let timeouts = ConnectionTimeouts {
+                read: Some(Duration::from_millis(50)),
+                write: None,
+            };
+
+            spawn_connection_workers(conn, timeouts, notifier).await.unwrap();
+
+            // NOTE: dropping the sender would shut the connection down.
+            let _sender = match events.next().await {
+                Some(Event::NewConnection { sender, .. }) => sender,
+                _ => panic!("Expected a new connection event."),
+            };
+
+            match timeout(Duration::from_secs(1), events.next()).await {
+                Ok(Some(Event::LostConnection { .. })) => (),
+                _ => panic!("Expected a lost connection event."),
+            }
+        });
+    }
+
+    #[test]
+    fn close_connection_after_write_timeout() {
+        let (notifier, mut events) = event_channel();
+
+        block_on(async {
+            // NOTE: the client never reads, so that the socket buffers fill up.
+            let (conn, _client) = connection().await;
+            let timeouts = ConnectionTimeouts {
+                read: None,
+                write: Some(Duration::from_millis(100)),
+            };
+
+            spawn_connection_workers(conn, timeouts, notifier).await.unwrap();
+
+            let mut sender = match events.next().await {
+                Some(Event::NewConnection { sender, .. }) => sender,
+                _ => panic!("Expected a new connection event."),
+            };
+
+            sender.send(Arc::new(vec![0u8; 64 * 1024 * 1024])).await.unwrap();
+
+            match timeout(Duration::from_secs(5), events.next()).await {
+                Ok(Some(Event::LostConnection { .. })) => (),
+                _ => panic!("Expected a lost connection event."),
+            }
+        });
+    }
+}
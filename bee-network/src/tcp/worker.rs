@@ -17,22 +17,27 @@ use crate::{
     shutdown::ShutdownListener as Shutdown,
 };
 
-use super::{connection::TcpConnection, spawn_connection_workers};
+use super::{connection::TcpConnection, spawn_connection_workers, ListenerConfig};
 
 use async_std::net::TcpListener;
 use futures::{prelude::*, select};
 use log::*;
+use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+
+use std::{convert::TryInto, io, net};
 
 pub(crate) struct TcpWorker {
     binding_addr: Address,
+    config: ListenerConfig,
     notifier: Notifier,
     shutdown: Shutdown,
 }
 
 impl TcpWorker {
-    pub fn new(binding_addr: Address, notifier: Notifier, shutdown: Shutdown) -> Self {
+    pub fn new(binding_addr: Address, config: ListenerConfig, notifier: Notifier, shutdown: Shutdown) -> Self {
         Self {
             binding_addr,
+            config,
             notifier,
             shutdown,
         }
@@ -41,7 +46,7 @@ impl TcpWorker {
     pub async fn run(mut self) -> Result<()> {
         debug!("[TCP  ] Starting TCP worker...");
 
-        let listener = TcpListener::bind(*self.binding_addr).await?;
+        let listener = TcpListener::from(bind_listener(&self.binding_addr, &self.config)?);
 
         info!("[TCP  ] Accepting connections on {}.", listener.local_addr()?);
 
@@ -64,13 +69,8 @@ impl TcpWorker {
                                     }
                                 };
 
-                                let whitelist = whitelist::get();
-
-                                // Update IP addresses if necessary
-                                // whitelist.refresh().await;
-
                                 // Immediatedly drop stream, if it's associated IP address isn't whitelisted
-                                if !whitelist.contains_address(&conn.remote_addr.ip()) {
+                                if !whitelist::get().contains_address(&conn.remote_addr.ip()) {
                                     warn!("[TCP  ] Contacted by unknown IP address '{}'.", &conn.remote_addr.ip());
                                     warn!("[TCP  ] Connection disallowed.");
                                     continue;
@@ -82,9 +82,10 @@ impl TcpWorker {
                                     Origin::Inbound
                                 );
 
-                                match spawn_connection_workers(conn, self.notifier.clone()).await {
-                                    Ok(_) => (),
-                                    Err(_) => (),
+                                if let Err(e) =
+                                    spawn_connection_workers(conn, self.config.timeouts, self.notifier.clone()).await
+                                {
+                                    error!("[TCP  ] Spawning connection workers failed: {:?}.", e);
                                 }
                             }
                             Err(e) => {
@@ -106,3 +107,139 @@ impl TcpWorker {
         Ok(())
     }
 }
+
+/// Binds a listening socket to `addr`, optionally with `SO_REUSEADDR`, queueing at most `config.backlog` pending
+/// connections.
+fn bind_listener(addr: &Address, config: &ListenerConfig) -> io::Result<net::TcpListener> {
+    let domain = if addr.is_ipv4() { Domain::ipv4() } else { Domain::ipv6() };
+    let socket = Socket::new(domain, Type::stream(), Some(Protocol::tcp()))?;
+
+    socket.set_reuse_address(config.reuse_address)?;
+
+    // NOTE: otherwise an IPv6 wildcard socket also claims the port for IPv4, and binding the IPv4 wildcard address to
+    // the same port fails.
+    if addr.is_ipv6() {
+        socket.set_only_v6(true)?;
+    }
+
+    socket.bind(&SockAddr::from(**addr))?;
+    socket.listen(config.backlog.try_into().unwrap_or(i32::MAX))?;
+
+    let listener = socket.into_tcp_listener();
+    listener.set_nonblocking(true)?;
+
+    Ok(listener)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        endpoint::EndpointId as EpId, events::event_channel, events::Event, shutdown::Shutdown, tcp::ConnectionTimeouts,
+    };
+
+    use async_std::{
+        net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream},
+        task::{self, block_on, spawn},
+    };
+    use futures::channel::oneshot;
+    use serial_test::serial;
+
+    use std::time::Duration;
+
+    const CONFIG: ListenerConfig = ListenerConfig {
+        reuse_address: true,
+        backlog: 128,
+        timeouts: ConnectionTimeouts {
+            read: None,
+            write: None,
+        },
+    };
+
+    fn unused_address(ip: IpAddr) -> Address {
+        // NOTE: let the OS pick a free port, and release it right away so that the worker can bind to it.
+        let listener = std::net::TcpListener::bind(SocketAddr::new(ip, 0)).unwrap();
+        listener.local_addr().unwrap().into()
+    }
+
+    async fn connect(addr: &Address) -> TcpStream {
+        // NOTE: the worker binds asynchronously, so we might have to try a couple of times.
+        for _ in 0..100 {
+            if let Ok(stream) = TcpStream::connect(**addr).await {
+                return stream;
+            }
+            task::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("Could not connect to {}", addr);
+    }
+
+    #[test]
+    #[serial]
+    fn accept_connections_on_multiple_addresses() {
+        whitelist::init();
+
+        let (notifier, mut events) = event_channel();
+        let mut shutdown = Shutdown::new();
+
+        let addrs = vec![
+            unused_address(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+            unused_address(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+        ];
+
+        for addr in &addrs {
+            whitelist::get().insert(EpId::from(*addr), addr.ip());
+
+            let (sd_sender, sd_listener) = oneshot::channel();
+
+            shutdown.add_notifier(sd_sender);
+            shutdown.add_task(spawn(
+                TcpWorker::new(*addr, CONFIG, notifier.clone(), sd_listener).run(),
+            ));
+        }
+
+        block_on(async {
+            let mut streams = vec![];
+
+            for addr in &addrs {
+                streams.push(connect(addr).await);
+
+                match events.next().await {
+                    Some(Event::NewConnection {
+                        ep,
+                        origin: Origin::Inbound,
+                        ..
+                    }) => assert_eq!(addr.ip(), ep.address.ip()),
+                    _ => panic!("Expected a new inbound connection"),
+                }
+            }
+
+            shutdown.execute().await;
+        });
+    }
+
+    #[test]
+    fn rebind_with_reuse_address() {
+        let addr = unused_address(IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+        let listener = bind_listener(&addr, &CONFIG).unwrap();
+        let client = std::net::TcpStream::connect(*addr).unwrap();
+        listener.set_nonblocking(false).unwrap();
+        let (server, _) = listener.accept().unwrap();
+
+        // NOTE: closing the connection from the listening side first leaves it in TIME_WAIT on the bound port.
+        std::mem::drop(server);
+        std::mem::drop(client);
+        std::mem::drop(listener);
+
+        assert!(bind_listener(&addr, &CONFIG).is_ok());
+    }
+
+    #[test]
+    fn bind_ipv4_and_ipv6_wildcards_to_same_port() {
+        let v4 = unused_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        let v6 = SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), v4.port().0).into();
+
+        let _v4_listener = bind_listener(&v4, &CONFIG).unwrap();
+        let _v6_listener = bind_listener(&v6, &CONFIG).unwrap();
+    }
+}
@@ -18,7 +18,7 @@ use node::Node;
 
 use async_std::task::block_on;
 
-use std::fs;
+use std::{fs, process};
 
 fn main() {
     let config_builder = match fs::read_to_string(CONFIG_PATH) {
@@ -35,11 +35,13 @@ fn main() {
 
     let config = config_builder.finish();
 
-    let (network, shutdown, receiver) = bee_network::init(config.network);
+    let (network, shutdown, receiver) = bee_network::init(config.network.clone());
 
     // TODO: proper shutdown
     let mut node = Node::new(config, network, shutdown, receiver);
 
-    block_on(node.init());
+    if block_on(node.init()).is_err() {
+        process::exit(1);
+    }
     block_on(node.run());
 }
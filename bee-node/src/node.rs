@@ -19,7 +19,7 @@ use bee_common::logger;
 use bee_ledger::{LedgerWorker, LedgerWorkerEvent};
 use bee_network::{Address, Command::Connect, EndpointId, Event, EventSubscriber, Network, Origin, Shutdown};
 use bee_peering::{PeerManager, StaticPeerManager};
-use bee_protocol::Protocol;
+use bee_protocol::{Protocol, ProtocolInitError};
 use bee_snapshot::{SnapshotMetadata, SnapshotState};
 use bee_tangle::tangle;
 
@@ -109,7 +109,7 @@ impl Node {
         }
     }
 
-    pub async fn init(&mut self) {
+    pub async fn init(&mut self) -> Result<(), ProtocolInitError> {
         logger::init(self.config.log_level);
 
         info!("[Node ] {} v{}-{}.", BEE_NAME, BEE_VERSION, &BEE_GIT_COMMIT[0..7]);
@@ -120,7 +120,7 @@ impl Node {
         bee_tangle::init();
 
         info!("[Node ] Reading snapshot metadata...");
-        match SnapshotMetadata::new(self.config.snapshot.meta_file_path()) {
+        let snapshot_index = match SnapshotMetadata::new(self.config.snapshot.meta_file_path()) {
             Ok(snapshot_metadata) => {
                 info!(
                     "[Node ] Read snapshot metadata from {} with index {}, {} solid entry points and {} seen milestones.",
@@ -130,8 +130,6 @@ impl Node {
                     snapshot_metadata.seen_milestones().len(),
                 );
                 tangle().update_solid_milestone_index(snapshot_metadata.index().into());
-                // TODO get from database
-                tangle().update_snapshot_milestone_index(snapshot_metadata.index().into());
                 tangle().add_solid_entry_point(Hash::zeros());
                 for solid_entry_point in snapshot_metadata.solid_entry_points() {
                     tangle().add_solid_entry_point(*solid_entry_point);
@@ -139,14 +137,18 @@ impl Node {
                 for seen_milestone in snapshot_metadata.seen_milestones() {
                     // TODO request ?
                 }
+                Some(snapshot_metadata.index())
             }
             // TODO exit ?
-            Err(e) => error!(
-                "[Node ] Failed to read snapshot metadata file \"{}\": {:?}.",
-                self.config.snapshot.meta_file_path(),
-                e
-            ),
-        }
+            Err(e) => {
+                error!(
+                    "[Node ] Failed to read snapshot metadata file \"{}\": {:?}.",
+                    self.config.snapshot.meta_file_path(),
+                    e
+                );
+                None
+            }
+        };
 
         info!("[Node ] Reading snapshot state...");
         let snapshot_state = match SnapshotState::new(self.config.snapshot.state_file_path()) {
@@ -168,7 +170,16 @@ impl Node {
             }
         };
 
-        Protocol::init(self.config.protocol.clone(), self.network.clone()).await;
+        if let Err(e) = Protocol::init(self.config.protocol.clone(), self.network.clone()).await {
+            error!("[Node ] Initializing protocol failed: {:?}.", e);
+            return Err(e);
+        }
+
+        // NOTE: set through the protocol so that the pruner follows the snapshot index.
+        if let Some(snapshot_index) = snapshot_index {
+            // TODO get from database
+            Protocol::update_snapshot_milestone_index(snapshot_index).await;
+        }
 
         // TODO config
         let (ledger_worker_tx, ledger_worker_rx) = mpsc::channel(1000);
@@ -177,6 +188,8 @@ impl Node {
         spawn(LedgerWorker::new(snapshot_state.into_state()).run(ledger_worker_rx, ledger_worker_shutdown_rx));
 
         info!("[Node ] Initialized.");
+
+        Ok(())
     }
 }
 
@@ -11,9 +11,8 @@
 
 use crate::{r#static::config::StaticPeeringConfig, PeerManager};
 
-use bee_network::{Command::AddEndpoint, Network, Url};
+use bee_network::{Command::AddHostEndpoint, Network};
 
-use async_std::task::block_on;
 use async_trait::async_trait;
 use log::warn;
 
@@ -31,16 +30,16 @@ impl StaticPeerManager {
     }
 
     async fn add_endpoint(&mut self, url: &str) {
-        // TODO block ?
-        match block_on(Url::from_url_str(url)) {
-            Ok(url) => {
-                if let Err(e) = self.network.send(AddEndpoint { url, responder: None }).await {
-                    warn!("[StaticPeerManager ] Failed to add endpoint \"{}\": {}", url, e);
-                }
-            }
-            Err(e) => {
-                warn!("[StaticPeerManager ] Failed to resolve URL \"{}\": {}", url, e);
-            }
+        // NOTE: the network resolves the URL, and keeps re-resolving it if it contains a hostname.
+        if let Err(e) = self
+            .network
+            .send(AddHostEndpoint {
+                url: url.to_string(),
+                responder: None,
+            })
+            .await
+        {
+            warn!("[StaticPeerManager ] Failed to add endpoint \"{}\": {}", url, e);
         }
     }
 }
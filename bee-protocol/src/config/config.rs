@@ -9,6 +9,8 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
+use crate::worker::CacheEviction;
+
 use bee_bundle::{Address, TransactionField};
 use bee_crypto::SpongeType;
 use bee_ternary::{T1B1Buf, T5B1Buf, TryteBuf};
@@ -17,6 +19,8 @@ use bytemuck::cast_slice;
 use serde::Deserialize;
 
 const DEFAULT_MWM: u8 = 14;
+const DEFAULT_PRUNING_RETENTION: u32 = 50;
+const DEFAULT_HANDSHAKE_TIMEOUT: u64 = 10;
 const DEFAULT_COO_DEPTH: u8 = 24;
 const DEFAULT_COO_PUBLIC_KEY: &str =
     "EQSAUZXULTTYZCLNJNTXQTQHOMOFZERHTCGTXOLTVAHKSA9OGAZDEKECURBRIXIJWNPFCQIOVFVVXJVD9";
@@ -26,17 +30,28 @@ const DEFAULT_MILESTONE_REQUEST_SEND_WORKER_BOUND: usize = 1000;
 const DEFAULT_TRANSACTION_BROADCAST_SEND_WORKER_BOUND: usize = 1000;
 const DEFAULT_TRANSACTION_REQUEST_SEND_WORKER_BOUND: usize = 1000;
 const DEFAULT_HEARTBEAT_SEND_WORKER_BOUND: usize = 1000;
+const DEFAULT_MILESTONE_REQUEST_SEND_WORKER_RATE: u32 = 0;
+const DEFAULT_TRANSACTION_BROADCAST_SEND_WORKER_RATE: u32 = 0;
+const DEFAULT_TRANSACTION_REQUEST_SEND_WORKER_RATE: u32 = 0;
+const DEFAULT_HEARTBEAT_SEND_WORKER_RATE: u32 = 0;
+const DEFAULT_SEND_WORKER_DRAIN_ON_SHUTDOWN: bool = false;
 const DEFAULT_MILESTONE_VALIDATOR_WORKER_BOUND: usize = 1000;
 const DEFAULT_TRANSACTION_SOLIDIFIER_WORKER_BOUND: usize = 1000;
 const DEFAULT_MILESTONE_SOLIDIFIER_WORKER_BOUND: usize = 1000;
 const DEFAULT_TRANSACTION_WORKER_BOUND: usize = 1000;
 const DEFAULT_TRANSACTION_WORKER_CACHE: usize = 10000;
+const DEFAULT_TRANSACTION_WORKER_CACHE_EVICTION: &str = "fifo";
 const DEFAULT_TRANSACTION_RESPONDER_WORKER_BOUND: usize = 1000;
 const DEFAULT_MILESTONE_RESPONDER_WORKER_BOUND: usize = 1000;
-const DEFAULT_TRANSACTION_REQUESTER_WORKER_BOUND: usize = 1000;
-const DEFAULT_MILESTONE_REQUESTER_WORKER_BOUND: usize = 1000;
+const DEFAULT_REQUESTER_WORKER_DROP_LOWEST: bool = false;
+const DEFAULT_TRANSACTION_REQUESTER_PEER_CAP: usize = 100;
+const DEFAULT_MILESTONE_REQUESTER_PEER_CAP: usize = 20;
+const DEFAULT_TRANSACTION_REQUESTER_DEDUP_WINDOW: u64 = 1000;
+const DEFAULT_TRANSACTION_REQUESTER_RETRY_TIMEOUT: u64 = 5000;
+const DEFAULT_TRANSACTION_REQUESTER_MAX_RETRIES: u8 = 5;
 const DEFAULT_RECEIVER_WORKER_BOUND: usize = 1000;
 const DEFAULT_BROADCASTER_WORKER_BOUND: usize = 1000;
+const DEFAULT_PRUNER_WORKER_BOUND: usize = 1000;
 
 #[derive(Default, Deserialize)]
 struct ProtocolCoordinatorConfigBuilder {
@@ -52,22 +67,37 @@ struct ProtocolWorkersConfigBuilder {
     transaction_broadcast_send_worker_bound: Option<usize>,
     transaction_request_send_worker_bound: Option<usize>,
     heartbeat_send_worker_bound: Option<usize>,
+    milestone_request_send_worker_rate: Option<u32>,
+    transaction_broadcast_send_worker_rate: Option<u32>,
+    transaction_request_send_worker_rate: Option<u32>,
+    heartbeat_send_worker_rate: Option<u32>,
+    send_worker_drain_on_shutdown: Option<bool>,
     milestone_validator_worker_bound: Option<usize>,
     transaction_solidifier_worker_bound: Option<usize>,
     milestone_solidifier_worker_bound: Option<usize>,
     transaction_worker_bound: Option<usize>,
     transaction_worker_cache: Option<usize>,
+    transaction_worker_cache_eviction: Option<String>,
     transaction_responder_worker_bound: Option<usize>,
     milestone_responder_worker_bound: Option<usize>,
     transaction_requester_worker_bound: Option<usize>,
     milestone_requester_worker_bound: Option<usize>,
+    requester_worker_drop_lowest: Option<bool>,
+    transaction_requester_peer_cap: Option<usize>,
+    transaction_requester_dedup_window: Option<u64>,
+    transaction_requester_retry_timeout: Option<u64>,
+    transaction_requester_max_retries: Option<u8>,
+    milestone_requester_peer_cap: Option<usize>,
     receiver_worker_bound: Option<usize>,
     broadcaster_worker_bound: Option<usize>,
+    pruner_worker_bound: Option<usize>,
 }
 
 #[derive(Default, Deserialize)]
 pub struct ProtocolConfigBuilder {
     mwm: Option<u8>,
+    pruning_retention: Option<u32>,
+    handshake_timeout: Option<u64>,
     coordinator: ProtocolCoordinatorConfigBuilder,
     workers: ProtocolWorkersConfigBuilder,
 }
@@ -82,6 +112,16 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    pub fn pruning_retention(mut self, pruning_retention: u32) -> Self {
+        self.pruning_retention.replace(pruning_retention);
+        self
+    }
+
+    pub fn handshake_timeout(mut self, handshake_timeout: u64) -> Self {
+        self.handshake_timeout.replace(handshake_timeout);
+        self
+    }
+
     pub fn coo_depth(mut self, coo_depth: u8) -> Self {
         self.coordinator.depth.replace(coo_depth);
         self
@@ -130,6 +170,41 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    pub fn milestone_request_send_worker_rate(mut self, milestone_request_send_worker_rate: u32) -> Self {
+        self.workers
+            .milestone_request_send_worker_rate
+            .replace(milestone_request_send_worker_rate);
+        self
+    }
+
+    pub fn transaction_broadcast_send_worker_rate(mut self, transaction_broadcast_send_worker_rate: u32) -> Self {
+        self.workers
+            .transaction_broadcast_send_worker_rate
+            .replace(transaction_broadcast_send_worker_rate);
+        self
+    }
+
+    pub fn transaction_request_send_worker_rate(mut self, transaction_request_send_worker_rate: u32) -> Self {
+        self.workers
+            .transaction_request_send_worker_rate
+            .replace(transaction_request_send_worker_rate);
+        self
+    }
+
+    pub fn heartbeat_send_worker_rate(mut self, heartbeat_send_worker_rate: u32) -> Self {
+        self.workers
+            .heartbeat_send_worker_rate
+            .replace(heartbeat_send_worker_rate);
+        self
+    }
+
+    pub fn send_worker_drain_on_shutdown(mut self, send_worker_drain_on_shutdown: bool) -> Self {
+        self.workers
+            .send_worker_drain_on_shutdown
+            .replace(send_worker_drain_on_shutdown);
+        self
+    }
+
     pub fn milestone_validator_worker_bound(mut self, milestone_validator_worker_bound: usize) -> Self {
         self.workers
             .milestone_validator_worker_bound
@@ -161,6 +236,13 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    pub fn transaction_worker_cache_eviction(mut self, transaction_worker_cache_eviction: &str) -> Self {
+        self.workers
+            .transaction_worker_cache_eviction
+            .replace(transaction_worker_cache_eviction.to_string());
+        self
+    }
+
     pub fn transaction_responder_worker_bound(mut self, transaction_responder_worker_bound: usize) -> Self {
         self.workers
             .transaction_responder_worker_bound
@@ -189,6 +271,48 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    pub fn requester_worker_drop_lowest(mut self, requester_worker_drop_lowest: bool) -> Self {
+        self.workers
+            .requester_worker_drop_lowest
+            .replace(requester_worker_drop_lowest);
+        self
+    }
+
+    pub fn transaction_requester_peer_cap(mut self, transaction_requester_peer_cap: usize) -> Self {
+        self.workers
+            .transaction_requester_peer_cap
+            .replace(transaction_requester_peer_cap);
+        self
+    }
+
+    pub fn transaction_requester_dedup_window(mut self, transaction_requester_dedup_window: u64) -> Self {
+        self.workers
+            .transaction_requester_dedup_window
+            .replace(transaction_requester_dedup_window);
+        self
+    }
+
+    pub fn transaction_requester_retry_timeout(mut self, transaction_requester_retry_timeout: u64) -> Self {
+        self.workers
+            .transaction_requester_retry_timeout
+            .replace(transaction_requester_retry_timeout);
+        self
+    }
+
+    pub fn transaction_requester_max_retries(mut self, transaction_requester_max_retries: u8) -> Self {
+        self.workers
+            .transaction_requester_max_retries
+            .replace(transaction_requester_max_retries);
+        self
+    }
+
+    pub fn milestone_requester_peer_cap(mut self, milestone_requester_peer_cap: usize) -> Self {
+        self.workers
+            .milestone_requester_peer_cap
+            .replace(milestone_requester_peer_cap);
+        self
+    }
+
     pub fn receiver_worker_bound(mut self, receiver_worker_bound: usize) -> Self {
         self.workers.receiver_worker_bound.replace(receiver_worker_bound);
         self
@@ -199,6 +323,11 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    pub fn pruner_worker_bound(mut self, pruner_worker_bound: usize) -> Self {
+        self.workers.pruner_worker_bound.replace(pruner_worker_bound);
+        self
+    }
+
     pub fn finish(self) -> ProtocolConfig {
         let coo_sponge_type = match self
             .coordinator
@@ -212,6 +341,17 @@ impl ProtocolConfigBuilder {
             _ => SpongeType::Kerl,
         };
 
+        let transaction_worker_cache_eviction = match self
+            .workers
+            .transaction_worker_cache_eviction
+            .unwrap_or_else(|| DEFAULT_TRANSACTION_WORKER_CACHE_EVICTION.to_owned())
+            .as_str()
+        {
+            "fifo" => CacheEviction::Fifo,
+            "lru" => CacheEviction::Lru,
+            _ => CacheEviction::Fifo,
+        };
+
         let coo_public_key_default = Address::from_inner_unchecked(
             TryteBuf::try_from_str(DEFAULT_COO_PUBLIC_KEY)
                 .unwrap()
@@ -237,6 +377,8 @@ impl ProtocolConfigBuilder {
 
         ProtocolConfig {
             mwm: self.mwm.unwrap_or(DEFAULT_MWM),
+            pruning_retention: self.pruning_retention.unwrap_or(DEFAULT_PRUNING_RETENTION),
+            handshake_timeout: self.handshake_timeout.unwrap_or(DEFAULT_HANDSHAKE_TIMEOUT),
             coordinator: ProtocolCoordinatorConfig {
                 depth: self.coordinator.depth.unwrap_or(DEFAULT_COO_DEPTH),
                 public_key: coo_public_key,
@@ -245,7 +387,6 @@ impl ProtocolConfigBuilder {
                 sponge_type: coo_sponge_type,
             },
             workers: ProtocolWorkersConfig {
-                null_address: Address::zeros(),
                 milestone_request_send_worker_bound: self
                     .workers
                     .milestone_request_send_worker_bound
@@ -262,6 +403,26 @@ impl ProtocolConfigBuilder {
                     .workers
                     .heartbeat_send_worker_bound
                     .unwrap_or(DEFAULT_HEARTBEAT_SEND_WORKER_BOUND),
+                milestone_request_send_worker_rate: self
+                    .workers
+                    .milestone_request_send_worker_rate
+                    .unwrap_or(DEFAULT_MILESTONE_REQUEST_SEND_WORKER_RATE),
+                transaction_broadcast_send_worker_rate: self
+                    .workers
+                    .transaction_broadcast_send_worker_rate
+                    .unwrap_or(DEFAULT_TRANSACTION_BROADCAST_SEND_WORKER_RATE),
+                transaction_request_send_worker_rate: self
+                    .workers
+                    .transaction_request_send_worker_rate
+                    .unwrap_or(DEFAULT_TRANSACTION_REQUEST_SEND_WORKER_RATE),
+                heartbeat_send_worker_rate: self
+                    .workers
+                    .heartbeat_send_worker_rate
+                    .unwrap_or(DEFAULT_HEARTBEAT_SEND_WORKER_RATE),
+                send_worker_drain_on_shutdown: self
+                    .workers
+                    .send_worker_drain_on_shutdown
+                    .unwrap_or(DEFAULT_SEND_WORKER_DRAIN_ON_SHUTDOWN),
                 milestone_validator_worker_bound: self
                     .workers
                     .milestone_validator_worker_bound
@@ -282,6 +443,7 @@ impl ProtocolConfigBuilder {
                     .workers
                     .transaction_worker_cache
                     .unwrap_or(DEFAULT_TRANSACTION_WORKER_CACHE),
+                transaction_worker_cache_eviction,
                 transaction_responder_worker_bound: self
                     .workers
                     .transaction_responder_worker_bound
@@ -290,14 +452,32 @@ impl ProtocolConfigBuilder {
                     .workers
                     .milestone_responder_worker_bound
                     .unwrap_or(DEFAULT_MILESTONE_RESPONDER_WORKER_BOUND),
-                transaction_requester_worker_bound: self
+                transaction_requester_worker_bound: self.workers.transaction_requester_worker_bound,
+                milestone_requester_worker_bound: self.workers.milestone_requester_worker_bound,
+                requester_worker_drop_lowest: self
+                    .workers
+                    .requester_worker_drop_lowest
+                    .unwrap_or(DEFAULT_REQUESTER_WORKER_DROP_LOWEST),
+                transaction_requester_peer_cap: self
+                    .workers
+                    .transaction_requester_peer_cap
+                    .unwrap_or(DEFAULT_TRANSACTION_REQUESTER_PEER_CAP),
+                transaction_requester_dedup_window: self
+                    .workers
+                    .transaction_requester_dedup_window
+                    .unwrap_or(DEFAULT_TRANSACTION_REQUESTER_DEDUP_WINDOW),
+                transaction_requester_retry_timeout: self
+                    .workers
+                    .transaction_requester_retry_timeout
+                    .unwrap_or(DEFAULT_TRANSACTION_REQUESTER_RETRY_TIMEOUT),
+                transaction_requester_max_retries: self
                     .workers
-                    .transaction_requester_worker_bound
-                    .unwrap_or(DEFAULT_TRANSACTION_REQUESTER_WORKER_BOUND),
-                milestone_requester_worker_bound: self
+                    .transaction_requester_max_retries
+                    .unwrap_or(DEFAULT_TRANSACTION_REQUESTER_MAX_RETRIES),
+                milestone_requester_peer_cap: self
                     .workers
-                    .milestone_requester_worker_bound
-                    .unwrap_or(DEFAULT_MILESTONE_REQUESTER_WORKER_BOUND),
+                    .milestone_requester_peer_cap
+                    .unwrap_or(DEFAULT_MILESTONE_REQUESTER_PEER_CAP),
                 receiver_worker_bound: self
                     .workers
                     .receiver_worker_bound
@@ -306,6 +486,7 @@ impl ProtocolConfigBuilder {
                     .workers
                     .broadcaster_worker_bound
                     .unwrap_or(DEFAULT_BROADCASTER_WORKER_BOUND),
+                pruner_worker_bound: self.workers.pruner_worker_bound.unwrap_or(DEFAULT_PRUNER_WORKER_BOUND),
             },
         }
     }
@@ -322,27 +503,45 @@ pub struct ProtocolCoordinatorConfig {
 
 #[derive(Clone)]
 pub struct ProtocolWorkersConfig {
-    pub(crate) null_address: Address,
     pub(crate) milestone_request_send_worker_bound: usize,
     pub(crate) transaction_broadcast_send_worker_bound: usize,
     pub(crate) transaction_request_send_worker_bound: usize,
     pub(crate) heartbeat_send_worker_bound: usize,
+    // Maximum number of messages sent per second to each peer, 0 meaning unlimited.
+    pub(crate) milestone_request_send_worker_rate: u32,
+    pub(crate) transaction_broadcast_send_worker_rate: u32,
+    pub(crate) transaction_request_send_worker_rate: u32,
+    pub(crate) heartbeat_send_worker_rate: u32,
+    // Whether messages still queued to a peer at shutdown are sent before its sender workers stop.
+    pub(crate) send_worker_drain_on_shutdown: bool,
     pub(crate) milestone_validator_worker_bound: usize,
     pub(crate) transaction_solidifier_worker_bound: usize,
     pub(crate) milestone_solidifier_worker_bound: usize,
     pub(crate) transaction_worker_bound: usize,
     pub(crate) transaction_worker_cache: usize,
+    pub(crate) transaction_worker_cache_eviction: CacheEviction,
     pub(crate) transaction_responder_worker_bound: usize,
     pub(crate) milestone_responder_worker_bound: usize,
-    pub(crate) transaction_requester_worker_bound: usize,
-    pub(crate) milestone_requester_worker_bound: usize,
+    // Maximum number of queued requests, `None` meaning unbounded.
+    pub(crate) transaction_requester_worker_bound: Option<usize>,
+    pub(crate) milestone_requester_worker_bound: Option<usize>,
+    // Whether a full requester queue evicts its lowest priority request for a higher one instead of rejecting it.
+    pub(crate) requester_worker_drop_lowest: bool,
+    pub(crate) transaction_requester_peer_cap: usize,
+    pub(crate) transaction_requester_dedup_window: u64,
+    pub(crate) transaction_requester_retry_timeout: u64,
+    pub(crate) transaction_requester_max_retries: u8,
+    pub(crate) milestone_requester_peer_cap: usize,
     pub(crate) receiver_worker_bound: usize,
     pub(crate) broadcaster_worker_bound: usize,
+    pub(crate) pruner_worker_bound: usize,
 }
 
 #[derive(Clone)]
 pub struct ProtocolConfig {
     pub(crate) mwm: u8,
+    pub(crate) pruning_retention: u32,
+    pub(crate) handshake_timeout: u64,
     pub(crate) coordinator: ProtocolCoordinatorConfig,
     pub(crate) workers: ProtocolWorkersConfig,
 }
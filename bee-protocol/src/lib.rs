@@ -20,5 +20,5 @@ mod util;
 mod worker;
 
 pub use config::{ProtocolConfig, ProtocolConfigBuilder};
-pub use milestone::{Milestone, MilestoneIndex};
-pub use protocol::{Protocol, ProtocolMetrics};
+pub use milestone::{Milestone, MilestoneConfirmed, MilestoneIndex};
+pub use protocol::{Protocol, ProtocolInitError, ProtocolMetrics};
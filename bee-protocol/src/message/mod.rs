@@ -21,7 +21,8 @@ mod version;
 
 pub(crate) use compression::{compress_transaction_bytes, uncompress_transaction_bytes};
 pub(crate) use message::Message;
-pub(crate) use tlv::{tlv_from_bytes, tlv_into_bytes, Header, HEADER_SIZE};
+pub(crate) use tlv::{tlv_from_bytes, tlv_into_bytes, tlv_read_frame, tlv_split_frame, Header, TlvError, HEADER_SIZE};
 pub(crate) use v0::Handshake;
+pub(crate) use v1::LegacyGossip;
 pub(crate) use v2::{Heartbeat, MilestoneRequest, TransactionBroadcast, TransactionRequest};
 pub(crate) use version::{messages_supported_version, MESSAGES_VERSIONS};
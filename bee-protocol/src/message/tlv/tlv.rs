@@ -51,24 +51,67 @@ pub(crate) fn tlv_from_bytes<M: Message>(header: &Header, bytes: &[u8]) -> Resul
     Ok(M::from_bytes(bytes))
 }
 
+/// Splits the frame at the start of a stream of bytes, e.g. as received from a TCP connection, into its header and
+/// payload, whatever the advertised message type.
+///
+/// Returns `None` if `bytes` doesn't hold the whole header and advertised payload yet. The frame spans `HEADER_SIZE`
+/// bytes more than the payload.
+pub(crate) fn tlv_split_frame(bytes: &[u8]) -> Option<(Header, &[u8])> {
+    if bytes.len() < HEADER_SIZE {
+        return None;
+    }
+
+    let header = Header::from_bytes(&bytes[..HEADER_SIZE]);
+    let end = HEADER_SIZE + header.message_length as usize;
+
+    if bytes.len() < end {
+        return None;
+    }
+
+    Some((header, &bytes[HEADER_SIZE..end]))
+}
+
+/// Reads the frame of a message of type `M` at the start of a stream of bytes, e.g. as received from a TCP connection.
+///
+/// Returns `None` if `bytes` doesn't hold the whole header and advertised payload yet, otherwise the message, or the
+/// error reading it failed with, along with the number of bytes the frame spans so that it can be skipped either way.
+///
+/// This is a TLV function rather than a `Message` method returning `Result<Option<(M, usize)>, _>` because `Message`
+/// knows nothing of headers, and because an invalid frame still has to be skipped, which requires its length.
+pub(crate) fn tlv_read_frame<M: Message>(bytes: &[u8]) -> Option<(Result<M, TlvError>, usize)> {
+    let (header, payload) = tlv_split_frame(bytes)?;
+
+    Some((tlv_from_bytes(&header, payload), HEADER_SIZE + payload.len()))
+}
+
 /// Serializes a TLV header and a message into a byte buffer.
 ///
 /// # Arguments
 ///
 /// * `message` -   The message to serialize.
 pub(crate) fn tlv_into_bytes<M: Message>(message: M) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_SIZE + message.size());
+
+    tlv_write_bytes(&message, &mut bytes);
+
+    bytes
+}
+
+/// Appends the header and payload of `message` to `bytes`, without consuming the message.
+///
+/// Allocates only if `bytes` lacks the capacity, which allows a buffer to be reused from one message to the next.
+pub(crate) fn tlv_write_bytes<M: Message>(message: &M, bytes: &mut Vec<u8>) {
     let size = message.size();
-    let mut bytes = vec![0u8; HEADER_SIZE + size];
-    let (header, payload) = bytes.split_at_mut(HEADER_SIZE);
+    let start = bytes.len();
+    bytes.resize(start + HEADER_SIZE + size, 0);
+    let (header, payload) = bytes[start..].split_at_mut(HEADER_SIZE);
 
     Header {
         message_type: M::ID,
         message_length: size as u16,
     }
     .to_bytes(header);
-    message.into_bytes(payload);
-
-    bytes
+    message.to_bytes(payload);
 }
 
 #[cfg(test)]
@@ -164,6 +207,105 @@ mod tests {
         }
     }
 
+    #[test]
+    fn write_bytes_appends_to_buffer() {
+        let first = Heartbeat::new(1, 2);
+        let second = MilestoneRequest::new(3);
+        let mut bytes = vec![42u8];
+
+        tlv_write_bytes(&first, &mut bytes);
+        tlv_write_bytes(&second, &mut bytes);
+
+        let first_bytes = tlv_into_bytes(first);
+        let second_bytes = tlv_into_bytes(second);
+
+        assert_eq!(bytes.len(), 1 + first_bytes.len() + second_bytes.len());
+        assert_eq!(bytes[0], 42);
+        assert!(slice_eq(&bytes[1..1 + first_bytes.len()], &first_bytes));
+        assert!(slice_eq(&bytes[1 + first_bytes.len()..], &second_bytes));
+    }
+
+    #[test]
+    fn write_bytes_reuses_buffer() {
+        let message = TransactionRequest::new(&[1u8; 49]);
+        let mut bytes = Vec::new();
+
+        tlv_write_bytes(&message, &mut bytes);
+        let capacity = bytes.capacity();
+        let ptr = bytes.as_ptr();
+
+        bytes.clear();
+        tlv_write_bytes(&message, &mut bytes);
+
+        assert_eq!(bytes.capacity(), capacity);
+        assert_eq!(bytes.as_ptr(), ptr);
+        assert!(slice_eq(&bytes, &tlv_into_bytes(message)));
+    }
+
+    #[test]
+    fn read_frame_needs_whole_frame() {
+        let bytes = tlv_into_bytes(MilestoneRequest::new(3));
+
+        for end in 0..bytes.len() {
+            assert!(tlv_read_frame::<MilestoneRequest>(&bytes[..end]).is_none());
+        }
+    }
+
+    #[test]
+    fn read_frame_consumes_one_frame() {
+        let mut bytes = Vec::new();
+        tlv_write_bytes(&Heartbeat::new(1, 2), &mut bytes);
+        tlv_write_bytes(&Heartbeat::new(3, 4), &mut bytes);
+
+        let (first, consumed) = tlv_read_frame::<Heartbeat>(&bytes).unwrap();
+        let first = first.unwrap();
+        assert_eq!(consumed, HEADER_SIZE + Heartbeat::size_range().start);
+        assert_eq!(first.solid_milestone_index(), 1);
+        assert_eq!(first.snapshot_milestone_index(), 2);
+
+        let (second, consumed) = tlv_read_frame::<Heartbeat>(&bytes[consumed..]).unwrap();
+        let second = second.unwrap();
+        assert_eq!(consumed, bytes.len() / 2);
+        assert_eq!(second.solid_milestone_index(), 3);
+        assert_eq!(second.snapshot_milestone_index(), 4);
+    }
+
+    #[test]
+    fn read_frame_rejects_other_type() {
+        let bytes = tlv_into_bytes(MilestoneRequest::new(3));
+
+        match tlv_read_frame::<Heartbeat>(&bytes) {
+            Some((Err(TlvError::InvalidAdvertisedType(advertised_type, actual_type)), consumed)) => {
+                assert_eq!(advertised_type, MilestoneRequest::ID);
+                assert_eq!(actual_type, Heartbeat::ID);
+                assert_eq!(consumed, bytes.len());
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn split_frame_any_type() {
+        let mut bytes = Vec::new();
+        tlv_write_bytes(&MilestoneRequest::new(3), &mut bytes);
+        tlv_write_bytes(&Heartbeat::new(1, 2), &mut bytes);
+
+        let (header, payload) = tlv_split_frame(&bytes).unwrap();
+        assert_eq!(header.message_type, MilestoneRequest::ID);
+        assert_eq!(payload.len(), header.message_length as usize);
+
+        let (header, payload) = tlv_split_frame(&bytes[HEADER_SIZE + payload.len()..]).unwrap();
+        assert_eq!(header.message_type, Heartbeat::ID);
+        assert_eq!(
+            tlv_from_bytes::<Heartbeat>(&header, payload)
+                .unwrap()
+                .solid_milestone_index(),
+            1
+        );
+
+        assert!(tlv_split_frame(&bytes[..HEADER_SIZE]).is_none());
+    }
+
     macro_rules! implement_tlv_tests {
         ($type:ty, $iat:tt, $ial:tt, $loor:tt, $fuzz:tt) => {
             #[test]
@@ -112,7 +112,7 @@ impl Message for Handshake {
         CONSTANT_SIZE + self.supported_versions.len()
     }
 
-    fn into_bytes(self, bytes: &mut [u8]) {
+    fn to_bytes(&self, bytes: &mut [u8]) {
         let (bytes, next) = bytes.split_at_mut(PORT_SIZE);
         bytes.copy_from_slice(&self.port.to_be_bytes());
 
@@ -171,7 +171,7 @@ mod tests {
     fn into_from() {
         let message_from = Handshake::new(PORT, &COORDINATOR, MINIMUM_WEIGHT_MAGNITUDE, &SUPPORTED_VERSIONS);
         let mut bytes = vec![0u8; message_from.size()];
-        message_from.into_bytes(&mut bytes);
+        message_from.to_bytes(&mut bytes);
         let message_to = Handshake::from_bytes(&bytes);
 
         // TODO test timestamp
@@ -13,12 +13,14 @@
 
 use crate::message::Message;
 
+use bee_bundle::TRANSACTION_BYTE_LEN;
+
 use std::ops::Range;
 
 const HASH_SIZE: usize = 49;
 const CONSTANT_SIZE: usize = HASH_SIZE;
 const VARIABLE_MIN_SIZE: usize = 292;
-const VARIABLE_MAX_SIZE: usize = 1604;
+const VARIABLE_MAX_SIZE: usize = TRANSACTION_BYTE_LEN;
 
 /// A legacy message to broadcast a transaction and request another one at the same time.
 pub(crate) struct LegacyGossip {
@@ -68,7 +70,7 @@ impl Message for LegacyGossip {
         self.transaction.len() + CONSTANT_SIZE
     }
 
-    fn into_bytes(self, bytes: &mut [u8]) {
+    fn to_bytes(&self, bytes: &mut [u8]) {
         bytes[0..self.transaction.len()].copy_from_slice(&self.transaction);
         bytes[self.transaction.len()..].copy_from_slice(&self.hash);
     }
@@ -136,7 +138,7 @@ mod tests {
     fn into_from() {
         let message_from = LegacyGossip::new(&TRANSACTION, REQUEST);
         let mut bytes = vec![0u8; message_from.size()];
-        message_from.into_bytes(&mut bytes);
+        message_from.to_bytes(&mut bytes);
         let message_to = LegacyGossip::from_bytes(&bytes);
 
         assert!(slice_eq(&message_to.transaction, &TRANSACTION));
@@ -11,7 +11,7 @@
 
 //! Heartbeat message of the protocol version 2
 
-use crate::message::Message;
+use crate::{message::Message, milestone::MilestoneIndex};
 
 use std::{convert::TryInto, ops::Range};
 
@@ -28,18 +28,28 @@ const CONSTANT_SIZE: usize = SOLID_MILESTONE_INDEX_SIZE + SNAPSHOT_MILESTONE_IND
 #[derive(Default)]
 pub(crate) struct Heartbeat {
     /// Index of the last solid milestone.
-    pub(crate) solid_milestone_index: u32,
+    solid_milestone_index: MilestoneIndex,
     /// Index of the snapshotted milestone.
-    pub(crate) snapshot_milestone_index: u32,
+    snapshot_milestone_index: MilestoneIndex,
 }
 
 impl Heartbeat {
-    pub(crate) fn new(solid_milestone_index: u32, snapshot_milestone_index: u32) -> Self {
+    pub(crate) fn new(solid_milestone_index: MilestoneIndex, snapshot_milestone_index: MilestoneIndex) -> Self {
         Self {
             solid_milestone_index,
             snapshot_milestone_index,
         }
     }
+
+    /// Returns the index of the last solid milestone of the sender.
+    pub(crate) fn solid_milestone_index(&self) -> MilestoneIndex {
+        self.solid_milestone_index
+    }
+
+    /// Returns the index of the snapshotted milestone of the sender.
+    pub(crate) fn snapshot_milestone_index(&self) -> MilestoneIndex {
+        self.snapshot_milestone_index
+    }
 }
 
 impl Message for Heartbeat {
@@ -65,7 +75,7 @@ impl Message for Heartbeat {
         CONSTANT_SIZE
     }
 
-    fn into_bytes(self, bytes: &mut [u8]) {
+    fn to_bytes(&self, bytes: &mut [u8]) {
         bytes[0..SOLID_MILESTONE_INDEX_SIZE].copy_from_slice(&self.solid_milestone_index.to_be_bytes());
         bytes[SOLID_MILESTONE_INDEX_SIZE..].copy_from_slice(&self.snapshot_milestone_index.to_be_bytes());
     }
@@ -102,10 +112,25 @@ mod tests {
     fn into_from() {
         let message_from = Heartbeat::new(FIRST_SOLID_MILESTONE_INDEX, LAST_SOLID_MILESTONE_INDEX);
         let mut bytes = vec![0u8; message_from.size()];
-        message_from.into_bytes(&mut bytes);
+        message_from.to_bytes(&mut bytes);
         let message_to = Heartbeat::from_bytes(&bytes);
 
-        assert_eq!(message_to.solid_milestone_index, FIRST_SOLID_MILESTONE_INDEX);
-        assert_eq!(message_to.snapshot_milestone_index, LAST_SOLID_MILESTONE_INDEX);
+        assert_eq!(message_to.solid_milestone_index(), FIRST_SOLID_MILESTONE_INDEX);
+        assert_eq!(message_to.snapshot_milestone_index(), LAST_SOLID_MILESTONE_INDEX);
+    }
+
+    #[test]
+    fn byte_layout() {
+        assert_eq!(CONSTANT_SIZE, std::mem::size_of::<u32>() + std::mem::size_of::<u32>());
+
+        let mut bytes = vec![0u8; CONSTANT_SIZE];
+        Heartbeat::new(FIRST_SOLID_MILESTONE_INDEX, LAST_SOLID_MILESTONE_INDEX).to_bytes(&mut bytes);
+
+        assert_eq!(bytes, [0x3d, 0xc2, 0x97, 0xb4, 0x01, 0x18, 0x1f, 0x9b]);
+
+        let message = Heartbeat::from_bytes(&[0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00]);
+
+        assert_eq!(message.solid_milestone_index(), 1);
+        assert_eq!(message.snapshot_milestone_index(), 256);
     }
 }
@@ -19,6 +19,9 @@ const INDEX_SIZE: usize = 4;
 const CONSTANT_SIZE: usize = INDEX_SIZE;
 
 /// A message to request a milestone.
+///
+/// The index is encoded as 4 big-endian bytes. Since no milestone has the index 0, the index 0 (`[0, 0, 0, 0]` on the
+/// wire) is used to request the latest milestone known to the peer, e.g. when syncing before knowing any index.
 #[derive(Default)]
 pub(crate) struct MilestoneRequest {
     /// Index of the requested milestone.
@@ -26,6 +29,9 @@ pub(crate) struct MilestoneRequest {
 }
 
 impl MilestoneRequest {
+    /// Index requesting the latest milestone.
+    pub(crate) const LATEST_INDEX: u32 = 0;
+
     pub(crate) fn new(index: u32) -> Self {
         Self { index }
     }
@@ -50,7 +56,7 @@ impl Message for MilestoneRequest {
         CONSTANT_SIZE
     }
 
-    fn into_bytes(self, bytes: &mut [u8]) {
+    fn to_bytes(&self, bytes: &mut [u8]) {
         bytes.copy_from_slice(&self.index.to_be_bytes())
     }
 }
@@ -85,9 +91,28 @@ mod tests {
     fn into_from() {
         let message_from = MilestoneRequest::new(INDEX);
         let mut bytes = vec![0u8; message_from.size()];
-        message_from.into_bytes(&mut bytes);
+        message_from.to_bytes(&mut bytes);
         let message_to = MilestoneRequest::from_bytes(&bytes);
 
         assert_eq!(message_to.index, INDEX);
     }
+
+    #[test]
+    fn byte_layout() {
+        assert_eq!(CONSTANT_SIZE, std::mem::size_of::<u32>());
+
+        let mut bytes = vec![0u8; CONSTANT_SIZE];
+        MilestoneRequest::new(INDEX).to_bytes(&mut bytes);
+
+        assert_eq!(bytes, [0x81, 0xf7, 0xdf, 0x7c]);
+        assert_eq!(MilestoneRequest::from_bytes(&[0x00, 0x00, 0x01, 0x00]).index, 256);
+    }
+
+    #[test]
+    fn latest_byte_layout() {
+        let mut bytes = vec![0xffu8; CONSTANT_SIZE];
+        MilestoneRequest::new(MilestoneRequest::LATEST_INDEX).to_bytes(&mut bytes);
+
+        assert_eq!(bytes, [0x00, 0x00, 0x00, 0x00]);
+    }
 }
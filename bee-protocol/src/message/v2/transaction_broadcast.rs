@@ -13,10 +13,12 @@
 
 use crate::message::Message;
 
+use bee_bundle::TRANSACTION_BYTE_LEN;
+
 use std::ops::Range;
 
 const VARIABLE_MIN_SIZE: usize = 292;
-const VARIABLE_MAX_SIZE: usize = 1604;
+const VARIABLE_MAX_SIZE: usize = TRANSACTION_BYTE_LEN;
 
 /// A message to broadcast a transaction.
 #[derive(Default)]
@@ -52,7 +54,7 @@ impl Message for TransactionBroadcast {
         self.transaction.len()
     }
 
-    fn into_bytes(self, bytes: &mut [u8]) {
+    fn to_bytes(&self, bytes: &mut [u8]) {
         bytes.copy_from_slice(&self.transaction)
     }
 }
@@ -115,7 +117,7 @@ mod tests {
     fn into_from() {
         let message_from = TransactionBroadcast::new(&TRANSACTION);
         let mut bytes = vec![0u8; message_from.size()];
-        message_from.into_bytes(&mut bytes);
+        message_from.to_bytes(&mut bytes);
         let message_to = TransactionBroadcast::from_bytes(&bytes);
 
         assert!(slice_eq(&message_to.transaction, &TRANSACTION));
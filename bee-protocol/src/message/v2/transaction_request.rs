@@ -59,7 +59,7 @@ impl Message for TransactionRequest {
         CONSTANT_SIZE
     }
 
-    fn into_bytes(self, bytes: &mut [u8]) {
+    fn to_bytes(&self, bytes: &mut [u8]) {
         bytes.copy_from_slice(&self.hash)
     }
 }
@@ -99,9 +99,20 @@ mod tests {
     fn into_from() {
         let message_from = TransactionRequest::new(&HASH);
         let mut bytes = vec![0u8; message_from.size()];
-        message_from.into_bytes(&mut bytes);
+        message_from.to_bytes(&mut bytes);
         let message_to = TransactionRequest::from_bytes(&bytes);
 
         assert!(slice_eq(&message_to.hash, &HASH));
     }
+
+    #[test]
+    fn byte_layout() {
+        // A hash of 243 trits packed 5 trits per byte
+        assert_eq!(CONSTANT_SIZE, (243 + 4) / 5);
+
+        let mut bytes = vec![0u8; CONSTANT_SIZE];
+        TransactionRequest::new(&HASH).to_bytes(&mut bytes);
+
+        assert!(slice_eq(&bytes, &HASH));
+    }
 }
@@ -63,6 +63,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn negotiate_compatible_versions() {
+        // Peer supporting versions 1, 2 and 3
+        assert_eq!(messages_supported_version(&[0b0000_0111]), Ok(2));
+        // Peer only supporting version 2
+        assert_eq!(messages_supported_version(&[0b0000_0010]), Ok(2));
+    }
+
+    #[test]
+    fn negotiate_incompatible_versions() {
+        // Peer only supporting legacy gossip
+        assert_eq!(messages_supported_version(&[0b0000_0001]), Err(1));
+        // Peer only supporting a newer version
+        assert_eq!(messages_supported_version(&[0b0000_0000, 0b0000_0001]), Err(9));
+    }
+
     #[test]
     fn test() {
         for i in 0..32 {
@@ -11,6 +11,8 @@
 
 use bee_bundle::Hash;
 
+use std::cmp::Ordering;
+
 pub type MilestoneIndex = u32;
 
 pub struct Milestone {
@@ -31,3 +33,60 @@ impl Milestone {
         self.index
     }
 }
+
+impl PartialEq for Milestone {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.hash == other.hash
+    }
+}
+
+impl Eq for Milestone {}
+
+impl PartialOrd for Milestone {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Milestones are ordered by index; the hash only breaks ties between milestones sharing an index.
+impl Ord for Milestone {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index
+            .cmp(&other.index)
+            .then_with(|| self.hash.as_bytes().cmp(other.hash.as_bytes()))
+    }
+}
+
+/// Event emitted to the subscribers of `Protocol::subscribe_milestones` once a new milestone has been validated and
+/// its cone confirmed.
+#[derive(Clone, Debug)]
+pub struct MilestoneConfirmed {
+    pub index: MilestoneIndex,
+    pub hash: Hash,
+    /// The number of transactions newly confirmed by the milestone.
+    pub confirmed_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn sort_by_index() {
+        let mut milestones = [
+            Milestone::new(Hash::zeros(), 3),
+            Milestone::new(Hash::zeros(), 1),
+            Milestone::new(Hash::zeros(), 2),
+        ];
+
+        milestones.sort();
+
+        assert_eq!(
+            milestones.iter().map(Milestone::index).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(milestones[0] < milestones[1]);
+        assert_eq!(milestones[2].hash(), &Hash::zeros());
+    }
+}
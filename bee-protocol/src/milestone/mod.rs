@@ -13,4 +13,4 @@ mod builder;
 mod milestone;
 
 pub(crate) use builder::{MilestoneBuilder, MilestoneBuilderError};
-pub use milestone::{Milestone, MilestoneIndex};
+pub use milestone::{Milestone, MilestoneConfirmed, MilestoneIndex};
@@ -27,6 +27,7 @@ use futures::channel::{mpsc, oneshot};
 pub struct HandshakedPeer {
     pub(crate) epid: EndpointId,
     pub(crate) address: Address,
+    pub(crate) version: u8,
     pub(crate) metrics: ProtocolMetrics,
     pub(crate) solid_milestone_index: AtomicU32,
     pub(crate) snapshot_milestone_index: AtomicU32,
@@ -40,6 +41,7 @@ impl HandshakedPeer {
     pub(crate) fn new(
         epid: EndpointId,
         address: Address,
+        version: u8,
         milestone_request: (mpsc::Sender<MilestoneRequest>, Mutex<Option<oneshot::Sender<()>>>),
         transaction_broadcast: (mpsc::Sender<TransactionBroadcast>, Mutex<Option<oneshot::Sender<()>>>),
         transaction_request: (mpsc::Sender<TransactionRequest>, Mutex<Option<oneshot::Sender<()>>>),
@@ -48,6 +50,7 @@ impl HandshakedPeer {
         Self {
             epid,
             address,
+            version,
             metrics: ProtocolMetrics::default(),
             solid_milestone_index: AtomicU32::new(0),
             snapshot_milestone_index: AtomicU32::new(0),
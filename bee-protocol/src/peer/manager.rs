@@ -13,6 +13,7 @@
 
 use crate::{
     message::{Heartbeat, MilestoneRequest, TransactionBroadcast, TransactionRequest},
+    milestone::MilestoneIndex,
     peer::{HandshakedPeer, Peer},
     protocol::Protocol,
     worker::SenderWorker,
@@ -31,6 +32,7 @@ pub(crate) struct PeerManager {
     network: Network,
     pub(crate) peers: DashMap<EndpointId, Arc<Peer>>,
     pub(crate) handshaked_peers: DashMap<EndpointId, Arc<HandshakedPeer>>,
+    pub(crate) in_flight_requests: DashMap<EndpointId, usize>,
 }
 
 impl PeerManager {
@@ -39,6 +41,7 @@ impl PeerManager {
             network,
             peers: Default::default(),
             handshaked_peers: Default::default(),
+            in_flight_requests: Default::default(),
         }
     }
 
@@ -46,7 +49,7 @@ impl PeerManager {
         self.peers.insert(peer.epid, peer);
     }
 
-    pub(crate) fn handshake(&self, epid: &EndpointId, address: Address) {
+    pub(crate) fn handshake(&self, epid: &EndpointId, address: Address, version: u8) {
         if self.peers.remove(epid).is_some() {
             // TODO check if not already added
 
@@ -73,6 +76,7 @@ impl PeerManager {
             let peer = Arc::new(HandshakedPeer::new(
                 *epid,
                 address,
+                version,
                 (milestone_request_tx, Mutex::new(Some(milestone_request_shutdown_tx))),
                 (
                     transaction_broadcast_tx,
@@ -87,25 +91,79 @@ impl PeerManager {
 
             self.handshaked_peers.insert(*epid, peer.clone());
 
+            let config = &Protocol::get().config.workers;
+
+            spawn(
+                SenderWorker::<MilestoneRequest>::new(
+                    self.network.clone(),
+                    peer.clone(),
+                    config.milestone_request_send_worker_rate,
+                    config.send_worker_drain_on_shutdown,
+                )
+                .run(milestone_request_rx, milestone_request_shutdown_rx),
+            );
             spawn(
-                SenderWorker::<MilestoneRequest>::new(self.network.clone(), peer.clone())
-                    .run(milestone_request_rx, milestone_request_shutdown_rx),
+                SenderWorker::<TransactionBroadcast>::new(
+                    self.network.clone(),
+                    peer.clone(),
+                    config.transaction_broadcast_send_worker_rate,
+                    config.send_worker_drain_on_shutdown,
+                )
+                .run(transaction_broadcast_rx, transaction_broadcast_shutdown_rx),
             );
             spawn(
-                SenderWorker::<TransactionBroadcast>::new(self.network.clone(), peer.clone())
-                    .run(transaction_broadcast_rx, transaction_broadcast_shutdown_rx),
+                SenderWorker::<TransactionRequest>::new(
+                    self.network.clone(),
+                    peer.clone(),
+                    config.transaction_request_send_worker_rate,
+                    config.send_worker_drain_on_shutdown,
+                )
+                .run(transaction_request_rx, transaction_request_shutdown_rx),
             );
             spawn(
-                SenderWorker::<TransactionRequest>::new(self.network.clone(), peer.clone())
-                    .run(transaction_request_rx, transaction_request_shutdown_rx),
+                SenderWorker::<Heartbeat>::new(
+                    self.network.clone(),
+                    peer,
+                    config.heartbeat_send_worker_rate,
+                    config.send_worker_drain_on_shutdown,
+                )
+                .run(heartbeat_rx, heartbeat_shutdown_rx),
             );
-            spawn(SenderWorker::<Heartbeat>::new(self.network.clone(), peer).run(heartbeat_rx, heartbeat_shutdown_rx));
+        }
+    }
+
+    // Returns the handshaked peers whose latest heartbeat advertises that they store the transactions confirmed by the
+    // milestone at `index`, i.e. that it lies above their snapshot and at or below their solid milestone. Falls back
+    // to all handshaked peers if none of them does.
+    pub(crate) fn peers_for_index(&self, index: MilestoneIndex) -> Vec<EndpointId> {
+        let covering: Vec<EndpointId> = self
+            .handshaked_peers
+            .iter()
+            .filter(|entry| entry.snapshot_milestone_index() < index && index <= entry.solid_milestone_index())
+            .map(|entry| *entry.key())
+            .collect();
+
+        if covering.is_empty() {
+            self.handshaked_peers.iter().map(|entry| *entry.key()).collect()
+        } else {
+            covering
+        }
+    }
+
+    pub(crate) fn request_sent(&self, epid: &EndpointId) {
+        *self.in_flight_requests.entry(*epid).or_insert(0) += 1;
+    }
+
+    pub(crate) fn request_fulfilled(&self, epid: &EndpointId) {
+        if let Some(mut in_flight) = self.in_flight_requests.get_mut(epid) {
+            *in_flight = in_flight.saturating_sub(1);
         }
     }
 
     pub(crate) fn remove(&self, epid: &EndpointId) {
         // TODO both ?
         self.peers.remove(epid);
+        self.in_flight_requests.remove(epid);
 
         if let Some((_, peer)) = self.handshaked_peers.remove(epid) {
             if let Ok(mut shutdown) = peer.milestone_request.1.lock() {
@@ -133,3 +191,57 @@ impl PeerManager {
         // }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use bee_network::Url;
+
+    use async_std::task::block_on;
+
+    fn add_handshaked_peer(manager: &PeerManager, port: u16, solid: MilestoneIndex, snapshot: MilestoneIndex) {
+        let url = format!("tcp://[::1]:{}", port);
+        let epid: EndpointId = block_on(Url::from_url_str(&url)).unwrap().into();
+        let address = block_on(Address::from_addr_str(&format!("[::1]:{}", port))).unwrap();
+        let peer = HandshakedPeer::new(
+            epid,
+            address,
+            2,
+            (mpsc::channel(1).0, Mutex::new(None)),
+            (mpsc::channel(1).0, Mutex::new(None)),
+            (mpsc::channel(1).0, Mutex::new(None)),
+            (mpsc::channel(1).0, Mutex::new(None)),
+        );
+        peer.set_solid_milestone_index(solid);
+        peer.set_snapshot_milestone_index(snapshot);
+
+        manager.handshaked_peers.insert(epid, Arc::new(peer));
+    }
+
+    fn ports(manager: &PeerManager, index: MilestoneIndex) -> Vec<u16> {
+        let mut ports: Vec<u16> = manager
+            .peers_for_index(index)
+            .iter()
+            .map(|epid| manager.handshaked_peers.get(epid).unwrap().address.port().0)
+            .collect();
+        ports.sort();
+        ports
+    }
+
+    #[test]
+    fn peers_for_index_prefers_covering_peers() {
+        let (command_sender, _) = mpsc::channel(1);
+        let manager = PeerManager::new(Network::new(command_sender));
+
+        add_handshaked_peer(&manager, 16000, 100, 10);
+        add_handshaked_peer(&manager, 16001, 50, 0);
+        add_handshaked_peer(&manager, 16002, 200, 60);
+
+        assert_eq!(ports(&manager, 40), vec![16000, 16001]);
+        assert_eq!(ports(&manager, 100), vec![16000, 16002]);
+        assert_eq!(ports(&manager, 10), vec![16001]);
+        assert_eq!(ports(&manager, 300), vec![16000, 16001, 16002]);
+    }
+}
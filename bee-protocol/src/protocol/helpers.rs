@@ -10,39 +10,139 @@
 // See the License for the specific language governing permissions and limitations under the License.
 
 use crate::{
-    message::{Heartbeat, TransactionBroadcast},
-    milestone::MilestoneIndex,
+    message::{Heartbeat, MilestoneRequest, TransactionBroadcast},
+    milestone::{MilestoneConfirmed, MilestoneIndex},
     protocol::Protocol,
     worker::{
-        BroadcasterWorkerEvent, MilestoneRequesterWorkerEntry, MilestoneSolidifierWorkerEvent, SenderWorker,
-        TransactionRequesterWorkerEntry, TransactionSolidifierWorkerEvent,
+        assign_round_robin, is_awaited, outstanding_requests, BroadcasterWorkerEvent, MilestoneRequesterWorkerEntry,
+        MilestoneSolidifierWorkerEvent, PrunerWorkerEvent, SenderWorker, TransactionRequesterWorkerEntry,
+        TransactionSolidifierWorkerEvent,
     },
 };
 
 use bee_bundle::Hash;
 use bee_network::EndpointId;
+use bee_tangle::tangle;
 
-use futures::sink::SinkExt;
+use futures::{channel::mpsc, sink::SinkExt};
 use log::warn;
 
+use std::time::Instant;
+
 impl Protocol {
     // MilestoneRequest
 
-    pub fn request_milestone(index: MilestoneIndex, to: Option<EndpointId>) {
+    /// Queues a request of the milestone at `index`, and returns whether it was queued, which it isn't if the
+    /// requester queue is bounded and full.
+    pub fn request_milestone(index: MilestoneIndex, to: Option<EndpointId>) -> bool {
         Protocol::get()
             .milestone_requester_worker
             .0
-            .insert(MilestoneRequesterWorkerEntry(index, to));
+            .insert(MilestoneRequesterWorkerEntry(index, to))
+            .is_ok()
+    }
+
+    /// Requests the missing milestones from `start` to `end` inclusive that aren't already awaited, spread round-robin
+    /// across the handshaked peers.
+    ///
+    /// At most `milestone_requester_peer_cap` milestones are awaited from each peer at a time, the rest of the range is
+    /// left for a later call. Without handshaked peers, the milestones are queued to be sent to any peer.
+    pub fn request_milestone_range(start: MilestoneIndex, end: MilestoneIndex) {
+        let requested = &Protocol::get().requested_milestones;
+        let now = Instant::now();
+
+        let missing = (start..=end).filter(|index| {
+            !tangle().contains_milestone((*index).into())
+                && !requested
+                    .get(index)
+                    .is_some_and(|entry| is_awaited(entry.value().1, now))
+        });
+
+        let epids: Vec<EndpointId> = Protocol::get()
+            .peer_manager
+            .handshaked_peers
+            .iter()
+            .map(|entry| *entry.key())
+            .collect();
+
+        let requests: Vec<(MilestoneIndex, Option<EndpointId>)> = if epids.is_empty() {
+            missing.map(|index| (index, None)).collect()
+        } else {
+            assign_round_robin(
+                missing,
+                start as usize,
+                &epids,
+                Protocol::get().config.workers.milestone_requester_peer_cap,
+                &outstanding_requests(requested, now),
+            )
+            .into_iter()
+            .map(|(index, epid)| (index, Some(epid)))
+            .collect()
+        };
+
+        for (index, epid) in requests {
+            // Recorded before queueing, as the requester worker may update or release the entry once it is queued.
+            requested.insert(index, (epid, now));
+
+            if !Protocol::request_milestone(index, epid) {
+                requested.remove(&index);
+                warn!(
+                    "[Protocol ] Requesting milestones {} to {} failed: too many pending requests.",
+                    index, end
+                );
+                break;
+            }
+        }
     }
 
-    pub fn request_last_milestone(to: Option<EndpointId>) {
-        Protocol::request_milestone(0, to);
+    pub fn request_last_milestone(to: Option<EndpointId>) -> bool {
+        Protocol::request_milestone(MilestoneRequest::LATEST_INDEX, to)
     }
 
     pub fn milestone_requester_is_empty() -> bool {
         Protocol::get().milestone_requester_worker.0.is_empty()
     }
 
+    // MilestoneConfirmed
+
+    /// Returns a receiver of a `MilestoneConfirmed` event for every milestone confirmed from now on.
+    pub fn subscribe_milestones() -> mpsc::UnboundedReceiver<MilestoneConfirmed> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        if let Ok(mut subscribers) = Protocol::get().milestone_subscribers.lock() {
+            subscribers.push(sender);
+        }
+
+        receiver
+    }
+
+    pub(crate) fn notify_milestone_confirmed(event: MilestoneConfirmed) {
+        if let Ok(mut subscribers) = Protocol::get().milestone_subscribers.lock() {
+            // Subscribers that dropped their receiver are removed.
+            subscribers.retain(|subscriber| subscriber.unbounded_send(event.clone()).is_ok());
+        }
+    }
+
+    // Handshake
+
+    /// Returns a receiver of the id of every peer disconnected from now on for not completing the handshake in time.
+    pub fn subscribe_handshake_timeouts() -> mpsc::UnboundedReceiver<EndpointId> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        if let Ok(mut subscribers) = Protocol::get().handshake_timeout_subscribers.lock() {
+            subscribers.push(sender);
+        }
+
+        receiver
+    }
+
+    pub(crate) fn notify_handshake_timeout(epid: EndpointId) {
+        if let Ok(mut subscribers) = Protocol::get().handshake_timeout_subscribers.lock() {
+            // Subscribers that dropped their receiver are removed.
+            subscribers.retain(|subscriber| subscriber.unbounded_send(epid).is_ok());
+        }
+    }
+
     // TransactionBroadcast
 
     pub async fn send_transaction(to: EndpointId, transaction: &[u8]) {
@@ -76,11 +176,14 @@ impl Protocol {
 
     // TransactionRequest
 
-    pub async fn request_transaction(hash: Hash, index: MilestoneIndex) {
+    /// Queues a request of the transaction `hash`, and returns whether it was queued, which it isn't if the
+    /// requester queue is bounded and full.
+    pub async fn request_transaction(hash: Hash, index: MilestoneIndex) -> bool {
         Protocol::get()
             .transaction_requester_worker
             .0
-            .insert(TransactionRequesterWorkerEntry(hash, index));
+            .insert(TransactionRequesterWorkerEntry(hash, index))
+            .is_ok()
     }
 
     pub fn transaction_requester_is_empty() -> bool {
@@ -130,4 +233,25 @@ impl Protocol {
             warn!("[Protocol ] Triggering milestone solidification failed: {}.", e);
         }
     }
+
+    // Pruner
+
+    pub async fn update_snapshot_milestone_index(index: MilestoneIndex) {
+        let previous_index = *tangle().get_snapshot_milestone_index();
+
+        tangle().update_snapshot_milestone_index(index.into());
+
+        if index > previous_index {
+            if let Err(e) = Protocol::get()
+                .pruner_worker
+                // TODO try to avoid clone
+                .0
+                .clone()
+                .send(PrunerWorkerEvent(index))
+                .await
+            {
+                warn!("[Protocol ] Triggering pruning failed: {}.", e);
+            }
+        }
+    }
 }
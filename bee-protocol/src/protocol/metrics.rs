@@ -18,6 +18,7 @@ pub struct ProtocolMetrics {
     random_transactions_received: AtomicU64,
     new_transactions_received: AtomicU64,
     known_transactions_received: AtomicU64,
+    insufficient_weight_transactions_received: AtomicU64,
 
     invalid_messages_received: AtomicU64,
 
@@ -30,6 +31,12 @@ pub struct ProtocolMetrics {
     transaction_broadcast_sent: AtomicU64,
     transaction_request_sent: AtomicU64,
     heartbeat_sent: AtomicU64,
+
+    valid_milestones: AtomicU64,
+    unknown_tail_milestones: AtomicU64,
+    not_a_tail_milestones: AtomicU64,
+    incomplete_milestones: AtomicU64,
+    invalid_milestones: AtomicU64,
 }
 
 impl ProtocolMetrics {
@@ -79,6 +86,15 @@ impl ProtocolMetrics {
         self.known_transactions_received.fetch_add(1, Ordering::SeqCst)
     }
 
+    pub fn insufficient_weight_transactions_received(&self) -> u64 {
+        self.insufficient_weight_transactions_received.load(Ordering::Relaxed)
+    }
+
+    pub fn insufficient_weight_transactions_received_inc(&self) -> u64 {
+        self.insufficient_weight_transactions_received
+            .fetch_add(1, Ordering::SeqCst)
+    }
+
     pub fn invalid_messages_received(&self) -> u64 {
         self.invalid_messages_received.load(Ordering::Relaxed)
     }
@@ -150,6 +166,46 @@ impl ProtocolMetrics {
     pub fn heartbeat_sent_inc(&self) -> u64 {
         self.heartbeat_sent.fetch_add(1, Ordering::SeqCst)
     }
+
+    pub fn valid_milestones(&self) -> u64 {
+        self.valid_milestones.load(Ordering::Relaxed)
+    }
+
+    pub fn valid_milestones_inc(&self) -> u64 {
+        self.valid_milestones.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn unknown_tail_milestones(&self) -> u64 {
+        self.unknown_tail_milestones.load(Ordering::Relaxed)
+    }
+
+    pub fn unknown_tail_milestones_inc(&self) -> u64 {
+        self.unknown_tail_milestones.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn not_a_tail_milestones(&self) -> u64 {
+        self.not_a_tail_milestones.load(Ordering::Relaxed)
+    }
+
+    pub fn not_a_tail_milestones_inc(&self) -> u64 {
+        self.not_a_tail_milestones.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn incomplete_milestones(&self) -> u64 {
+        self.incomplete_milestones.load(Ordering::Relaxed)
+    }
+
+    pub fn incomplete_milestones_inc(&self) -> u64 {
+        self.incomplete_milestones.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn invalid_milestones(&self) -> u64 {
+        self.invalid_milestones.load(Ordering::Relaxed)
+    }
+
+    pub fn invalid_milestones_inc(&self) -> u64 {
+        self.invalid_milestones.fetch_add(1, Ordering::SeqCst)
+    }
 }
 
 #[cfg(test)]
@@ -166,18 +222,21 @@ mod tests {
         assert_eq!(metrics.random_transactions_received(), 0);
         assert_eq!(metrics.new_transactions_received(), 0);
         assert_eq!(metrics.known_transactions_received(), 0);
+        assert_eq!(metrics.insufficient_weight_transactions_received(), 0);
 
         metrics.invalid_transactions_received_inc();
         metrics.stale_transactions_received_inc();
         metrics.random_transactions_received_inc();
         metrics.new_transactions_received_inc();
         metrics.known_transactions_received_inc();
+        metrics.insufficient_weight_transactions_received_inc();
 
         assert_eq!(metrics.invalid_transactions_received(), 1);
         assert_eq!(metrics.stale_transactions_received(), 1);
         assert_eq!(metrics.random_transactions_received(), 1);
         assert_eq!(metrics.new_transactions_received(), 1);
         assert_eq!(metrics.known_transactions_received(), 1);
+        assert_eq!(metrics.insufficient_weight_transactions_received(), 1);
     }
 
     #[test]
@@ -222,4 +281,27 @@ mod tests {
         assert_eq!(metrics.transaction_request_sent(), 1);
         assert_eq!(metrics.heartbeat_sent(), 1);
     }
+
+    #[test]
+    fn node_metrics_milestones_test() {
+        let metrics = ProtocolMetrics::default();
+
+        assert_eq!(metrics.valid_milestones(), 0);
+        assert_eq!(metrics.unknown_tail_milestones(), 0);
+        assert_eq!(metrics.not_a_tail_milestones(), 0);
+        assert_eq!(metrics.incomplete_milestones(), 0);
+        assert_eq!(metrics.invalid_milestones(), 0);
+
+        metrics.valid_milestones_inc();
+        metrics.unknown_tail_milestones_inc();
+        metrics.not_a_tail_milestones_inc();
+        metrics.incomplete_milestones_inc();
+        metrics.invalid_milestones_inc();
+
+        assert_eq!(metrics.valid_milestones(), 1);
+        assert_eq!(metrics.unknown_tail_milestones(), 1);
+        assert_eq!(metrics.not_a_tail_milestones(), 1);
+        assert_eq!(metrics.incomplete_milestones(), 1);
+        assert_eq!(metrics.invalid_milestones(), 1);
+    }
 }
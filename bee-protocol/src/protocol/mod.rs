@@ -14,4 +14,4 @@ mod metrics;
 mod protocol;
 
 pub use metrics::ProtocolMetrics;
-pub use protocol::Protocol;
+pub use protocol::{Protocol, ProtocolInitError};
@@ -11,17 +11,17 @@
 
 use crate::{
     config::ProtocolConfig,
-    milestone::MilestoneIndex,
+    milestone::{MilestoneConfirmed, MilestoneIndex},
     peer::{Peer, PeerManager},
     protocol::ProtocolMetrics,
-    util::WaitPriorityQueue,
+    util::{WaitPriorityQueue, WorkerHandles},
     worker::{
         BroadcasterWorker, BroadcasterWorkerEvent, MilestoneRequesterWorker, MilestoneRequesterWorkerEntry,
         MilestoneResponderWorker, MilestoneResponderWorkerEvent, MilestoneSolidifierWorker,
         MilestoneSolidifierWorkerEvent, MilestoneValidatorWorker, MilestoneValidatorWorkerEvent, PeerHandshakerWorker,
-        StatusWorker, TransactionRequesterWorker, TransactionRequesterWorkerEntry, TransactionResponderWorker,
-        TransactionResponderWorkerEvent, TransactionSolidifierWorker, TransactionSolidifierWorkerEvent,
-        TransactionWorker, TransactionWorkerEvent,
+        PrunerWorker, PrunerWorkerEvent, StatusWorker, TransactionRequesterWorker, TransactionRequesterWorkerEntry,
+        TransactionResponderWorker, TransactionResponderWorkerEvent, TransactionSolidifierWorker,
+        TransactionSolidifierWorkerEvent, TransactionWorker, TransactionWorkerEvent,
     },
 };
 
@@ -33,6 +33,7 @@ use bee_signing::WotsPublicKey;
 use std::{
     ptr,
     sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 use async_std::task::spawn;
@@ -45,6 +46,13 @@ use log::warn;
 
 static mut PROTOCOL: *const Protocol = ptr::null();
 
+#[derive(Debug)]
+pub enum ProtocolInitError {
+    AlreadyInitialized,
+    InvalidCoordinatorSecurityLevel(u8),
+    InvalidCoordinatorDepth(u8),
+}
+
 pub struct Protocol {
     pub(crate) config: ProtocolConfig,
     pub(crate) network: Network,
@@ -79,16 +87,31 @@ pub struct Protocol {
         Mutex<Option<oneshot::Sender<()>>>,
     ),
     pub(crate) broadcaster_worker: (mpsc::Sender<BroadcasterWorkerEvent>, Mutex<Option<oneshot::Sender<()>>>),
+    pub(crate) pruner_worker: (mpsc::Sender<PrunerWorkerEvent>, Mutex<Option<oneshot::Sender<()>>>),
     pub(crate) status_worker: mpsc::Sender<()>,
+    pub(crate) workers: WorkerHandles,
     pub(crate) peer_manager: PeerManager,
-    pub(crate) requested: DashMap<Hash, MilestoneIndex>,
+    pub(crate) requested: DashMap<Hash, (MilestoneIndex, EndpointId, Instant)>,
+    // Milestones requested by index, along with the peer they were sent to, if any yet, and when.
+    pub(crate) requested_milestones: DashMap<MilestoneIndex, (Option<EndpointId>, Instant)>,
+    pub(crate) milestone_subscribers: Mutex<Vec<mpsc::UnboundedSender<MilestoneConfirmed>>>,
+    pub(crate) handshake_timeout_subscribers: Mutex<Vec<mpsc::UnboundedSender<EndpointId>>>,
 }
 
 impl Protocol {
-    pub async fn init(config: ProtocolConfig, network: Network) {
+    pub async fn init(config: ProtocolConfig, network: Network) -> Result<(), ProtocolInitError> {
+        let security_level = config.coordinator.security_level;
+        if !(1..=3).contains(&security_level) {
+            return Err(ProtocolInitError::InvalidCoordinatorSecurityLevel(security_level));
+        }
+
+        if config.coordinator.depth == 0 {
+            return Err(ProtocolInitError::InvalidCoordinatorDepth(config.coordinator.depth));
+        }
+
         if unsafe { !PROTOCOL.is_null() } {
             warn!("[Protocol ] Already initialized.");
-            return;
+            return Err(ProtocolInitError::AlreadyInitialized);
         }
 
         let (transaction_worker_tx, transaction_worker_rx) = mpsc::channel(config.workers.transaction_worker_bound);
@@ -121,8 +144,24 @@ impl Protocol {
         let (broadcaster_worker_tx, broadcaster_worker_rx) = mpsc::channel(config.workers.broadcaster_worker_bound);
         let (broadcaster_worker_shutdown_tx, broadcaster_worker_shutdown_rx) = oneshot::channel();
 
+        let (pruner_worker_tx, pruner_worker_rx) = mpsc::channel(config.workers.pruner_worker_bound);
+        let (pruner_worker_shutdown_tx, pruner_worker_shutdown_rx) = oneshot::channel();
+
         let (status_worker_shutdown_tx, status_worker_shutdown_rx) = mpsc::channel(1);
 
+        let transaction_requester_worker_queue = match config.workers.transaction_requester_worker_bound {
+            Some(bound) => {
+                WaitPriorityQueue::with_capacity(bound).drop_lowest(config.workers.requester_worker_drop_lowest)
+            }
+            None => WaitPriorityQueue::default(),
+        };
+        let milestone_requester_worker_queue = match config.workers.milestone_requester_worker_bound {
+            Some(bound) => {
+                WaitPriorityQueue::with_capacity(bound).drop_lowest(config.workers.requester_worker_drop_lowest)
+            }
+            None => WaitPriorityQueue::default(),
+        };
+
         let protocol = Protocol {
             config,
             network: network.clone(),
@@ -137,11 +176,11 @@ impl Protocol {
                 Mutex::new(Some(milestone_responder_worker_shutdown_tx)),
             ),
             transaction_requester_worker: (
-                Default::default(),
+                transaction_requester_worker_queue,
                 Mutex::new(Some(transaction_requester_worker_shutdown_tx)),
             ),
             milestone_requester_worker: (
-                Default::default(),
+                milestone_requester_worker_queue,
                 Mutex::new(Some(milestone_requester_worker_shutdown_tx)),
             ),
             milestone_validator_worker: (
@@ -157,57 +196,75 @@ impl Protocol {
                 Mutex::new(Some(milestone_solidifier_worker_shutdown_tx)),
             ),
             broadcaster_worker: (broadcaster_worker_tx, Mutex::new(Some(broadcaster_worker_shutdown_tx))),
+            pruner_worker: (pruner_worker_tx, Mutex::new(Some(pruner_worker_shutdown_tx))),
             status_worker: status_worker_shutdown_tx,
+            workers: Default::default(),
             peer_manager: PeerManager::new(network.clone()),
             requested: Default::default(),
+            requested_milestones: Default::default(),
+            milestone_subscribers: Default::default(),
+            handshake_timeout_subscribers: Default::default(),
         };
 
         unsafe {
             PROTOCOL = Box::leak(protocol.into()) as *const _;
         }
 
-        spawn(
-            TransactionWorker::new(Protocol::get().config.workers.transaction_worker_cache).run(
+        let workers = &Protocol::get().workers;
+
+        workers.spawn(
+            TransactionWorker::new(
+                Protocol::get().config.workers.transaction_worker_cache,
+                Protocol::get().config.workers.transaction_worker_cache_eviction,
+                Protocol::get().config.mwm,
+            )
+            .run(
                 transaction_worker_rx,
                 transaction_worker_shutdown_rx,
                 Protocol::get().milestone_validator_worker.0.clone(),
             ),
         );
-        spawn(TransactionResponderWorker::new().run(
+        workers.spawn(TransactionResponderWorker::new().run(
             transaction_responder_worker_rx,
             transaction_responder_worker_shutdown_rx,
         ));
-        spawn(
+        workers.spawn(
             MilestoneResponderWorker::new().run(milestone_responder_worker_rx, milestone_responder_worker_shutdown_rx),
         );
-        spawn(TransactionRequesterWorker::new().run(transaction_requester_worker_shutdown_rx));
-        spawn(MilestoneRequesterWorker::new().run(milestone_requester_worker_shutdown_rx));
+        workers.spawn(TransactionRequesterWorker::new().run(transaction_requester_worker_shutdown_rx));
+        workers.spawn(MilestoneRequesterWorker::new().run(milestone_requester_worker_shutdown_rx));
 
         match Protocol::get().config.coordinator.sponge_type {
-            SpongeType::Kerl => spawn(
+            SpongeType::Kerl => workers.spawn(
                 MilestoneValidatorWorker::<Kerl, WotsPublicKey<Kerl>>::new()
                     .run(milestone_validator_worker_rx, milestone_validator_worker_shutdown_rx),
             ),
-            SpongeType::CurlP27 => spawn(
+            SpongeType::CurlP27 => workers.spawn(
                 MilestoneValidatorWorker::<CurlP27, WotsPublicKey<CurlP27>>::new()
                     .run(milestone_validator_worker_rx, milestone_validator_worker_shutdown_rx),
             ),
-            SpongeType::CurlP81 => spawn(
+            SpongeType::CurlP81 => workers.spawn(
                 MilestoneValidatorWorker::<CurlP81, WotsPublicKey<CurlP81>>::new()
                     .run(milestone_validator_worker_rx, milestone_validator_worker_shutdown_rx),
             ),
         };
 
-        spawn(TransactionSolidifierWorker::new().run(
+        workers.spawn(TransactionSolidifierWorker::new().run(
             transaction_solidifier_worker_rx,
             transaction_solidifier_worker_shutdown_rx,
         ));
-        spawn(
+        workers.spawn(
             MilestoneSolidifierWorker::new()
                 .run(milestone_solidifier_worker_rx, milestone_solidifier_worker_shutdown_rx),
         );
-        spawn(BroadcasterWorker::new(network).run(broadcaster_worker_rx, broadcaster_worker_shutdown_rx));
-        spawn(StatusWorker::new().run(status_worker_shutdown_rx));
+        workers.spawn(BroadcasterWorker::new(network).run(broadcaster_worker_rx, broadcaster_worker_shutdown_rx));
+        workers.spawn(
+            PrunerWorker::new(Protocol::get().config.pruning_retention)
+                .run(pruner_worker_rx, pruner_worker_shutdown_rx),
+        );
+        workers.spawn(StatusWorker::new().run(status_worker_shutdown_rx));
+
+        Ok(())
     }
 
     pub async fn shutdown() {
@@ -274,9 +331,18 @@ impl Protocol {
                 }
             }
         }
+        if let Ok(mut shutdown) = Protocol::get().pruner_worker.1.lock() {
+            if let Some(shutdown) = shutdown.take() {
+                if let Err(e) = shutdown.send(()) {
+                    warn!("[Protocol ] Shutting down PrunerWorker failed: {:?}.", e);
+                }
+            }
+        }
         if let Err(e) = Protocol::get().status_worker.clone().send(()).await {
             warn!("[Protocol ] Shutting down StatusWorker failed: {:?}.", e);
         }
+
+        Protocol::get().workers.join().await;
     }
 
     pub(crate) fn get() -> &'static Protocol {
@@ -301,8 +367,48 @@ impl Protocol {
 
         Protocol::get().peer_manager.add(peer.clone());
 
-        spawn(PeerHandshakerWorker::new(Protocol::get().network.clone(), peer).run(receiver_rx, receiver_shutdown_rx));
+        spawn(
+            PeerHandshakerWorker::new(
+                Protocol::get().network.clone(),
+                peer,
+                Duration::from_secs(Protocol::get().config.handshake_timeout),
+            )
+            .run(receiver_rx, receiver_shutdown_rx),
+        );
 
         (receiver_tx, receiver_shutdown_tx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use async_std::task::block_on;
+
+    fn network() -> Network {
+        let (command_sender, _) = mpsc::channel(1);
+        Network::new(command_sender)
+    }
+
+    #[test]
+    fn init_with_invalid_security_level_fails() {
+        let config = ProtocolConfig::build().coo_security_level(4).finish();
+
+        match block_on(Protocol::init(config, network())) {
+            Err(ProtocolInitError::InvalidCoordinatorSecurityLevel(4)) => {}
+            _ => panic!("Expected an invalid security level error."),
+        }
+    }
+
+    #[test]
+    fn init_with_invalid_depth_fails() {
+        let config = ProtocolConfig::build().coo_depth(0).finish();
+
+        match block_on(Protocol::init(config, network())) {
+            Err(ProtocolInitError::InvalidCoordinatorDepth(0)) => {}
+            _ => panic!("Expected an invalid depth error."),
+        }
+    }
+}
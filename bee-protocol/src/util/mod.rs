@@ -9,6 +9,14 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
+#[cfg(test)]
+mod test;
+mod token_bucket;
 mod wait_priority_queue;
+mod workers;
 
+#[cfg(test)]
+pub(crate) use test::init_protocol;
+pub(crate) use token_bucket::TokenBucket;
 pub(crate) use wait_priority_queue::WaitPriorityQueue;
+pub(crate) use workers::WorkerHandles;
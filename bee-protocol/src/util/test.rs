@@ -0,0 +1,28 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use crate::{Protocol, ProtocolConfig};
+
+use bee_network::NetworkConfig;
+
+use std::sync::Once;
+
+use async_std::task::block_on;
+
+static INIT: Once = Once::new();
+
+// The network and the protocol can only be initialized once per process, so all tests share the same instances.
+pub(crate) fn init_protocol() {
+    INIT.call_once(|| {
+        let (network, _shutdown, _events) = bee_network::init(NetworkConfig::build().finish());
+        block_on(Protocol::init(ProtocolConfig::build().finish(), network)).unwrap();
+    });
+}
@@ -0,0 +1,85 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use std::time::{Duration, Instant};
+
+/// A token bucket allowing `rate` operations per second, in bursts of at most `rate` operations.
+pub(crate) struct TokenBucket {
+    rate: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket, or `None` if `rate` is 0, which stands for no limit.
+    pub(crate) fn new(rate: u32, now: Instant) -> Option<Self> {
+        if rate == 0 {
+            return None;
+        }
+
+        Some(Self {
+            rate: rate as f64,
+            tokens: rate as f64,
+            last_refill: now,
+        })
+    }
+
+    /// Takes a token if one is available at `now`, otherwise returns how long to wait for the next one.
+    pub(crate) fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn zero_rate_is_unlimited() {
+        assert!(TokenBucket::new(0, Instant::now()).is_none());
+    }
+
+    #[test]
+    fn burst_then_wait() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(4, now).unwrap();
+
+        for _ in 0..4 {
+            assert_eq!(bucket.try_take(now), Ok(()));
+        }
+        assert_eq!(bucket.try_take(now), Err(Duration::from_millis(250)));
+
+        let later = now + Duration::from_millis(250);
+        assert_eq!(bucket.try_take(later), Ok(()));
+        assert!(bucket.try_take(later).is_err());
+    }
+
+    #[test]
+    fn refill_is_capped() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(2, now).unwrap();
+
+        let later = now + Duration::from_secs(60);
+        assert_eq!(bucket.try_take(later), Ok(()));
+        assert_eq!(bucket.try_take(later), Ok(()));
+        assert!(bucket.try_take(later).is_err());
+    }
+}
@@ -13,58 +13,373 @@ use std::{
     collections::{BinaryHeap, VecDeque},
     future::Future,
     pin::Pin,
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
     task::{Context, Poll, Waker},
+    time::Duration,
 };
 
+// Tasks waiting in `pop`, along with the identifiers that let them deregister.
+type Waiters = VecDeque<(usize, Waker)>;
+
 pub(crate) struct WaitPriorityQueue<T: Ord + Eq> {
     // TODO use an RWLock ?
-    inner: Mutex<(BinaryHeap<T>, VecDeque<Waker>)>,
+    inner: Mutex<(BinaryHeap<T>, Waiters)>,
+    next_waiter: AtomicUsize,
+    capacity: Option<usize>,
+    drop_lowest: bool,
 }
 
 impl<T: Ord + Eq> WaitPriorityQueue<T> {
+    /// Creates a queue holding at most `capacity` entries.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new((BinaryHeap::with_capacity(capacity), VecDeque::new())),
+            next_waiter: AtomicUsize::new(0),
+            capacity: Some(capacity),
+            drop_lowest: false,
+        }
+    }
+
+    /// Makes a full queue evict its lowest entry to make room for a higher one, instead of rejecting it.
+    pub(crate) fn drop_lowest(mut self, drop_lowest: bool) -> Self {
+        self.drop_lowest = drop_lowest;
+        self
+    }
+
     pub(crate) fn is_empty(&self) -> bool {
         self.inner.lock().unwrap().0.is_empty()
     }
+
+    pub(crate) fn len(&self) -> usize {
+        self.inner.lock().unwrap().0.len()
+    }
+
+    /// Applies `f` to the highest entry, if any, without removing it.
+    pub(crate) fn peek<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.inner.lock().unwrap().0.peek().map(f)
+    }
 }
 
 impl<T: Ord + Eq> Default for WaitPriorityQueue<T> {
     fn default() -> Self {
         Self {
             inner: Mutex::new((BinaryHeap::new(), VecDeque::new())),
+            next_waiter: AtomicUsize::new(0),
+            capacity: None,
+            drop_lowest: false,
         }
     }
 }
 
 impl<T: Ord + Eq> WaitPriorityQueue<T> {
-    pub fn insert(&self, entry: T) {
+    /// Inserts an entry and wakes a task waiting in `pop`, if any.
+    ///
+    /// If the queue is full, the entry is handed back, unless the queue drops its lowest entries and the new one is
+    /// higher, in which case the lowest entry is evicted (in O(n)) instead.
+    pub fn insert(&self, entry: T) -> Result<(), T> {
         let mut inner = self.inner.lock().unwrap();
 
+        if let Some(capacity) = self.capacity {
+            if inner.0.len() >= capacity {
+                if !self.drop_lowest {
+                    return Err(entry);
+                }
+
+                let mut entries = std::mem::take(&mut inner.0).into_vec();
+                let lowest = entries
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.cmp(b))
+                    .map(|(index, _)| index);
+
+                match lowest {
+                    Some(index) if entries[index] < entry => {
+                        entries.swap_remove(index);
+                        inner.0 = BinaryHeap::from(entries);
+                    }
+                    _ => {
+                        inner.0 = BinaryHeap::from(entries);
+                        return Err(entry);
+                    }
+                }
+            }
+        }
+
         inner.0.push(entry);
-        if let Some(waker) = inner.1.pop_front() {
+        if let Some((_, waker)) = inner.1.pop_front() {
             Waker::wake(waker)
         }
+
+        Ok(())
     }
 
     pub fn pop(&self) -> impl Future<Output = T> + '_ {
-        WaitFut(self)
+        WaitFut {
+            queue: self,
+            waiter: None,
+        }
+    }
+
+    /// Waits for an entry like `pop`, but resolves to `None` once `duration` has elapsed.
+    ///
+    /// On timeout, the waiter is deregistered so that a later `insert` wakes a live task instead.
+    pub async fn pop_timeout(&self, duration: Duration) -> Option<T> {
+        async_std::future::timeout(duration, self.pop()).await.ok()
+    }
+
+    /// Removes and returns an entry matching `predicate`, if any.
+    ///
+    /// The heap is rebuilt without the entry, which is O(n) in the number of queued entries. Tasks waiting in `pop`
+    /// stay registered and are woken by the next `insert`; a task that was woken for the removed entry registers
+    /// again if it finds the queue empty.
+    pub fn remove(&self, predicate: impl Fn(&T) -> bool) -> Option<T> {
+        let mut inner = self.inner.lock().unwrap();
+
+        let mut entries = std::mem::take(&mut inner.0).into_vec();
+        let removed = entries
+            .iter()
+            .position(predicate)
+            .map(|index| entries.swap_remove(index));
+        inner.0 = BinaryHeap::from(entries);
+
+        removed
     }
 }
 
-pub(crate) struct WaitFut<'a, T: Ord + Eq>(&'a WaitPriorityQueue<T>);
+pub(crate) struct WaitFut<'a, T: Ord + Eq> {
+    queue: &'a WaitPriorityQueue<T>,
+    // Identifies the waker registered by this future, if any.
+    waiter: Option<usize>,
+}
 
 impl<'a, T: Ord + Eq> Future for WaitFut<'a, T> {
     type Output = T;
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
-        let mut inner = self.0.inner.lock().unwrap();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let queue = self.queue;
+        let mut inner = queue.inner.lock().unwrap();
 
         match inner.0.pop() {
-            Some(entry) => Poll::Ready(entry),
+            Some(entry) => {
+                if let Some(waiter) = self.waiter.take() {
+                    inner.1.retain(|(id, _)| *id != waiter);
+                }
+                Poll::Ready(entry)
+            }
             None => {
-                inner.1.push_back(cx.waker().clone());
+                let waker = cx.waker().clone();
+                let registered = self
+                    .waiter
+                    .and_then(|waiter| inner.1.iter_mut().find(|(id, _)| *id == waiter));
+
+                match registered {
+                    Some((_, registered)) => *registered = waker,
+                    None => {
+                        let waiter = queue.next_waiter.fetch_add(1, Ordering::Relaxed);
+                        inner.1.push_back((waiter, waker));
+                        self.waiter = Some(waiter);
+                    }
+                }
+
                 Poll::Pending
             }
         }
     }
 }
+
+impl<'a, T: Ord + Eq> Drop for WaitFut<'a, T> {
+    fn drop(&mut self) {
+        if let (Some(waiter), Ok(mut inner)) = (self.waiter, self.queue.inner.lock()) {
+            let registered = inner.1.len();
+            inner.1.retain(|(id, _)| *id != waiter);
+
+            // This future was woken for an entry it will never take, so the wake-up is passed on.
+            if inner.1.len() == registered && !inner.0.is_empty() {
+                if let Some((_, waker)) = inner.1.pop_front() {
+                    Waker::wake(waker)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use async_std::{
+        future::timeout,
+        task::{block_on, spawn},
+    };
+
+    use std::{sync::Arc, time::Duration};
+
+    #[test]
+    fn remove() {
+        let queue = WaitPriorityQueue::default();
+
+        queue.insert(1).unwrap();
+        queue.insert(3).unwrap();
+        queue.insert(2).unwrap();
+
+        assert_eq!(queue.remove(|entry| *entry == 3), Some(3));
+        assert_eq!(queue.remove(|entry| *entry == 3), None);
+        assert_eq!(block_on(queue.pop()), 2);
+        assert_eq!(queue.remove(|entry| *entry == 1), Some(1));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn pop_after_remove_waits_for_insert() {
+        let queue = Arc::new(WaitPriorityQueue::default());
+
+        queue.insert(1).unwrap();
+        assert_eq!(queue.remove(|entry| *entry == 1), Some(1));
+
+        let popper = {
+            let queue = queue.clone();
+            spawn(async move { queue.pop().await })
+        };
+
+        assert!(block_on(timeout(Duration::from_millis(50), queue.pop())).is_err());
+
+        queue.insert(2).unwrap();
+        queue.insert(3).unwrap();
+
+        let mut popped = vec![block_on(popper), block_on(queue.pop())];
+        popped.sort();
+        assert_eq!(popped, vec![2, 3]);
+    }
+
+    #[test]
+    fn with_capacity_rejects_when_full() {
+        let queue = WaitPriorityQueue::with_capacity(2);
+
+        assert_eq!(queue.insert(1), Ok(()));
+        assert_eq!(queue.insert(3), Ok(()));
+        assert_eq!(queue.insert(2), Err(2));
+
+        assert_eq!(block_on(queue.pop()), 3);
+        assert_eq!(queue.insert(2), Ok(()));
+        assert_eq!(block_on(queue.pop()), 2);
+        assert_eq!(block_on(queue.pop()), 1);
+    }
+
+    #[test]
+    fn with_capacity_drops_lowest() {
+        let queue = WaitPriorityQueue::with_capacity(2).drop_lowest(true);
+
+        assert_eq!(queue.insert(2), Ok(()));
+        assert_eq!(queue.insert(3), Ok(()));
+        assert_eq!(queue.insert(1), Err(1));
+        assert_eq!(queue.insert(2), Err(2));
+        assert_eq!(queue.insert(4), Ok(()));
+
+        assert_eq!(block_on(queue.pop()), 4);
+        assert_eq!(block_on(queue.pop()), 3);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn default_is_unbounded() {
+        let queue = WaitPriorityQueue::default();
+
+        for i in 0..10_000 {
+            assert_eq!(queue.insert(i), Ok(()));
+        }
+    }
+
+    #[test]
+    fn len_and_peek() {
+        let queue = WaitPriorityQueue::default();
+
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.peek(|entry| *entry), None);
+
+        queue.insert(1).unwrap();
+        queue.insert(3).unwrap();
+        queue.insert(2).unwrap();
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(|entry| *entry), Some(3));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(block_on(queue.pop()), 3);
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn peek_keeps_waiters_parked() {
+        let queue = Arc::new(WaitPriorityQueue::default());
+
+        let popper = {
+            let queue = queue.clone();
+            spawn(async move { queue.pop().await })
+        };
+
+        // Lets the popper park before inspecting the empty queue.
+        block_on(async_std::task::sleep(Duration::from_millis(50)));
+        assert_eq!(queue.peek(|entry| *entry), None);
+        assert_eq!(queue.len(), 0);
+
+        queue.insert(1).unwrap();
+        assert_eq!(block_on(popper), 1);
+    }
+
+    #[test]
+    fn pop_timeout() {
+        let queue = WaitPriorityQueue::default();
+
+        assert_eq!(block_on(queue.pop_timeout(Duration::from_millis(10))), None);
+        assert!(queue.inner.lock().unwrap().1.is_empty());
+
+        queue.insert(1).unwrap();
+        assert_eq!(block_on(queue.pop_timeout(Duration::from_millis(10))), Some(1));
+    }
+
+    #[test]
+    fn insert_after_pop_timeout_wakes_live_waiter() {
+        let queue = Arc::new(WaitPriorityQueue::default());
+
+        let timed_out = {
+            let queue = queue.clone();
+            spawn(async move { queue.pop_timeout(Duration::from_millis(100)).await })
+        };
+
+        // Lets the timed out waiter register ahead of the popper.
+        block_on(async_std::task::sleep(Duration::from_millis(20)));
+        let popper = {
+            let queue = queue.clone();
+            spawn(async move { queue.pop().await })
+        };
+
+        assert_eq!(block_on(timed_out), None);
+        assert_eq!(queue.inner.lock().unwrap().1.len(), 1);
+
+        queue.insert(1).unwrap();
+        assert_eq!(block_on(timeout(Duration::from_secs(1), popper)), Ok(1));
+    }
+
+    #[test]
+    fn dropped_waiter_passes_wake_up_on() {
+        let queue = Arc::new(WaitPriorityQueue::default());
+
+        let mut first = Box::pin(queue.pop());
+        let mut second = Box::pin(queue.pop());
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(first.as_mut().poll(&mut cx).is_pending());
+        assert!(second.as_mut().poll(&mut cx).is_pending());
+
+        // Wakes the first waiter, which then goes away without taking the entry.
+        queue.insert(1).unwrap();
+        drop(first);
+
+        assert!(queue.inner.lock().unwrap().1.is_empty());
+        assert_eq!(second.as_mut().poll(&mut cx), Poll::Ready(1));
+    }
+}
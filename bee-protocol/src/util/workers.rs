@@ -0,0 +1,104 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use async_std::task::{spawn, JoinHandle};
+
+use std::{future::Future, sync::Mutex};
+
+/// Handles of spawned workers, through which their completion can be awaited.
+#[derive(Default)]
+pub(crate) struct WorkerHandles(Mutex<Vec<JoinHandle<()>>>);
+
+impl WorkerHandles {
+    /// Spawns `worker` and keeps a handle resolving once it has finished.
+    pub(crate) fn spawn(&self, worker: impl Future<Output = ()> + Send + 'static) {
+        let handle = spawn(worker);
+
+        self.0.lock().unwrap().push(handle);
+    }
+
+    /// Waits for every spawned worker to finish, including the ones spawned while waiting.
+    ///
+    /// Dropping the returned future before it resolves detaches the workers it was waiting for.
+    pub(crate) async fn join(&self) {
+        loop {
+            let handles = std::mem::take(&mut *self.0.lock().unwrap());
+
+            if handles.is_empty() {
+                break;
+            }
+
+            for handle in handles {
+                handle.await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use async_std::task::{block_on, sleep};
+    use futures::channel::oneshot;
+
+    use std::{
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    #[test]
+    fn join_waits_for_every_worker() {
+        let handles = Arc::new(WorkerHandles::default());
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let mut shutdowns = Vec::new();
+
+        for _ in 0..3 {
+            let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+            let stopped = stopped.clone();
+
+            handles.spawn(async move {
+                let _ = shutdown_rx.await;
+                stopped.fetch_add(1, Ordering::SeqCst);
+            });
+            shutdowns.push(shutdown_tx);
+        }
+
+        let joined = Arc::new(AtomicBool::new(false));
+        let join = {
+            let handles = handles.clone();
+            let joined = joined.clone();
+            spawn(async move {
+                handles.join().await;
+                joined.store(true, Ordering::SeqCst);
+            })
+        };
+
+        let last = shutdowns.pop().unwrap();
+        for shutdown in shutdowns {
+            shutdown.send(()).unwrap();
+        }
+
+        block_on(sleep(Duration::from_millis(50)));
+        assert_eq!(stopped.load(Ordering::SeqCst), 2);
+        assert!(!joined.load(Ordering::SeqCst));
+
+        last.send(()).unwrap();
+        block_on(join);
+
+        assert_eq!(stopped.load(Ordering::SeqCst), 3);
+        assert!(joined.load(Ordering::SeqCst));
+    }
+}
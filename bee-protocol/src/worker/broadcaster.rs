@@ -29,6 +29,18 @@ pub(crate) struct BroadcasterWorkerEvent {
     pub(crate) transaction_broadcast: TransactionBroadcast,
 }
 
+// Pairs each recipient with its own copy of `item`, the last one taking `item` itself so that only one clone is made
+// per additional recipient.
+fn fan_out<R, T: Clone>(recipients: Vec<R>, item: T) -> impl Iterator<Item = (R, T)> {
+    let last = recipients.len().saturating_sub(1);
+    let mut item = Some(item);
+
+    recipients.into_iter().enumerate().map(move |(i, recipient)| {
+        let copy = if i == last { item.take() } else { item.clone() };
+        (recipient, copy.expect("item is only taken by the last recipient"))
+    })
+}
+
 pub(crate) struct BroadcasterWorker {
     network: Network,
 }
@@ -39,32 +51,35 @@ impl BroadcasterWorker {
     }
 
     async fn broadcast(&mut self, from: Option<EndpointId>, bytes: Vec<u8>) {
-        for entry in Protocol::get().peer_manager.handshaked_peers.iter() {
-            if match from {
-                Some(from) => from != *entry.key(),
-                None => true,
-            } {
-                match self
-                    .network
-                    .send(SendMessage {
-                        epid: *entry.key(),
-                        bytes: bytes.clone(),
-                        responder: None,
-                    })
-                    .await
-                {
-                    Ok(_) => {
-                        // TODO metrics
-                    }
-                    Err(e) => {
-                        warn!(
-                            "[BroadcasterWorker ] Broadcasting transaction to {:?} failed: {:?}.",
-                            *entry.key(),
-                            e
-                        );
-                    }
-                };
-            }
+        // Recipients are collected first to not hold the peers map across the sends.
+        let recipients: Vec<EndpointId> = Protocol::get()
+            .peer_manager
+            .handshaked_peers
+            .iter()
+            .map(|entry| *entry.key())
+            .filter(|epid| from != Some(*epid))
+            .collect();
+
+        for (epid, bytes) in fan_out(recipients, bytes) {
+            match self
+                .network
+                .send(SendMessage {
+                    epid,
+                    bytes,
+                    responder: None,
+                })
+                .await
+            {
+                Ok(_) => {
+                    // TODO metrics
+                }
+                Err(e) => {
+                    warn!(
+                        "[BroadcasterWorker ] Broadcasting transaction to {:?} failed: {:?}.",
+                        epid, e
+                    );
+                }
+            };
         }
     }
 
@@ -94,3 +109,45 @@ impl BroadcasterWorker {
         info!("[BroadcasterWorker ] Stopped.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::{cell::Cell, rc::Rc};
+
+    struct Counted(Rc<Cell<usize>>);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            Self(self.0.clone())
+        }
+    }
+
+    fn clones(recipients: usize) -> usize {
+        let count = Rc::new(Cell::new(0));
+        let received = fan_out((0..recipients).collect(), Counted(count.clone())).count();
+
+        assert_eq!(received, recipients);
+        count.get()
+    }
+
+    #[test]
+    fn fan_out_clones_once_per_additional_recipient() {
+        // Cloning for every recipient used to cost `recipients` clones.
+        assert_eq!(clones(0), 0);
+        assert_eq!(clones(1), 0);
+        assert_eq!(clones(2), 1);
+        assert_eq!(clones(8), 7);
+    }
+
+    #[test]
+    fn fan_out_pairs_each_recipient_with_the_item() {
+        let bytes = vec![1u8; 1604];
+        let sent: Vec<(u16, Vec<u8>)> = fan_out(vec![1, 2, 3], bytes.clone()).collect();
+
+        assert_eq!(sent, vec![(1, bytes.clone()), (2, bytes.clone()), (3, bytes)]);
+    }
+}
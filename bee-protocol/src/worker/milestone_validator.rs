@@ -11,7 +11,7 @@
 
 use crate::{
     milestone::{Milestone, MilestoneBuilder, MilestoneBuilderError},
-    protocol::Protocol,
+    protocol::{Protocol, ProtocolMetrics},
 };
 
 use bee_bundle::Hash;
@@ -39,6 +39,18 @@ pub(crate) enum MilestoneValidatorWorkerError {
 
 pub(crate) type MilestoneValidatorWorkerEvent = Hash;
 
+// Increments the counter of `metrics` matching the outcome of validating a milestone. Incomplete bundles are counted
+// apart from invalid ones since they are expected while syncing.
+fn record_validation(metrics: &ProtocolMetrics, result: &Result<Milestone, MilestoneValidatorWorkerError>) {
+    match result {
+        Ok(_) => metrics.valid_milestones_inc(),
+        Err(MilestoneValidatorWorkerError::UnknownTail) => metrics.unknown_tail_milestones_inc(),
+        Err(MilestoneValidatorWorkerError::NotATail) => metrics.not_a_tail_milestones_inc(),
+        Err(MilestoneValidatorWorkerError::IncompleteBundle) => metrics.incomplete_milestones_inc(),
+        Err(MilestoneValidatorWorkerError::InvalidMilestone(_)) => metrics.invalid_milestones_inc(),
+    };
+}
+
 pub(crate) struct MilestoneValidatorWorker<M, P> {
     mss_sponge: PhantomData<M>,
     public_key: PhantomData<P>,
@@ -102,15 +114,19 @@ where
                 tail_hash = receiver_fused.next() => {
                     if let Some(tail_hash) = tail_hash {
                         // TODO split
-                        match self.validate_milestone(tail_hash).await {
+                        let result = self.validate_milestone(tail_hash).await;
+                        record_validation(&Protocol::get().metrics, &result);
+                        match result {
                             Ok(milestone) => {
                                 // TODO check multiple triggers
                                 tangle().add_milestone(milestone.index.into(), milestone.hash);
+                                Protocol::get().requested_milestones.remove(&milestone.index);
                                 // TODO deref ? Why not .into() ?
                                 if milestone.index > *tangle().get_last_milestone_index() {
                                     info!("[MilestoneValidatorWorker ] New milestone #{}.", milestone.index);
                                     tangle().update_last_milestone_index(milestone.index.into());
                                 }
+                                // NOTE: the milestone solidifier confirms the cone once it's solid, in index order.
                                 // TODO only trigger if index == last solid index ?
                                 // TODO trigger only if requester is empty ? And unsynced ?
                                 // Protocol::trigger_transaction_solidification(milestone.hash).await;
@@ -135,4 +151,124 @@ where
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+
+    use super::*;
+
+    use crate::util::init_protocol;
+
+    use bee_bundle::{Transaction, TransactionField};
+    use bee_crypto::IdentitySponge;
+    use bee_signing::WotsPublicKey;
+    use bee_ternary::{T1B1Buf, TryteBuf};
+    use bee_test::transaction::create_random_bundle;
+
+    use async_std::task::{block_on, sleep, spawn};
+    use futures::sink::SinkExt;
+    use serial_test::serial;
+
+    use std::time::Duration;
+
+    #[test]
+    #[serial]
+    fn validated_milestone_is_added() {
+        const MS_HASH: &str = "AVVTI9AB9HFVGKGDQCXHJQEJDOYHZRFDRAMABMPXUUB9XSMRTENHFMOPQDZRQQUAZHIVYRXBYWJK99999";
+        const MS_TX_0_TRYTES: &str = "JLXZXQOGYIASGXHPPTXQGFKPVXKAZKCRHRSY9AXHPCKRPCDYUACNLVAMUMVEGSUYCSFMNEYZKCZHLNSPZWQEEDYVEUWDHEHXCHQAA9YOEZKETHZTMXDOFAIXXLXXZHJDS9VRHOATUCBBSREMXBBGBMYJYBFJRU9CWZF9VX9LIXMPRILZXZHFJJQRDGXEWGECKD9FOEAELQADYLZAGIDHJVJPQUHZEAVPAZLQJNIWCAFTPMDCKPWNZXVXWQLFLHJSPWCBWQWKAPKOQYFIFIIZHDDEQVPVYIUHBWWT9HAITJXNQUSWCEMTMABQLE9WLCLSXOUZKLVRJM9UAGYVMTYBIOIWDBEOTAV9FGHFRRKRECBQEUGEJVONMHQSDWLRUCCYCRUZWBVMMIIAOTZUTQLNAHJOZDZAICZTKYNYFXRLUFGH9CLSWDF9HIKK9UKFE9USTCYEWNIASWIHEGJKXUEGNFBZGNXTWEZICQRLW9TFIXJIFHHMRPLVIHAOZTZISZTRQJDAINKUCISDFJBKBRCVEJITIBQGMCLWEZVUSGKIHO9HIVDKDEHVNWAXOQNXUDCXESDBELFFNAOHSWPJSUEGLSYURUCJOWQPIOVYRMKHN9FVRAGWGZPZAKDFYLXICBXDLHGLJLLCQUVIAJZZTXNFGHKACZNGPPTEAFMMUAYDSOXVIZFCVGQHVNCRMSWGCLHKBEJDZQFBWSYPSNPEETARJLRD9NGPRTEWTFNFNJNTK9KSUIOMXWZFLOTIMWMYUBRGLYGXAQIFOZ9EEATXNCFRST9CLNZVQQEKMNIFBYZSYXWYWEFVRJQUFATHAICNPXYIRJQUIDEOLFXVSIETMIZLSFLKTZBPZVNSMFVLJYEARBICCHPXEHMES9DM9BYVWFSNWCPPIHNUYFVPOBKBDAYNZUZAUUYKVYQDGL9TGIXKCJQPRPWQQJGNTCAIFWVJZZLVJXTHDOWAHRRBANOAUTNCBIXHHVBZNZEOLZQYCVTGXL9CPOWQD9WEIWQNHPUCSGFFYWVOQGAETAHDNYDVXXTQMGFYPAXNCN9RU9XSHNXCHVYLF9ARIKLXUEYMZUAJZP9BVQHWB9YCZQFMUQRUJQS9LUNOFWMMCCSASGLTYOBDCHDIOBJBOONQHBLGVZIKGWUFLSOYARYTWZSSOXTOXJWURWGEHMSTDUWUGSSXUINDKJVG9FWBFPFBXYEGVHHAPVYQ9BHEWUWBGHDCOCBMI9SLNXPESCCKSOXQLWCYOLLZEQDJLQYYEZRNISRCTLEXHBXGCHSZSKGUYEEWV9HA9AXJSSXMRTPBDFKGVGNWJOGMBOZYNYUNQQLDGISIHKGQBFZBPQXBJBOFKDWABSZFFBWSNWKJBQSOOFZHZNLBIZVRJLAPRGXEHADIDCNEDVMYFJAYHBWTPYFKBRVP9JNZZESBWBNHQQFOEZSUVXJEOIIPVEEZISRSXBHDQAHRJ9X9EMNZLEUAPSUKWJAPPGEZUHFMUHPGVTLBAZPRA9AUFTCPFPQTUNPQEIODTYQIBW9GOIEKJHBNHJILNKBUZKZJHWAIDFVSNJSRXPYCYTZDBRUJEBTVBJJWTGLSKPI9YNQPNFKDPNI9MCNRV9SOJGRICKPYTOTJQZTIHKCIQWQMXZTKOVOMNXPEWPNVUYDXAYXX9ZHEVLCYIRHETNUSKLBDKUWISIKSFERPHXXSF9KFDNKQVUFGPGHMBMIHFZX9ZKRPYULRFDJUSIODHWXZJUMZDQCZB9TJ9PISMZRUNZZREFKTUSYMZRVQFBGVWXBOTMBTVXJMPFRGLNPGRSEJIFEQFSERMCUFCEFFOOMASJWROYWAWTQBBVGCDXTVEHLVYHGGBAIQCYQSCVDGAML9DMAEYOSVFNALCI9KHWCCMDTOANJLZFDIWDCWTQBSCLXCNMYKVQCYTSVMKQYGNLIF9DTPDWG9PYVYWALXQK9PUQOITCDWCYNTKNASGRJUSZNHF9FNYBKWAWLTDMBEDVOQCYSTDM9RIWRKCDTUJNCLKEZPDSMJYKOMHY9MYCSMQBHYGLHDHPKJZHGOQSIKEJILZFYFI9IYIYZ9QCDGC9PLJGRLYVWV9FDMKQNNWANMEUCWIX9OSOX9EDMCDJUQHJKKEYJXRWADQILCIYAOCEVSUAXAZOXHLGWCWO9DBQADSMHOJBN9RQGMDWUCLSBFTOWEQSAUZXULTTYZCLNJNTXQTQHOMOFZERHTCGTXOLTVAHKSA9OGAZDEKECURBRIXIJWNPFCQIOVFVVXJVD9999999999999999999999999999KNHOC9999999999999999999999AYUQNCD99999999999B99999999ZNTINGHMSWDSSERUUKBHLVWHQZAP9XYRCBEINKT9PSXSXKFHNWKCJUOYCMWMMVJKCG9XFZBOWGZSNUKAA9DWAZGXZGOLPXMAWXL9KOXRKWUWDYCJNYDDUCPXHZIFTFMRMZODYUHSNDFVTDHAGDBHBHECZJFQU99999CTAQFROZJTBEOKBN99WRDGDZPLVNXMSFFYVSVAFTQDJVRWFHVEWOKSJOQESMRBFTVDNIXDZRKSACZ9999KNHOC9999999999999999999999SJLHMTPQF999999999K99999999WBD9999999RSL99999999999999";
+        const MS_TX_1_TRYTES: &str = "DFTZFZXAXTVBQMPZONBZDOD9WBBBWQS9PQLTBWEAXFPKYWNMS9JAGOJTRQNKHMGERQVGOFKCQUZFFXGADPYQQUIRDSEREWLFFVMDFEVCCYUCKULRQZBMOALWUBAASAGJPVJVSXXHTLIEQVZVXIANEMIMV9FAESZKFCBPYVEALZGYAYADOJLMZJFR9WUGZDWKZUNZMQDY9MTTPGMPLNB9LKEBPMKVYKNJTB9YBHEOETJDEYYRIWAXHEDLCVIZJKB9HLGCNJXXUWCECTHDYNR9EJXRWFQCAFYRAHGFSSGYGIQYIRYFSZITCQNMLGUDXGSWOFKXLYYRPXB9S9WPDCRHBLXALJAJKORGTVCSCNRAMBTCSNLXRVDDTAQLXKLUOL9HH99DDFHNJYCOYZHRDYYHZMXFRKPHGBLWRPPFQWBNQCLVWFORWZPROYZHCRY99ZVFOEB9JYXDHLCBXFEIGBRVZPZYHDHU9FPDQZSXVCZTQUKBK9TUPGGQBAOIUVAXAUEIEGMMNEDJAESRRPHYKXG99DYNOLQFVWLJINMTGGYGQO9TYZXFTKSZBAXFVJODOETNARWKCMRZTGYGYBLKSFHWPHJXCXD9FPBJUJYJSCNLOIXAGJB9OWTLDBWWCYAJTALKDSHYDGHWNBUIAYVBAIG9BTPMKB9BGHHTVNEKXKOSRRJTZNCEOTDIFONNBMRYKNMOSWKPOIERXJPMCTOCMBTQRHXUZMPPOIKRCI9VGCWXQCWKSWIKB9NAQJNZREPQWXOCVMZMGFSORUUDMEJMESYNLNLRNRRNPNZKS9IWQVHKJDPETTGEIDAXLMTEEMJIONWLZILGWMGQWQWJLJJVHHI9PHQGPTHBOWY9DGZJJIPRRLBWPDSFTRLRQLNC9WX9L9YWSHLUUJKVDMPNRLXPPQFSTKRRVGCAGB9SYAHQLPXZRJRDRJTMXRDJ9ODBTFGXNHYKSKURKMQWVIXZYFIEJAYXSAOTNJUSHAIORJBVPEMHEIEYACFPGWKYRBTTOMGFQFNBCXNVJYUUBDSTXKHMUSRJGWFAHWSUWSBQRVCGANNYCLWFONQGOQWKRCI999IRV9WIWXFK9UIEGKCOKARP9OTCDDVNDEJPQS9IDEYUPTURWVALCCQBEFOV9WXGWRLAHTRNEKIXSPTOHLRADBUAKTNHEOBJEEJDRMCNSWNDISQETGPDTYGNBKRRXRGBJCGSKZZKDLJYZFZEVNHTUU9DNNQTFUELEDZGJT9RDMIKUTRXYDLNBDDMZWHJWKCSVQZLKAWMZYII9QNLGDVOZ9FZFACEYDZB9JNSKAJAWNKUEMXZVUURZDJFBLCHPWEKFTYIYGDNZHWFJONBNQHLAAVAIFEZZMJPYSWMHZKRWHVDVGVKMEJMPWYPB9AFNPKKFLYIFETHXHSRFR9GWMTGSMVZBWMQDOUTZAYEAUHRIXPBCXLYBMFLVLMXND9THGBZJPJANUABRSQHRJOOQOEJAOALRDEMOBMMRTCRXJFXGHVLHJUZGXFE9XGVOKPVCTVZZACMRCCBONDGHFSURRASAJFSZBGQGYTWXSRNSHMBWEUNRXCXCO9ZVJLGWKKABUXKISVKGCIZUOXTTBECUZHVPOETUEIZAVZF99IUOQKEF9QTI9LLBJSYUGINUG9OJWPLCDUFURHFCVMSDEXVQLQFRGCZADSVAQJTVOQYBTYTKZAI9L9ZHIWQHMRNDAJSVXRBPWWMUKWWPOADAFKL9CMUFJXLHTYNWTLOXODDMVOLNCJBEXCWMSSHDKSTXM9EMXSAXKXWSTBCCSSMX9MUPBAYPLOOMAY9YREDWJYKKHZDQXLZQVPF9EAYESD9RBGOPARKYGVBFGGMNTQYPHWKZJVRDRSWIT9VFAB9FWAPXMYAEKEM9EVDT9YCXSSJTEWEAZPXIRWIGMJY9QSXRNANFQDLHNZQXCVADJJDMAUJCLUGUDUBOTYMUIRRCGCHUOPRLCJUDYXXFSIKJCMTWCUOHFMQCF99ACQYJPREY9MOEGMGDJPCGYASCIBATAKJIZCOIRUDZIYKVYWKMWSSCBT9TCBCGYZPKLVHCXOSSBHJSPDRYJTEAMFXHTBEJLYCHXJVUQIACWREQXUBUI9EDEYKGJADCJDLPAGMO9GWQOGBUSBSPTWFIFHOKMKWJKHZVX9PGKOLBTLIZV9LMFKDUHENUQDEQSAUZXULTTYZCLNJNTXQTQHOMOFZERHTCGTXOLTVAHKSA9OGAZDEKECURBRIXIJWNPFCQIOVFVVXJVD9999999999999999999999999999KNHOC9999999999999999999999AYUQNCD99A99999999B99999999ZNTINGHMSWDSSERUUKBHLVWHQZAP9XYRCBEINKT9PSXSXKFHNWKCJUOYCMWMMVJKCG9XFZBOWGZSNUKAAJMTCM9HFZHIIWJCP9TRBIVLHVTLBWESJUNXYS9FWJTEAYXPWHWIEHFOJ9Q9ZBADGTSIRCUSFTLPK99999CTAQFROZJTBEOKBN99WRDGDZPLVNXMSFFYVSVAFTQDJVRWFHVEWOKSJOQESMRBFTVDNIXDZRKSACZ9999KNHOC9999999999999999999999HWLHMTPQF999999999K99999999KFC9999999XZG99999999999999";
+        const MS_TX_2_TRYTES: &str = "UFI9GAMLPDRND9DCGEXVMDYHEGRJTUZJLLCXRNZJJCZKOBTOQSZVJMIAMTSDSBRTUMAWYJVSRSWAIRHQWABSUS9ZZMMNBUGQWMYHFUSJQDVUNBLJILFZKZJNEZDTOEGXONHEUUKAW9QZUCTBVOEEFCROLJYFWKILODMGGUIOXBXLWLFDILWCQQKNBXCALYQUSIVFMNWWZNSBHRPNAXJIZDASJHVICHVMADPLRDAMMGPUKCGARSBAIFKBXYURFCRBBVXOCJQKRQQAAWCBLGAMHLKHELXEBWCCQLBRJRDKUYIWGMWEJ9SKSXGICORHSJQTFRCDMDALNFRFNJCGQOOJLQYHYUGCMKOGNIUNRUMKYZAWPZTOKERKPMQILYLOPIINAM9SRWXINAWNMQEMHDFIDFNYZRL9BCUAPMHFQXIPNWJNTXYXTUNNFCA9HZOLCXOPFPLDLLXRXSMKJHYLBGCEYI9NLN9DLCPRLFILJCJFOEXPEDPYAMWMWYZSRKULCHWUCAZCSILSPLCJGISFOCAGJZSZALOCDNCXOANM9BNBOXUVRXCJRSJWRCX9SW9GFXLPTHHJ9HYYTUMQMBHSHOMBASYQOLHSVKUNZWUNVGTACKXNBASLEKNXITODWICCVUNWEFHMMMQYTL9CVBQGSWWXBYPHO9DGDSMYYSBZQPOJXBE9SKFALGPMZQAEQHDKHZMUMVLSSCIXOCXZH9XFMCFTGQKODJNAUBQDSMHISXAMKTYGWPRFKKHSCDGWCJEFDBKTTKVXTYGVUJAOHRINHGUDLFIKMZBHFEHBGBUWBHZCRYXALHBKQFVWGDBSAQUWWMOTKYKBWVZYLLLLGQDTMZGYPWQLIUHCFKZOIPHOGSKF9HSCQIBWFYHIMFTGDUCCXWRJWJNDJDJHSOWYMUIPEKOVQOEVBX9ZZTGBUIALRXDQGSASEMI9NCZK9VPVCYNGJSSPUIBAVHIUBVWZETHWDNGLVSCUXFWZYFIXLW9ODYHGGQCPJVNBJUNMV99SUQSEENLH9LCH9EXGEFPFBYHBCUDANSGHVIHRWBFXLJOYYFNQQXGSBQCATYEPMFLJSPUGXXNLXWHLA9PPKDODBJBELVTCBSBPCMYHTJNVAHMVFQAWEQQGUYPTCD9ZXXSI9MZUYLUJXQ9GFNLIQUDMAIQPBPZTRZKAERMZZKKJZZUMVMBXAGMPSESKRWUIMMMCN9CLAOCKQFOGNMACWYLEIRNKUAB9IIWUIKYHPGTRITMXFNJWDJDFWKCRRWZJSUYJDCEEJHVEJZWFPQLNTK9SIINXNFOI9DJEGMRRNFUZMSGKZZWXPRY9PQPQPLNRBDX99JBNZZGPYDNRDHEYKMZVYUIYVLKQHGYHFEFC9LMKYJWQQQZVYVMFQDEKYPE9RETZN9BMXFBGVPDKNSWSCNFHVPPBVQKWMKCQEOWGURJIKCXFDSSJDLGQHQ9WQ9KUNBAAQAUPBGLGGBIKANVDLGHFHKJQEOWKXV9TZQEXZZZUFOAC9ZOVPKTQZZASSWCTSLGQCPPBATPLMRXBLBCNXZTGFFNCMAUIZDRADNH9VGTP9LXNTSKCULPS9AYEMCWXFCLGHXHIGORKZGMEXLD9DOAZWLHXGYUWQAHOEGDSHTYUSGLI9OGFWIQTIAWAIA9TNVOZ9REWUPCZJEXAS9IDSYOJ9VDTAIOXHLEIOKNXUPNZ9DVOXRZXYOBKWARQLVCWIIDTUAFOYRYCIKAQCRSIXDDJYRKUD9RAVIUWJHXQYVCENPAIS9SACTKEOANWDJEJRDSFPIPDMSWFHUZ9VXIDGULVZOCOUDZEHFWPTUJDES9KGFHXTYZMQMEMZ9KQVD9JDHPOJKICKYSTGGQPDV9999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999AYUQNCD99B99999999B99999999ZNTINGHMSWDSSERUUKBHLVWHQZAP9XYRCBEINKT9PSXSXKFHNWKCJUOYCMWMMVJKCG9XFZBOWGZSNUKAACTAQFROZJTBEOKBN99WRDGDZPLVNXMSFFYVSVAFTQDJVRWFHVEWOKSJOQESMRBFTVDNIXDZRKSACZ9999BOOGETCQNX9VOSRCZEYUNSOFCJZNCYMNQMBHNLLTIWGPOSAEPNWVODBCHSDDDBUYNQUHDPUYUUWGZ9999999999999999999999999999999UVKHMTPQF999999999K99999999ITGA999999IVWE9999999999999";
+
+        bee_tangle::init();
+
+        init_protocol();
+
+        let tail_hash =
+            Hash::try_from_inner(TryteBuf::try_from_str(MS_HASH).unwrap().as_trits().encode::<T1B1Buf>()).unwrap();
+
+        block_on(async {
+            let mut hash = tail_hash;
+            for trytes in &[MS_TX_0_TRYTES, MS_TX_1_TRYTES, MS_TX_2_TRYTES] {
+                let trits = TryteBuf::try_from_str(trytes).unwrap().as_trits().encode::<T1B1Buf>();
+                let transaction = Transaction::from_trits(&trits).unwrap();
+                let trunk = *transaction.trunk();
+                tangle().insert_transaction(transaction, hash).await;
+                hash = trunk;
+            }
+        });
+
+        let (mut validator_sender, validator_receiver) = mpsc::channel(1);
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+
+        let worker = spawn(
+            MilestoneValidatorWorker::<Kerl, WotsPublicKey<Kerl>>::new().run(validator_receiver, shutdown_receiver),
+        );
+
+        block_on(async {
+            validator_sender.send(tail_hash).await.unwrap();
+
+            while !tangle().contains_milestone(1_363_619.into()) {
+                sleep(Duration::from_millis(10)).await;
+            }
+
+            shutdown_sender.send(()).unwrap();
+            worker.await;
+        });
+
+        assert_eq!(tangle().get_milestone_hash(1_363_619.into()), Some(tail_hash));
+        assert_eq!(*tangle().get_last_milestone_index(), 1_363_619);
+        // The cone isn't confirmed before the milestone is solid.
+        assert!(!tangle().is_referenced(&tail_hash));
+
+        bee_tangle::drop();
+    }
+
+    // Rejected before any hashing, so a cheap sponge is enough to validate with.
+    type TestValidator = MilestoneValidatorWorker<IdentitySponge, WotsPublicKey<IdentitySponge>>;
+
+    #[test]
+    #[serial]
+    fn unknown_tail_is_recorded() {
+        bee_tangle::init();
+
+        let metrics = ProtocolMetrics::new();
+        let result = block_on(TestValidator::new().validate_milestone(Hash::zeros()));
+        assert!(matches!(result, Err(MilestoneValidatorWorkerError::UnknownTail)));
+
+        record_validation(&metrics, &result);
+
+        assert_eq!(metrics.unknown_tail_milestones(), 1);
+        assert_eq!(metrics.not_a_tail_milestones(), 0);
+        assert_eq!(metrics.incomplete_milestones(), 0);
+        assert_eq!(metrics.invalid_milestones(), 0);
+        assert_eq!(metrics.valid_milestones(), 0);
+
+        bee_tangle::drop();
+    }
+
+    #[test]
+    #[serial]
+    fn not_a_tail_is_recorded() {
+        bee_tangle::init();
+
+        let bundle = create_random_bundle(2);
+        let (hash, _) = bundle[1];
+        block_on(async {
+            for (hash, transaction) in bundle.into_iter() {
+                tangle().insert_transaction(transaction, hash).await;
+            }
+        });
+
+        let metrics = ProtocolMetrics::new();
+        let result = block_on(TestValidator::new().validate_milestone(hash));
+        assert!(matches!(result, Err(MilestoneValidatorWorkerError::NotATail)));
+
+        record_validation(&metrics, &result);
+
+        assert_eq!(metrics.not_a_tail_milestones(), 1);
+        assert_eq!(metrics.unknown_tail_milestones(), 0);
+        assert_eq!(metrics.incomplete_milestones(), 0);
+        assert_eq!(metrics.invalid_milestones(), 0);
+        assert_eq!(metrics.valid_milestones(), 0);
+
+        bee_tangle::drop();
+    }
+}
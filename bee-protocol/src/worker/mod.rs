@@ -12,6 +12,7 @@
 mod broadcaster;
 mod milestone_validator;
 mod peer;
+mod pruner;
 mod requester;
 mod responder;
 mod sender;
@@ -22,9 +23,10 @@ mod transaction;
 pub(crate) use broadcaster::{BroadcasterWorker, BroadcasterWorkerEvent};
 pub(crate) use milestone_validator::{MilestoneValidatorWorker, MilestoneValidatorWorkerEvent};
 pub(crate) use peer::{PeerHandshakerWorker, PeerWorker};
+pub(crate) use pruner::{PrunerWorker, PrunerWorkerEvent};
 pub(crate) use requester::{
-    MilestoneRequesterWorker, MilestoneRequesterWorkerEntry, TransactionRequesterWorker,
-    TransactionRequesterWorkerEntry,
+    assign_round_robin, is_awaited, outstanding_requests, MilestoneRequesterWorker, MilestoneRequesterWorkerEntry,
+    TransactionRequesterWorker, TransactionRequesterWorkerEntry,
 };
 pub(crate) use responder::{
     MilestoneResponderWorker, MilestoneResponderWorkerEvent, TransactionResponderWorker,
@@ -36,4 +38,4 @@ pub(crate) use solidifier::{
     TransactionSolidifierWorkerEvent,
 };
 pub(crate) use status::StatusWorker;
-pub(crate) use transaction::{TransactionWorker, TransactionWorkerEvent};
+pub(crate) use transaction::{CacheEviction, TransactionWorker, TransactionWorkerEvent};
@@ -11,9 +11,7 @@
 
 use crate::{
     config::slice_eq,
-    message::{
-        messages_supported_version, tlv_from_bytes, tlv_into_bytes, Handshake, Header, Message, MESSAGES_VERSIONS,
-    },
+    message::{messages_supported_version, tlv_into_bytes, tlv_read_frame, Handshake, TlvError, MESSAGES_VERSIONS},
     peer::Peer,
     protocol::Protocol,
     worker::PeerWorker,
@@ -28,17 +26,20 @@ use bee_tangle::tangle;
 
 use std::{
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use async_std::{net::SocketAddr, task::spawn};
+use async_std::{
+    net::SocketAddr,
+    task::{sleep, spawn},
+};
 use futures::{
     channel::{mpsc, oneshot},
     future::FutureExt,
     select,
     stream::StreamExt,
 };
-use log::{debug, error, info, warn};
+use log::{debug, info, warn};
 
 #[derive(Debug)]
 pub(crate) enum HandshakeError {
@@ -51,37 +52,28 @@ pub(crate) enum HandshakeError {
     AlreadyHandshaked,
 }
 
-#[derive(Debug)]
-pub(crate) enum PeerHandshakerWorkerError {}
-
-enum PeerReadState {
-    Header,
-    Payload(Header),
-}
-
-struct PeerReadContext {
-    state: PeerReadState,
-    buffer: Vec<u8>,
-}
-
 enum HandshakeStatus {
     Awaiting,
     Done,
     Duplicate,
+    Incompatible,
+    TimedOut,
 }
 
 pub struct PeerHandshakerWorker {
     network: Network,
     peer: Arc<Peer>,
     status: HandshakeStatus,
+    timeout: Duration,
 }
 
 impl PeerHandshakerWorker {
-    pub(crate) fn new(network: Network, peer: Arc<Peer>) -> Self {
+    pub(crate) fn new(network: Network, peer: Arc<Peer>, timeout: Duration) -> Self {
         Self {
             network,
             peer,
             status: HandshakeStatus::Awaiting,
+            timeout,
         }
     }
 
@@ -90,12 +82,10 @@ impl PeerHandshakerWorker {
 
         // TODO should we have a first check if already connected ?
 
-        let mut context = PeerReadContext {
-            state: PeerReadState::Header,
-            buffer: Vec::new(),
-        };
+        let mut buffer = Vec::new();
         let mut receiver_fused = receiver.fuse();
         let mut shutdown_fused = shutdown.fuse();
+        let mut timeout_fused = Box::pin(sleep(self.timeout)).fuse();
 
         // This is the only message not using a SenderWorker because they are not running yet (awaiting handshake)
         if let Err(e) = self
@@ -124,13 +114,17 @@ impl PeerHandshakerWorker {
             select! {
                 event = receiver_fused.next() => {
                     if let Some(event) = event {
-                        context = self.message_handler(context, event).await;
+                        buffer = self.message_handler(buffer, event).await;
                         match self.status {
-                            HandshakeStatus::Done | HandshakeStatus::Duplicate => break,
+                            HandshakeStatus::Done | HandshakeStatus::Duplicate | HandshakeStatus::Incompatible => break,
                             _ => continue
                         }
                     }
                 },
+                _ = timeout_fused => {
+                    self.status = HandshakeStatus::TimedOut;
+                    break;
+                },
                 _ = shutdown_fused => {
                     break;
                 }
@@ -152,24 +146,25 @@ impl PeerHandshakerWorker {
                     .run(receiver_fused, shutdown_fused),
                 );
             }
-            HandshakeStatus::Duplicate => {
+            HandshakeStatus::Duplicate | HandshakeStatus::Incompatible => {
                 info!(
-                    "[PeerHandshakerWorker({})] Closing duplicate connection.",
-                    self.peer.epid
+                    "[PeerHandshakerWorker({})] Closing {} connection.",
+                    self.peer.epid,
+                    if let HandshakeStatus::Duplicate = self.status {
+                        "duplicate"
+                    } else {
+                        "incompatible"
+                    }
                 );
-                if let Err(e) = self
-                    .network
-                    .send(Disconnect {
-                        epid: self.peer.epid,
-                        responder: None,
-                    })
-                    .await
-                {
-                    warn!(
-                        "[PeerHandshakerWorker({})] Disconnecting peer failed: {}.",
-                        self.peer.epid, e
-                    );
-                }
+                self.disconnect().await;
+            }
+            HandshakeStatus::TimedOut => {
+                warn!(
+                    "[PeerHandshakerWorker({})] No handshake received within {:?}, closing connection.",
+                    self.peer.epid, self.timeout
+                );
+                self.disconnect().await;
+                Protocol::notify_handshake_timeout(self.peer.epid);
             }
             _ => (),
         }
@@ -177,7 +172,23 @@ impl PeerHandshakerWorker {
         info!("[PeerHandshakerWorker({})] Stopped.", self.peer.address);
     }
 
-    pub(crate) fn validate_handshake(&mut self, handshake: Handshake) -> Result<Address, HandshakeError> {
+    async fn disconnect(&mut self) {
+        if let Err(e) = self
+            .network
+            .send(Disconnect {
+                epid: self.peer.epid,
+                responder: None,
+            })
+            .await
+        {
+            warn!(
+                "[PeerHandshakerWorker({})] Disconnecting peer failed: {}.",
+                self.peer.epid, e
+            );
+        }
+    }
+
+    pub(crate) fn validate_handshake(&mut self, handshake: Handshake) -> Result<(Address, u8), HandshakeError> {
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Clock may have gone backwards")
@@ -203,9 +214,13 @@ impl PeerHandshakerWorker {
             ));
         }
 
-        if let Err(version) = messages_supported_version(&handshake.supported_versions) {
-            return Err(HandshakeError::UnsupportedVersion(version));
-        }
+        let version = match messages_supported_version(&handshake.supported_versions) {
+            Ok(version) => version,
+            Err(version) => {
+                self.status = HandshakeStatus::Incompatible;
+                return Err(HandshakeError::UnsupportedVersion(version));
+            }
+        };
 
         let address = match self.peer.origin {
             Origin::Outbound => {
@@ -230,117 +245,160 @@ impl PeerHandshakerWorker {
             }
         }
 
-        Ok(address)
+        Ok((address, version))
     }
 
-    async fn process_message(&mut self, header: &Header, bytes: &[u8]) -> Result<(), PeerHandshakerWorkerError> {
-        if let Handshake::ID = header.message_type {
-            debug!("[PeerHandshakerWorker({})] Reading Handshake...", self.peer.address);
-            match tlv_from_bytes::<Handshake>(&header, bytes) {
-                Ok(handshake) => match self.validate_handshake(handshake) {
-                    Ok(address) => {
-                        info!("[PeerHandshakerWorker({})] Handshake completed.", self.peer.address);
-
-                        Protocol::get().peer_manager.handshake(&self.peer.epid, address);
+    async fn process_handshake(&mut self, handshake: Result<Handshake, TlvError>) {
+        match handshake {
+            Ok(handshake) => match self.validate_handshake(handshake) {
+                Ok((address, version)) => {
+                    info!(
+                        "[PeerHandshakerWorker({})] Handshake completed with version {}.",
+                        self.peer.address, version
+                    );
 
-                        Protocol::send_heartbeat(
-                            self.peer.epid,
-                            *tangle().get_solid_milestone_index(),
-                            *tangle().get_snapshot_milestone_index(),
-                        )
-                        .await;
+                    Protocol::get()
+                        .peer_manager
+                        .handshake(&self.peer.epid, address, version);
 
-                        Protocol::request_last_milestone(Some(self.peer.epid));
-                        Protocol::trigger_milestone_solidification().await;
+                    Protocol::send_heartbeat(
+                        self.peer.epid,
+                        *tangle().get_solid_milestone_index(),
+                        *tangle().get_snapshot_milestone_index(),
+                    )
+                    .await;
 
-                        self.status = HandshakeStatus::Done;
-                    }
-                    Err(e) => {
+                    if !Protocol::request_last_milestone(Some(self.peer.epid)) {
                         warn!(
-                            "[PeerHandshakerWorker({})] Handshaking failed: {:?}.",
-                            self.peer.address, e
+                            "[PeerHandshakerWorker({})] Requesting the last milestone failed: too many pending requests.",
+                            self.peer.address
                         );
                     }
-                },
+                    Protocol::trigger_milestone_solidification().await;
+
+                    self.status = HandshakeStatus::Done;
+                }
                 Err(e) => {
                     warn!(
-                        "[PeerHandshakerWorker({})] Reading Handshake failed: {:?}.",
+                        "[PeerHandshakerWorker({})] Handshaking failed: {:?}.",
                         self.peer.address, e
                     );
-
-                    Protocol::get().metrics.invalid_messages_received_inc();
                 }
+            },
+            Err(TlvError::InvalidAdvertisedType(..)) => {
+                warn!(
+                    "[PeerHandshakerWorker({})] Ignoring messages until fully handshaked.",
+                    self.peer.address
+                );
+
+                Protocol::get().metrics.invalid_messages_received_inc();
             }
-        } else {
-            warn!(
-                "[PeerHandshakerWorker({})] Ignoring messages until fully handshaked.",
-                self.peer.address
-            );
+            Err(e) => {
+                warn!(
+                    "[PeerHandshakerWorker({})] Reading Handshake failed: {:?}.",
+                    self.peer.address, e
+                );
 
-            Protocol::get().metrics.invalid_messages_received_inc();
+                Protocol::get().metrics.invalid_messages_received_inc();
+            }
         }
-
-        Ok(())
     }
 
-    async fn message_handler(&mut self, mut context: PeerReadContext, mut bytes: Vec<u8>) -> PeerReadContext {
+    async fn message_handler(&mut self, mut buffer: Vec<u8>, mut bytes: Vec<u8>) -> Vec<u8> {
         let mut offset = 0;
-        let mut remaining = true;
 
-        if context.buffer.is_empty() {
-            context.buffer = bytes;
-        } else {
-            context.buffer.append(&mut bytes);
+        buffer.append(&mut bytes);
+
+        while let Some((handshake, length)) = tlv_read_frame::<Handshake>(&buffer[offset..]) {
+            debug!("[PeerHandshakerWorker({})] Reading Handshake...", self.peer.address);
+            offset += length;
+            self.process_handshake(handshake).await;
         }
 
-        while remaining {
-            context.state = match context.state {
-                PeerReadState::Header => {
-                    if offset + 3 <= context.buffer.len() {
-                        debug!("[PeerHandshakerWorker({})] Reading Header...", self.peer.address);
-                        let header = Header::from_bytes(&context.buffer[offset..offset + 3]);
-                        offset += 3;
+        buffer.split_off(offset)
+    }
+}
 
-                        PeerReadState::Payload(header)
-                    } else {
-                        remaining = false;
+#[cfg(test)]
+mod tests {
 
-                        PeerReadState::Header
-                    }
-                }
-                PeerReadState::Payload(header) => {
-                    if (offset + header.message_length as usize) <= context.buffer.len() {
-                        if let Err(e) = self
-                            .process_message(
-                                &header,
-                                &context.buffer[offset..offset + header.message_length as usize],
-                            )
-                            .await
-                        {
-                            error!(
-                                "[PeerHandshakerWorker({})] Processing message failed: {:?}.",
-                                self.peer.address, e
-                            );
-                        }
+    use super::*;
 
-                        offset += header.message_length as usize;
+    use crate::util::init_protocol;
 
-                        PeerReadState::Header
-                    } else {
-                        remaining = false;
+    use bee_network::{Command, EndpointId, Url};
 
-                        PeerReadState::Payload(header)
-                    }
-                }
-            };
-        }
+    use async_std::{future::timeout, task::block_on};
 
-        PeerReadContext {
-            state: context.state,
-            buffer: context.buffer[offset..].to_vec(),
-        }
+    #[test]
+    fn disconnect_after_handshake_timeout() {
+        init_protocol();
+
+        let url = block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap();
+        let epid: EndpointId = url.into();
+        let address = block_on(Address::from_addr_str("[::1]:16000")).unwrap();
+        let peer = Arc::new(Peer::new(epid, address, Origin::Inbound));
+
+        let (command_sender, mut command_receiver) = mpsc::channel(10);
+        let (_receiver_sender, receiver) = mpsc::channel(1);
+        let (_shutdown_sender, shutdown) = oneshot::channel();
+        let mut timeouts = Protocol::subscribe_handshake_timeouts();
+
+        block_on(
+            PeerHandshakerWorker::new(Network::new(command_sender), peer, Duration::from_millis(100))
+                .run(receiver, shutdown),
+        );
+
+        block_on(async {
+            match timeout(Duration::from_secs(1), command_receiver.next()).await {
+                Ok(Some(Command::SendMessage { .. })) => {}
+                _ => panic!("Expected the handshake to be sent."),
+            }
+            match timeout(Duration::from_secs(1), command_receiver.next()).await {
+                Ok(Some(Command::Disconnect { epid: disconnected, .. })) => assert_eq!(disconnected, epid),
+                _ => panic!("Expected the peer to be disconnected."),
+            }
+            assert_eq!(timeout(Duration::from_secs(1), timeouts.next()).await, Ok(Some(epid)));
+        });
     }
-}
 
-#[cfg(test)]
-mod tests {}
+    #[test]
+    fn disconnect_incompatible_peer() {
+        init_protocol();
+
+        let url = block_on(Url::from_url_str("tcp://[::1]:16001")).unwrap();
+        let epid: EndpointId = url.into();
+        let address = block_on(Address::from_addr_str("[::1]:16001")).unwrap();
+        let peer = Arc::new(Peer::new(epid, address, Origin::Inbound));
+
+        let (command_sender, mut command_receiver) = mpsc::channel(10);
+        let (mut receiver_sender, receiver) = mpsc::channel(1);
+        let (_shutdown_sender, shutdown) = oneshot::channel();
+
+        // Peer only supporting legacy gossip
+        receiver_sender
+            .try_send(tlv_into_bytes(Handshake::new(
+                16001,
+                &Protocol::get().config.coordinator.public_key_bytes,
+                Protocol::get().config.mwm,
+                &[0b0000_0001],
+            )))
+            .unwrap();
+
+        block_on(
+            PeerHandshakerWorker::new(Network::new(command_sender), peer, Duration::from_secs(10))
+                .run(receiver, shutdown),
+        );
+
+        block_on(async {
+            match timeout(Duration::from_secs(1), command_receiver.next()).await {
+                Ok(Some(Command::SendMessage { .. })) => {}
+                _ => panic!("Expected the handshake to be sent."),
+            }
+            match timeout(Duration::from_secs(1), command_receiver.next()).await {
+                Ok(Some(Command::Disconnect { epid: disconnected, .. })) => assert_eq!(disconnected, epid),
+                _ => panic!("Expected the peer to be disconnected."),
+            }
+        });
+    }
+}
@@ -10,14 +10,22 @@
 // See the License for the specific language governing permissions and limitations under the License.
 
 use crate::{
-    message::{tlv_from_bytes, Header, Heartbeat, Message, MilestoneRequest, TransactionBroadcast, TransactionRequest},
+    message::{
+        tlv_from_bytes, tlv_split_frame, Header, Heartbeat, LegacyGossip, Message, MilestoneRequest,
+        TransactionBroadcast, TransactionRequest, HEADER_SIZE,
+    },
     peer::HandshakedPeer,
     protocol::Protocol,
     worker::{MilestoneResponderWorkerEvent, TransactionResponderWorkerEvent, TransactionWorkerEvent},
 };
 
+use bee_bundle::{Hash, TransactionField};
+use bee_tangle::tangle;
+use bee_ternary::{Trits, T5B1};
+
 use std::sync::Arc;
 
+use bytemuck::cast_slice;
 use futures::{
     channel::{mpsc, oneshot},
     select,
@@ -31,14 +39,20 @@ pub(crate) enum PeerWorkerError {
     FailedSend,
 }
 
-enum PeerReadState {
-    Header,
-    Payload(Header),
-}
-
-struct PeerReadContext {
-    state: PeerReadState,
-    buffer: Vec<u8>,
+// Decodes the hash requested by the suffix of a legacy gossip message and returns it if the transaction has to be
+// requested, i.e. if it is not the null hash, meaning no request, and the transaction is not in the tangle.
+fn legacy_gossip_request(hash: &[u8]) -> Option<Hash> {
+    let hash = Hash::from_inner_unchecked(
+        Trits::<T5B1>::try_from_raw(cast_slice(hash), Hash::trit_len())
+            .ok()?
+            .to_buf(),
+    );
+
+    if hash.is_null() || tangle().is_solid_entry_point(&hash) || tangle().contains_transaction(&hash) {
+        None
+    } else {
+        Some(hash)
+    }
 }
 
 pub struct PeerWorker {
@@ -63,12 +77,12 @@ impl PeerWorker {
         mut receiver_fused: futures_util::stream::Fuse<mpsc::Receiver<Vec<u8>>>,
         mut shutdown_fused: futures_util::future::Fuse<oneshot::Receiver<()>>,
     ) {
-        info!("[PeerWorker({})] Running.", self.peer.address);
+        info!(
+            "[PeerWorker({})] Running with messages version {}.",
+            self.peer.address, self.peer.version
+        );
 
-        let mut context = PeerReadContext {
-            state: PeerReadState::Header,
-            buffer: Vec::new(),
-        };
+        let mut buffer = Vec::new();
         // let mut receiver_fused = receiver.fuse();
         // let mut shutdown_fused = shutdown.fuse();
 
@@ -76,7 +90,7 @@ impl PeerWorker {
             select! {
                 event = receiver_fused.next() => {
                     if let Some(event) = event {
-                        context = self.message_handler(context, event).await;
+                        buffer = self.message_handler(buffer, event).await;
                     }
                 },
                 _ = shutdown_fused => {
@@ -174,8 +188,9 @@ impl PeerWorker {
                 debug!("[PeerWorker({})] Reading Heartbeat...", self.peer.address);
                 match tlv_from_bytes::<Heartbeat>(&header, bytes) {
                     Ok(message) => {
-                        self.peer.set_solid_milestone_index(message.solid_milestone_index);
-                        self.peer.set_snapshot_milestone_index(message.snapshot_milestone_index);
+                        self.peer.set_solid_milestone_index(message.solid_milestone_index());
+                        self.peer
+                            .set_snapshot_milestone_index(message.snapshot_milestone_index());
 
                         self.peer.metrics.heartbeat_received_inc();
                         Protocol::get().metrics.heartbeat_received_inc();
@@ -188,6 +203,41 @@ impl PeerWorker {
                     }
                 }
             }
+            LegacyGossip::ID => {
+                debug!("[PeerWorker({})] Reading LegacyGossip...", self.peer.address);
+                match tlv_from_bytes::<LegacyGossip>(header, bytes) {
+                    Ok(message) => {
+                        self.transaction_worker
+                            .send(TransactionWorkerEvent {
+                                from: self.peer.epid,
+                                transaction_broadcast: TransactionBroadcast::new(&message.transaction),
+                            })
+                            .await
+                            .map_err(|_| PeerWorkerError::FailedSend)?;
+
+                        if let Some(hash) = legacy_gossip_request(&message.hash) {
+                            if !Protocol::request_transaction(hash, *tangle().get_last_milestone_index()).await {
+                                warn!(
+                                    "[PeerWorker({})] Requesting transaction {} failed: too many pending requests.",
+                                    self.peer.address, hash
+                                );
+                            }
+                        }
+
+                        self.peer.metrics.transaction_broadcast_received_inc();
+                        Protocol::get().metrics.transaction_broadcast_received_inc();
+                    }
+                    Err(e) => {
+                        warn!(
+                            "[PeerWorker({})] Reading LegacyGossip failed: {:?}.",
+                            self.peer.address, e
+                        );
+
+                        self.peer.metrics.invalid_messages_received_inc();
+                        Protocol::get().metrics.invalid_messages_received_inc();
+                    }
+                }
+            }
             _ => {
                 warn!("[PeerWorker({})] Ignoring unsupported message.", self.peer.address);
 
@@ -199,64 +249,79 @@ impl PeerWorker {
         Ok(())
     }
 
-    async fn message_handler(&mut self, mut context: PeerReadContext, mut bytes: Vec<u8>) -> PeerReadContext {
+    async fn message_handler(&mut self, mut buffer: Vec<u8>, mut bytes: Vec<u8>) -> Vec<u8> {
         let mut offset = 0;
-        let mut remaining = true;
 
-        if context.buffer.is_empty() {
-            context.buffer = bytes;
+        if buffer.is_empty() {
+            buffer = bytes;
         } else {
-            context.buffer.append(&mut bytes);
+            buffer.append(&mut bytes);
+        }
+
+        while let Some((header, payload)) = tlv_split_frame(&buffer[offset..]) {
+            debug!("[PeerWorker({})] Reading Header...", self.peer.address);
+            offset += HEADER_SIZE + payload.len();
+
+            if let Err(e) = self.process_message(&header, payload).await {
+                error!(
+                    "[PeerWorker({})] Processing message failed: {:?}.",
+                    self.peer.address, e
+                );
+            }
         }
 
-        while remaining {
-            context.state = match context.state {
-                PeerReadState::Header => {
-                    if offset + 3 <= context.buffer.len() {
-                        debug!("[PeerWorker({})] Reading Header...", self.peer.address);
-                        let header = Header::from_bytes(&context.buffer[offset..offset + 3]);
-                        offset += 3;
+        buffer.split_off(offset)
+    }
+}
 
-                        PeerReadState::Payload(header)
-                    } else {
-                        remaining = false;
+#[cfg(test)]
+mod tests {
 
-                        PeerReadState::Header
-                    }
-                }
-                PeerReadState::Payload(header) => {
-                    if (offset + header.message_length as usize) <= context.buffer.len() {
-                        if let Err(e) = self
-                            .process_message(
-                                &header,
-                                &context.buffer[offset..offset + header.message_length as usize],
-                            )
-                            .await
-                        {
-                            error!(
-                                "[PeerWorker({})] Processing message failed: {:?}.",
-                                self.peer.address, e
-                            );
-                        }
+    use super::*;
 
-                        offset += header.message_length as usize;
+    use bee_ternary::T5B1Buf;
+    use bee_test::transaction::create_random_tx;
 
-                        PeerReadState::Header
-                    } else {
-                        remaining = false;
+    use async_std::task::block_on;
+    use serial_test::serial;
 
-                        PeerReadState::Payload(header)
-                    }
-                }
-            };
-        }
+    fn suffix(hash: &Hash) -> Vec<u8> {
+        cast_slice(hash.as_trits().encode::<T5B1Buf>().as_i8_slice()).to_vec()
+    }
 
-        PeerReadContext {
-            state: context.state,
-            buffer: context.buffer[offset..].to_vec(),
-        }
+    #[test]
+    #[serial]
+    fn legacy_gossip_request_known_hash() {
+        bee_tangle::init();
+
+        let (hash, transaction) = create_random_tx();
+        block_on(tangle().insert_transaction(transaction, hash));
+
+        assert_eq!(legacy_gossip_request(&suffix(&hash)), None);
+
+        bee_tangle::drop();
     }
-}
 
-#[cfg(test)]
-mod tests {}
+    #[test]
+    #[serial]
+    fn legacy_gossip_request_unknown_hash() {
+        bee_tangle::init();
+
+        let (hash, _) = create_random_tx();
+
+        assert_eq!(legacy_gossip_request(&suffix(&hash)), Some(hash));
+
+        bee_tangle::drop();
+    }
+
+    #[test]
+    #[serial]
+    fn legacy_gossip_request_null_hash() {
+        bee_tangle::init();
+
+        assert_eq!(suffix(&Hash::zeros()), vec![0u8; 49]);
+        assert_eq!(legacy_gossip_request(&[0u8; 49]), None);
+
+        bee_tangle::drop();
+    }
+}
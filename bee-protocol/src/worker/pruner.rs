@@ -0,0 +1,155 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use crate::milestone::MilestoneIndex;
+
+use bee_tangle::tangle;
+
+use futures::{
+    channel::{mpsc, oneshot},
+    future::FutureExt,
+    select,
+    stream::StreamExt,
+};
+use log::{debug, info};
+
+pub(crate) struct PrunerWorkerEvent(pub(crate) MilestoneIndex);
+
+pub(crate) struct PrunerWorker {
+    retention: u32,
+    pruned_index: MilestoneIndex,
+}
+
+impl PrunerWorker {
+    pub(crate) fn new(retention: u32) -> Self {
+        Self {
+            retention,
+            pruned_index: (*tangle().get_snapshot_milestone_index()).saturating_sub(retention),
+        }
+    }
+
+    fn prune(&mut self, snapshot_index: MilestoneIndex) {
+        // Keeps `retention` milestones below the snapshot index around
+        let target_index = snapshot_index.saturating_sub(self.retention);
+
+        if target_index <= self.pruned_index {
+            return;
+        }
+
+        for index in self.pruned_index + 1..=target_index {
+            tangle().prune_below_milestone(index.into());
+        }
+
+        debug!(
+            "[PrunerWorker ] Pruned milestones {}..{}.",
+            self.pruned_index + 1,
+            target_index
+        );
+
+        self.pruned_index = target_index;
+    }
+
+    pub(crate) async fn run(mut self, receiver: mpsc::Receiver<PrunerWorkerEvent>, shutdown: oneshot::Receiver<()>) {
+        info!("[PrunerWorker ] Running.");
+
+        let mut receiver_fused = receiver.fuse();
+        let mut shutdown_fused = shutdown.fuse();
+
+        loop {
+            select! {
+                event = receiver_fused.next() => {
+                    if let Some(PrunerWorkerEvent(index)) = event {
+                        self.prune(index);
+                    }
+                },
+                _ = shutdown_fused => {
+                    break;
+                }
+            }
+        }
+
+        info!("[PrunerWorker ] Stopped.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::{protocol::Protocol, util::init_protocol};
+
+    use bee_test::transaction::{create_random_attached_tx, create_random_tx};
+
+    use async_std::task::{block_on, sleep};
+    use serial_test::serial;
+
+    use std::time::Duration;
+
+    async fn wait_for_pruning(index: MilestoneIndex) {
+        for _ in 0..100 {
+            if !tangle().contains_milestone(index.into()) {
+                return;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        panic!("Milestone {} was not pruned", index);
+    }
+
+    #[test]
+    #[serial]
+    fn prune_past_retention_window() {
+        bee_tangle::init();
+
+        init_protocol();
+
+        let tangle = tangle();
+        let retention = Protocol::get().config.pruning_retention;
+
+        // Each milestone directly approves the previous one
+        let (hash_1, tx_1) = create_random_tx();
+        let (hash_2, tx_2) = create_random_attached_tx(hash_1, hash_1);
+        let (hash_3, tx_3) = create_random_attached_tx(hash_2, hash_2);
+
+        block_on(async {
+            tangle.insert_transaction(tx_1, hash_1).await;
+            tangle.insert_transaction(tx_2, hash_2).await;
+            tangle.insert_transaction(tx_3, hash_3).await;
+        });
+
+        for (index, hash) in [(1u32, hash_1), (2, hash_2), (3, hash_3)].iter() {
+            tangle.add_milestone((*index).into(), *hash);
+            tangle.confirm_transaction(*hash, (*index).into());
+        }
+
+        block_on(async {
+            // Still within the retention window
+            Protocol::update_snapshot_milestone_index(retention).await;
+            sleep(Duration::from_millis(50)).await;
+            assert_eq!(tangle.size(), 3);
+
+            Protocol::update_snapshot_milestone_index(retention + 1).await;
+            wait_for_pruning(1).await;
+            assert!(!tangle.contains_transaction(&hash_1));
+            assert!(tangle.contains_transaction(&hash_2));
+            assert!(tangle.contains_transaction(&hash_3));
+
+            Protocol::update_snapshot_milestone_index(retention + 2).await;
+            wait_for_pruning(2).await;
+            assert!(!tangle.contains_transaction(&hash_2));
+            assert!(tangle.contains_transaction(&hash_3));
+        });
+
+        assert_eq!(*tangle.get_snapshot_milestone_index(), retention + 2);
+
+        bee_tangle::drop();
+    }
+}
@@ -14,13 +14,83 @@ use crate::{message::MilestoneRequest, milestone::MilestoneIndex, protocol::Prot
 use bee_network::EndpointId;
 use bee_tangle::tangle;
 
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
+use dashmap::DashMap;
 use futures::{channel::oneshot, future::FutureExt, select};
 use log::info;
 use rand::{Rng, SeedableRng};
 use rand_pcg::Pcg32;
 
+// How long a requested milestone is awaited before it can be requested again.
+const MILESTONE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Returns whether a milestone requested at `requested_at` is still awaited at `now`.
+pub(crate) fn is_awaited(requested_at: Instant, now: Instant) -> bool {
+    now.duration_since(requested_at) < MILESTONE_REQUEST_TIMEOUT
+}
+
+// Counts the milestone requests of `requested` still awaited at `now` from each peer. Requests that weren't sent to a
+// peer yet aren't counted.
+pub(crate) fn outstanding_requests(
+    requested: &DashMap<MilestoneIndex, (Option<EndpointId>, Instant)>,
+    now: Instant,
+) -> HashMap<EndpointId, usize> {
+    let mut outstanding = HashMap::new();
+
+    for entry in requested.iter() {
+        if let (Some(epid), requested_at) = entry.value() {
+            if is_awaited(*requested_at, now) {
+                *outstanding.entry(*epid).or_insert(0) += 1;
+            }
+        }
+    }
+
+    outstanding
+}
+
+// Assigns the indexes of `range` to `epids` round-robin, starting with the peer at `start % epids.len()` so that
+// consecutive batches don't always load the same peer first. Each peer is assigned indexes until it has `cap`
+// `outstanding` requests, the remaining ones are left out of the batch.
+pub(crate) fn assign_round_robin<I: IntoIterator<Item = MilestoneIndex>>(
+    range: I,
+    start: usize,
+    epids: &[EndpointId],
+    cap: usize,
+    outstanding: &HashMap<EndpointId, usize>,
+) -> Vec<(MilestoneIndex, EndpointId)> {
+    let mut slots: Vec<(EndpointId, usize)> = (0..epids.len())
+        .map(|i| epids[(start + i) % epids.len()])
+        .map(|epid| (epid, cap.saturating_sub(*outstanding.get(&epid).unwrap_or(&0))))
+        .filter(|(_, free)| *free > 0)
+        .collect();
+    let mut assigned = Vec::new();
+    let mut next = 0;
+
+    for index in range {
+        if slots.is_empty() {
+            break;
+        }
+
+        next %= slots.len();
+        let (epid, free) = &mut slots[next];
+        assigned.push((index, *epid));
+        *free -= 1;
+
+        if *free == 0 {
+            slots.remove(next);
+        } else {
+            next += 1;
+        }
+    }
+
+    assigned
+}
+
 #[derive(Eq, PartialEq)]
 pub(crate) struct MilestoneRequesterWorkerEntry(pub(crate) MilestoneIndex, pub(crate) Option<EndpointId>);
 
@@ -50,6 +120,8 @@ impl MilestoneRequesterWorker {
 
     async fn process_request(&mut self, index: MilestoneIndex, epid: Option<EndpointId>) {
         if Protocol::get().peer_manager.handshaked_peers.is_empty() {
+            // NOTE: released so that the milestone gets requested again once there are peers.
+            Protocol::get().requested_milestones.remove(&index);
             return;
         }
 
@@ -62,11 +134,18 @@ impl MilestoneRequesterWorker {
                         .gen_range(0, Protocol::get().peer_manager.handshaked_peers.len()),
                 ) {
                     Some(entry) => *entry.key(),
-                    None => return,
+                    None => {
+                        Protocol::get().requested_milestones.remove(&index);
+                        return;
+                    }
                 }
             }
         };
 
+        if let Some(mut requested) = Protocol::get().requested_milestones.get_mut(&index) {
+            *requested = (Some(epid), Instant::now());
+        }
+
         SenderWorker::<MilestoneRequest>::send(&epid, MilestoneRequest::new(index)).await;
     }
 
@@ -82,6 +161,8 @@ impl MilestoneRequesterWorker {
                     if let MilestoneRequesterWorkerEntry(index, epid) = entry {
                         if !tangle().contains_milestone(index.into()) {
                             self.process_request(index, epid).await;
+                        } else {
+                            Protocol::get().requested_milestones.remove(&index);
                         }
 
                     }
@@ -95,3 +176,60 @@ impl MilestoneRequesterWorker {
         info!("[MilestoneRequesterWorker ] Stopped.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use bee_network::Url;
+
+    use async_std::task::block_on;
+
+    #[test]
+    fn assign_round_robin_spreads_and_caps() {
+        let epids: Vec<EndpointId> = vec![
+            block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap().into(),
+            block_on(Url::from_url_str("tcp://[::1]:16001")).unwrap().into(),
+            block_on(Url::from_url_str("tcp://[::1]:16002")).unwrap().into(),
+        ];
+
+        let none = HashMap::new();
+
+        assert_eq!(
+            assign_round_robin(10..14, 1, &epids, 2, &none),
+            vec![(10, epids[1]), (11, epids[2]), (12, epids[0]), (13, epids[1])]
+        );
+        assert_eq!(assign_round_robin(10..100, 0, &epids, 2, &none).len(), 6);
+        assert!(assign_round_robin(10..100, 0, &epids, 0, &none).is_empty());
+        assert!(assign_round_robin(10..100, 0, &[], 2, &none).is_empty());
+    }
+
+    #[test]
+    fn assign_round_robin_counts_outstanding_requests() {
+        let epids: Vec<EndpointId> = vec![
+            block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap().into(),
+            block_on(Url::from_url_str("tcp://[::1]:16001")).unwrap().into(),
+        ];
+        let requested = DashMap::new();
+        let now = Instant::now();
+
+        requested.insert(1, (Some(epids[0]), now));
+        requested.insert(2, (Some(epids[1]), now));
+        requested.insert(3, (Some(epids[1]), now));
+        // Not sent yet, or no longer awaited.
+        requested.insert(4, (None, now));
+        requested.insert(5, (Some(epids[0]), now - MILESTONE_REQUEST_TIMEOUT));
+
+        let outstanding = outstanding_requests(&requested, now);
+        assert_eq!(outstanding.get(&epids[0]), Some(&1));
+        assert_eq!(outstanding.get(&epids[1]), Some(&2));
+
+        assert_eq!(
+            assign_round_robin(10..14, 0, &epids, 2, &outstanding),
+            vec![(10, epids[0])]
+        );
+        assert!(is_awaited(now, now));
+        assert!(!is_awaited(now - MILESTONE_REQUEST_TIMEOUT, now));
+    }
+}
@@ -12,5 +12,7 @@
 mod milestone;
 mod transaction;
 
-pub(crate) use milestone::{MilestoneRequesterWorker, MilestoneRequesterWorkerEntry};
+pub(crate) use milestone::{
+    assign_round_robin, is_awaited, outstanding_requests, MilestoneRequesterWorker, MilestoneRequesterWorkerEntry,
+};
 pub(crate) use transaction::{TransactionRequesterWorker, TransactionRequesterWorkerEntry};
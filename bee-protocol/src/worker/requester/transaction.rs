@@ -12,17 +12,99 @@
 use crate::{message::TransactionRequest, milestone::MilestoneIndex, protocol::Protocol, worker::SenderWorker};
 
 use bee_bundle::Hash;
+use bee_network::EndpointId;
 use bee_tangle::tangle;
 use bee_ternary::T5B1Buf;
 
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 use bytemuck::cast_slice;
+use dashmap::DashMap;
 use futures::{channel::oneshot, future::FutureExt, select};
-use log::info;
+use log::{debug, info};
 use rand::{Rng, SeedableRng};
 use rand_pcg::Pcg32;
 
+// How often the requested transactions are checked for requests to retry.
+const RETRY_SWEEP_INTERVAL: Duration = Duration::from_millis(1000);
+
+// Picks a random peer among those with less than `cap` outstanding requests, falling back to the least loaded peer
+// if all of them reached the cap.
+fn select_peer<R: Rng>(
+    rng: &mut R,
+    epids: &[EndpointId],
+    in_flight: &DashMap<EndpointId, usize>,
+    cap: usize,
+) -> Option<EndpointId> {
+    let load = |epid: &EndpointId| in_flight.get(epid).map_or(0, |count| *count);
+    let available: Vec<&EndpointId> = epids.iter().filter(|epid| load(epid) < cap).collect();
+
+    if available.is_empty() {
+        epids.iter().min_by_key(|epid| load(epid)).copied()
+    } else {
+        Some(*available[rng.gen_range(0, available.len())])
+    }
+}
+
+// Returns whether a transaction last requested at `requested_at` was requested less than `window` before `now`, in
+// which case it should not be requested again yet.
+fn within_dedup_window(requested_at: Option<Instant>, now: Instant, window: Duration) -> bool {
+    requested_at.is_some_and(|requested_at| now.duration_since(requested_at) < window)
+}
+
+// Returns how long a transaction that has already been retried `retries` times is awaited before being requested again;
+// the delay doubles with each retry.
+fn retry_delay(timeout: Duration, retries: u8) -> Duration {
+    timeout
+        .checked_mul(1 << u32::from(retries.min(16)))
+        .unwrap_or_else(|| Duration::from_secs(u64::MAX))
+}
+
+// Goes through the requested transactions whose retry delay elapsed at `now`. Those that were retried less than
+// `max_retries` times are handed to `enqueue` to be requested again, the others are given up on and removed from
+// `requested`, and returned along with the peer they were requested from. A retry only counts if `enqueue` accepted
+// it, otherwise it is attempted again at the next sweep.
+fn sweep_requested<F: FnMut(TransactionRequesterWorkerEntry) -> bool>(
+    requested: &DashMap<Hash, (MilestoneIndex, EndpointId, Instant)>,
+    retries: &mut HashMap<Hash, u8>,
+    now: Instant,
+    timeout: Duration,
+    max_retries: u8,
+    mut enqueue: F,
+) -> Vec<(Hash, EndpointId)> {
+    retries.retain(|hash, _| requested.contains_key(hash));
+
+    let expired: Vec<(Hash, MilestoneIndex, EndpointId)> = requested
+        .iter()
+        .filter(|entry| {
+            now.duration_since(entry.value().2) >= retry_delay(timeout, *retries.get(entry.key()).unwrap_or(&0))
+        })
+        .map(|entry| (*entry.key(), entry.value().0, entry.value().1))
+        .collect();
+
+    let mut dropped = Vec::new();
+
+    for (hash, index, epid) in expired {
+        let count = retries.entry(hash).or_insert(0);
+
+        if *count < max_retries {
+            if enqueue(TransactionRequesterWorkerEntry(hash, index)) {
+                *count += 1;
+            }
+        } else {
+            retries.remove(&hash);
+            requested.remove(&hash);
+            dropped.push((hash, epid));
+        }
+    }
+
+    dropped
+}
+
 #[derive(Eq, PartialEq)]
 pub(crate) struct TransactionRequesterWorkerEntry(pub(crate) Hash, pub(crate) MilestoneIndex);
 
@@ -41,12 +123,36 @@ impl Ord for TransactionRequesterWorkerEntry {
 
 pub(crate) struct TransactionRequesterWorker {
     rng: Pcg32,
+    retries: HashMap<Hash, u8>,
 }
 
 impl TransactionRequesterWorker {
     pub(crate) fn new() -> Self {
         Self {
             rng: Pcg32::from_entropy(),
+            retries: HashMap::new(),
+        }
+    }
+
+    fn retry_requests(&mut self) {
+        let dropped = sweep_requested(
+            &Protocol::get().requested,
+            &mut self.retries,
+            Instant::now(),
+            Duration::from_millis(Protocol::get().config.workers.transaction_requester_retry_timeout),
+            Protocol::get().config.workers.transaction_requester_max_retries,
+            |entry| match Protocol::get().transaction_requester_worker.0.insert(entry) {
+                Ok(()) => true,
+                Err(TransactionRequesterWorkerEntry(hash, _)) => {
+                    debug!("[TransactionRequesterWorker ] Retrying request of {} deferred.", hash);
+                    false
+                }
+            },
+        );
+
+        for (hash, epid) in dropped {
+            debug!("[TransactionRequesterWorker ] Giving up on requesting {}.", hash);
+            Protocol::get().peer_manager.request_fulfilled(&epid);
         }
     }
 
@@ -55,34 +161,54 @@ impl TransactionRequesterWorker {
             return;
         }
 
-        // TODO check that neighbor may have the tx (by the index)
-        Protocol::get().requested.insert(hash, index);
+        if within_dedup_window(
+            Protocol::get().requested.get(&hash).map(|entry| entry.2),
+            Instant::now(),
+            Duration::from_millis(Protocol::get().config.workers.transaction_requester_dedup_window),
+        ) {
+            return;
+        }
+
+        let epids = Protocol::get().peer_manager.peers_for_index(index);
 
-        match Protocol::get().peer_manager.handshaked_peers.iter().nth(
-            self.rng
-                .gen_range(0, Protocol::get().peer_manager.handshaked_peers.len()),
+        let epid = match select_peer(
+            &mut self.rng,
+            &epids,
+            &Protocol::get().peer_manager.in_flight_requests,
+            Protocol::get().config.workers.transaction_requester_peer_cap,
         ) {
-            Some(entry) => {
-                SenderWorker::<TransactionRequest>::send(
-                    entry.key(),
-                    TransactionRequest::new(cast_slice(hash.as_trits().encode::<T5B1Buf>().as_i8_slice())),
-                )
-                .await;
-            }
-            None => {}
+            Some(epid) => epid,
+            None => return,
+        };
+
+        if let Some((_, previous, _)) = Protocol::get().requested.insert(hash, (index, epid, Instant::now())) {
+            Protocol::get().peer_manager.request_fulfilled(&previous);
         }
+        Protocol::get().peer_manager.request_sent(&epid);
+
+        SenderWorker::<TransactionRequest>::send(
+            &epid,
+            TransactionRequest::new(cast_slice(hash.as_trits().encode::<T5B1Buf>().as_i8_slice())),
+        )
+        .await;
     }
 
     pub(crate) async fn run(mut self, shutdown: oneshot::Receiver<()>) {
         info!("[TransactionRequesterWorker ] Running.");
 
         let mut shutdown_fused = shutdown.fuse();
+        let mut last_sweep = Instant::now();
 
         loop {
+            // NOTE: parks until the next sweep is due at most, so that requests are retried even when idle.
+            let until_sweep = RETRY_SWEEP_INTERVAL
+                .checked_sub(last_sweep.elapsed())
+                .unwrap_or_default();
+
             select! {
                 // TODO impl fused stream
-                entry = Protocol::get().transaction_requester_worker.0.pop().fuse() => {
-                    if let TransactionRequesterWorkerEntry(hash, index) = entry {
+                entry = Protocol::get().transaction_requester_worker.0.pop_timeout(until_sweep).fuse() => {
+                    if let Some(TransactionRequesterWorkerEntry(hash, index)) = entry {
                         if !tangle().is_solid_entry_point(&hash) && !tangle().contains_transaction(&hash) {
                             self.process_request(hash, index).await;
                         }
@@ -92,8 +218,147 @@ impl TransactionRequesterWorker {
                     break;
                 }
             }
+
+            if last_sweep.elapsed() >= RETRY_SWEEP_INTERVAL {
+                self.retry_requests();
+                last_sweep = Instant::now();
+            }
         }
 
         info!("[TransactionRequesterWorker ] Stopped.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use bee_network::Url;
+
+    use async_std::task::block_on;
+
+    #[test]
+    fn select_peer_skips_peers_at_cap() {
+        let mut rng = Pcg32::from_entropy();
+        let epids: Vec<EndpointId> = vec![
+            block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap().into(),
+            block_on(Url::from_url_str("tcp://[::1]:16001")).unwrap().into(),
+        ];
+        let in_flight = DashMap::new();
+
+        let first = select_peer(&mut rng, &epids, &in_flight, 1).unwrap();
+        in_flight.insert(first, 1);
+        let second = select_peer(&mut rng, &epids, &in_flight, 1).unwrap();
+        assert_ne!(first, second);
+
+        in_flight.insert(second, 2);
+        assert_eq!(select_peer(&mut rng, &epids, &in_flight, 1), Some(first));
+
+        assert_eq!(select_peer(&mut rng, &[], &in_flight, 1), None);
+    }
+
+    #[test]
+    fn dedup_window() {
+        let window = Duration::from_millis(100);
+        let requested_at = Instant::now();
+
+        assert!(!within_dedup_window(None, requested_at, window));
+        assert!(within_dedup_window(Some(requested_at), requested_at, window));
+        assert!(within_dedup_window(
+            Some(requested_at),
+            requested_at + window / 2,
+            window
+        ));
+        assert!(!within_dedup_window(Some(requested_at), requested_at + window, window));
+        assert!(!within_dedup_window(
+            Some(requested_at),
+            requested_at,
+            Duration::from_millis(0)
+        ));
+    }
+
+    #[test]
+    fn retry_delay_backs_off() {
+        let timeout = Duration::from_millis(100);
+
+        assert_eq!(retry_delay(timeout, 0), timeout);
+        assert_eq!(retry_delay(timeout, 1), timeout * 2);
+        assert_eq!(retry_delay(timeout, 3), timeout * 8);
+        assert_eq!(retry_delay(timeout, 255), retry_delay(timeout, 16));
+    }
+
+    #[test]
+    fn sweep_requested_retries_then_drops() {
+        let epid: EndpointId = block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap().into();
+        let timeout = Duration::from_millis(100);
+        let requested = DashMap::new();
+        let mut retries = HashMap::new();
+        let mut retried = Vec::new();
+        let hash = Hash::zeros();
+        let requested_at = Instant::now();
+
+        let mut sweep = |requested: &DashMap<_, _>, now, retried: &mut Vec<_>| {
+            sweep_requested(requested, &mut retries, now, timeout, 2, |entry| {
+                retried.push(entry);
+                true
+            })
+        };
+
+        requested.insert(hash, (42, epid, requested_at));
+
+        // Not expired yet.
+        let dropped = sweep(&requested, requested_at, &mut retried);
+        assert!(retried.is_empty() && dropped.is_empty());
+
+        // First retry after the timeout.
+        let dropped = sweep(&requested, requested_at + timeout, &mut retried);
+        assert!(retried == vec![TransactionRequesterWorkerEntry(hash, 42)] && dropped.is_empty());
+        retried.clear();
+
+        // The second retry waits twice as long.
+        let requested_at = requested_at + timeout;
+        requested.insert(hash, (42, epid, requested_at));
+        let dropped = sweep(&requested, requested_at + timeout, &mut retried);
+        assert!(retried.is_empty() && dropped.is_empty());
+        let dropped = sweep(&requested, requested_at + timeout * 2, &mut retried);
+        assert!(retried.len() == 1 && dropped.is_empty());
+        retried.clear();
+
+        // Once out of retries, the request is given up on.
+        let requested_at = requested_at + timeout * 2;
+        requested.insert(hash, (42, epid, requested_at));
+        let dropped = sweep(&requested, requested_at + timeout * 4, &mut retried);
+        assert!(retried.is_empty());
+        assert_eq!(dropped, vec![(hash, epid)]);
+        assert!(requested.is_empty());
+        assert!(retries.is_empty());
+    }
+
+    #[test]
+    fn sweep_requested_does_not_count_rejected_retries() {
+        let epid: EndpointId = block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap().into();
+        let timeout = Duration::from_millis(100);
+        let requested = DashMap::new();
+        let mut retries = HashMap::new();
+        let hash = Hash::zeros();
+        let requested_at = Instant::now();
+
+        requested.insert(hash, (42, epid, requested_at));
+
+        // The queue is full, so the retry doesn't happen and isn't counted.
+        for _ in 0..3 {
+            let dropped = sweep_requested(&requested, &mut retries, requested_at + timeout, timeout, 1, |_| false);
+            assert!(dropped.is_empty());
+        }
+        assert_eq!(retries.get(&hash), Some(&0));
+
+        let mut retried = 0;
+        let dropped = sweep_requested(&requested, &mut retries, requested_at + timeout, timeout, 1, |_| {
+            retried += 1;
+            true
+        });
+        assert!(retried == 1 && dropped.is_empty());
+        assert_eq!(retries.get(&hash), Some(&1));
+    }
+}
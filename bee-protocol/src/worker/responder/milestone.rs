@@ -41,13 +41,13 @@ impl MilestoneResponderWorker {
     }
 
     async fn process_request(&self, epid: EndpointId, request: MilestoneRequest) {
-        let index = match request.index {
-            0 => tangle().get_last_milestone_index(),
-            _ => request.index.into(),
+        let milestone = match request.index {
+            MilestoneRequest::LATEST_INDEX => tangle().get_latest_milestone(),
+            index => tangle().get_milestone(index.into()),
         };
 
         // TODO send complete ms bundle ?
-        match tangle().get_milestone(index) {
+        match milestone {
             Some(transaction) => {
                 let mut trits = TritBuf::<T1B1Buf>::zeros(Transaction::trit_len());
                 transaction.into_trits_allocated(&mut trits);
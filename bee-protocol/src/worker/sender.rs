@@ -13,51 +13,62 @@ use crate::{
     message::{tlv_into_bytes, Heartbeat, Message, MilestoneRequest, TransactionBroadcast, TransactionRequest},
     peer::HandshakedPeer,
     protocol::Protocol,
+    util::TokenBucket,
 };
 
 use bee_network::{Command::SendMessage, EndpointId, Network};
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use async_std::{future::timeout, task::sleep};
 use futures::{
     channel::{mpsc, oneshot},
-    future::FutureExt,
+    future::{Fuse, FutureExt},
     select,
     sink::SinkExt,
     stream::StreamExt,
 };
 use log::warn;
 
+// How long queued messages are still sent for after the shutdown signal, when draining on shutdown.
+const DRAIN_DEADLINE: Duration = Duration::from_secs(1);
+
 pub(crate) struct SenderWorker<M: Message> {
     network: Network,
     peer: Arc<HandshakedPeer>,
+    bucket: Option<TokenBucket>,
+    drain_on_shutdown: bool,
     _message_type: PhantomData<M>,
 }
 
 macro_rules! implement_sender_worker {
     ($type:ty, $sender:tt, $incrementor:tt) => {
         impl SenderWorker<$type> {
-            pub(crate) fn new(network: Network, peer: Arc<HandshakedPeer>) -> Self {
+            pub(crate) fn new(network: Network, peer: Arc<HandshakedPeer>, rate: u32, drain_on_shutdown: bool) -> Self {
                 Self {
                     network,
                     peer,
+                    bucket: TokenBucket::new(rate, Instant::now()),
+                    drain_on_shutdown,
                     _message_type: PhantomData,
                 }
             }
 
             pub(crate) async fn send(epid: &EndpointId, message: $type) {
-                if let Some(context) = Protocol::get().peer_manager.handshaked_peers.get(&epid) {
-                    if let Err(e) = context
-                        .$sender
-                        .0
-                        // TODO avoid clone ?
-                        .clone()
-                        .send(message)
-                        .await
-                    {
-                        warn!("[SenderWorker ] Sending message failed: {:?}.", e);
-                    }
+                // Only the channel handle is cloned, the message itself is moved into the channel. The peers map is
+                // not held while awaiting.
+                let mut sender = match Protocol::get().peer_manager.handshaked_peers.get(&epid) {
+                    Some(context) => context.$sender.0.clone(),
+                    None => return,
                 };
+
+                if let Err(e) = sender.send(message).await {
+                    warn!("[SenderWorker ] Sending message failed: {:?}.", e);
+                }
             }
 
             pub(crate) async fn run(
@@ -67,30 +78,17 @@ macro_rules! implement_sender_worker {
             ) {
                 let mut events_fused = events_receiver.fuse();
                 let mut shutdown_fused = shutdown_receiver.fuse();
+                let mut pending = None;
 
                 loop {
                     select! {
                         message = events_fused.next() => {
                             if let Some(message) = message {
-                                match self
-                                    .network
-                                    .send(SendMessage {
-                                        epid: self.peer.epid,
-                                        bytes: tlv_into_bytes(message),
-                                        responder: None,
-                                    })
-                                    .await
-                                {
-                                    Ok(_) => {
-                                        self.peer.metrics.$incrementor();
-                                        Protocol::get().metrics.$incrementor();
-                                    }
-                                    Err(e) => {
-                                        warn!(
-                                            "[SenderWorker({}) ] Sending message failed: {}.",
-                                            self.peer.epid, e
-                                        );
-                                    }
+                                if self.wait_for_token(&mut shutdown_fused).await {
+                                    self.send_message(message).await;
+                                } else {
+                                    pending = Some(message);
+                                    break;
                                 }
                             }
                         }
@@ -99,6 +97,72 @@ macro_rules! implement_sender_worker {
                         }
                     }
                 }
+
+                if self.drain_on_shutdown {
+                    self.drain(pending, events_fused.get_mut()).await;
+                }
+            }
+
+            // Waits until the rate limit allows a message to be sent, returns `false` if shut down in the meantime.
+            async fn wait_for_token(&mut self, shutdown: &mut Fuse<oneshot::Receiver<()>>) -> bool {
+                if let Some(bucket) = self.bucket.as_mut() {
+                    while let Err(wait) = bucket.try_take(Instant::now()) {
+                        select! {
+                            _ = sleep(wait).fuse() => (),
+                            _ = &mut *shutdown => return false,
+                        }
+                    }
+                }
+
+                true
+            }
+
+            // Sends the message that was waiting for the rate limit, if any, then the ones still queued, until the queue
+            // is empty or the drain deadline passes.
+            async fn drain(&mut self, pending: Option<$type>, events: &mut mpsc::Receiver<$type>) {
+                let deadline = Instant::now() + DRAIN_DEADLINE;
+                let queued = std::iter::from_fn(|| events.next().now_or_never().flatten());
+
+                for message in pending.into_iter().chain(queued) {
+                    if let Some(bucket) = self.bucket.as_mut() {
+                        while let Err(wait) = bucket.try_take(Instant::now()) {
+                            sleep(wait).await;
+                        }
+                    }
+
+                    let now = Instant::now();
+
+                    if now >= deadline || timeout(deadline - now, self.send_message(message)).await.is_err() {
+                        warn!(
+                            "[SenderWorker({}) ] Draining deadline reached, dropping queued messages.",
+                            self.peer.epid
+                        );
+                        break;
+                    }
+                }
+            }
+
+            async fn send_message(&mut self, message: $type) {
+                match self
+                    .network
+                    .send(SendMessage {
+                        epid: self.peer.epid,
+                        bytes: tlv_into_bytes(message),
+                        responder: None,
+                    })
+                    .await
+                {
+                    Ok(_) => {
+                        self.peer.metrics.$incrementor();
+                        Protocol::get().metrics.$incrementor();
+                    }
+                    Err(e) => {
+                        warn!(
+                            "[SenderWorker({}) ] Sending message failed: {}.",
+                            self.peer.epid, e
+                        );
+                    }
+                }
             }
         }
     };
@@ -110,3 +174,67 @@ implement_sender_worker!(TransactionRequest, transaction_request, transaction_re
 implement_sender_worker!(Heartbeat, heartbeat, heartbeat_sent);
 
 // TODO is this really necessary ?
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::util::init_protocol;
+
+    use bee_network::{Address, Command, Url};
+
+    use std::sync::Mutex;
+
+    use async_std::task::block_on;
+    use serial_test::serial;
+
+    fn peer() -> Arc<HandshakedPeer> {
+        let epid: EndpointId = block_on(Url::from_url_str("tcp://[::1]:16100")).unwrap().into();
+        let address = block_on(Address::from_addr_str("[::1]:16100")).unwrap();
+
+        Arc::new(HandshakedPeer::new(
+            epid,
+            address,
+            2,
+            (mpsc::channel(1).0, Mutex::new(None)),
+            (mpsc::channel(1).0, Mutex::new(None)),
+            (mpsc::channel(1).0, Mutex::new(None)),
+            (mpsc::channel(1).0, Mutex::new(None)),
+        ))
+    }
+
+    #[test]
+    #[serial]
+    fn drain_on_shutdown_sends_queued_messages() {
+        bee_tangle::init();
+
+        init_protocol();
+
+        let (command_sender, mut command_receiver) = mpsc::channel(16);
+        let (mut events_sender, events_receiver) = mpsc::channel(16);
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+
+        for index in 0..5 {
+            events_sender.try_send(Heartbeat::new(index, 0)).unwrap();
+        }
+        shutdown_sender.send(()).unwrap();
+
+        block_on(
+            SenderWorker::<Heartbeat>::new(Network::new(command_sender), peer(), 0, true)
+                .run(events_receiver, shutdown_receiver),
+        );
+
+        for index in 0..5 {
+            match command_receiver.next().now_or_never() {
+                Some(Some(Command::SendMessage { bytes, .. })) => {
+                    assert_eq!(bytes, tlv_into_bytes(Heartbeat::new(index, 0)))
+                }
+                _ => panic!("missing message {}", index),
+            }
+        }
+        assert!(matches!(command_receiver.next().now_or_never(), Some(None)));
+
+        bee_tangle::drop();
+    }
+}
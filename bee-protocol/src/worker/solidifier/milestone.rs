@@ -9,7 +9,7 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use crate::protocol::Protocol;
+use crate::{milestone::MilestoneConfirmed, protocol::Protocol};
 
 use bee_tangle::tangle;
 
@@ -83,11 +83,10 @@ impl MilestoneSolidifierWorker {
         let solid_milestone_index = *tangle().get_solid_milestone_index();
 
         // TODO this may request unpublished milestones
-        for index in solid_milestone_index..solid_milestone_index + MILESTONE_REQUEST_RANGE as u32 {
-            if !tangle().contains_milestone(index.into()) {
-                Protocol::request_milestone(index, None);
-            }
-        }
+        Protocol::request_milestone_range(
+            solid_milestone_index + 1,
+            solid_milestone_index + MILESTONE_REQUEST_RANGE as u32,
+        );
     }
 
     async fn solidify_milestone(&self) {
@@ -95,8 +94,16 @@ impl MilestoneSolidifierWorker {
 
         if let Some(target_hash) = tangle().get_milestone_hash(target_index.into()) {
             if tangle().is_solid_transaction(&target_hash) {
-                // TODO set confirmation index + trigger ledger
+                // NOTE: the cone of a solid milestone is complete and milestones only become solid in index order, so
+                // every transaction gets confirmed by the oldest milestone referencing it.
+                let confirmed_count = tangle().confirm_cone(target_hash, target_index.into());
+                // TODO trigger ledger
                 tangle().update_solid_milestone_index(target_index.into());
+                Protocol::notify_milestone_confirmed(MilestoneConfirmed {
+                    index: target_index,
+                    hash: target_hash,
+                    confirmed_count,
+                });
                 Protocol::broadcast_heartbeat(
                     *tangle().get_solid_milestone_index(),
                     *tangle().get_snapshot_milestone_index(),
@@ -142,4 +149,69 @@ impl MilestoneSolidifierWorker {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    use crate::util::init_protocol;
+
+    use bee_bundle::Hash;
+    use bee_test::transaction::create_random_attached_tx;
+
+    use async_std::{
+        future::timeout,
+        task::{block_on, sleep},
+    };
+    use serial_test::serial;
+
+    use std::time::Duration;
+
+    #[test]
+    #[serial]
+    fn late_cone_transaction_is_confirmed() {
+        bee_tangle::init();
+
+        init_protocol();
+
+        let index = *tangle().get_solid_milestone_index() + 1;
+        let genesis = Hash::zeros();
+        tangle().add_solid_entry_point(genesis);
+
+        let (approvee_hash, approvee) = create_random_attached_tx(genesis, genesis);
+        let (milestone_hash, milestone) = create_random_attached_tx(approvee_hash, approvee_hash);
+
+        let mut milestones = Protocol::subscribe_milestones();
+        let worker = MilestoneSolidifierWorker::new();
+
+        block_on(async {
+            // The milestone arrives before a transaction of its cone, so it can't be confirmed yet.
+            tangle().insert_transaction(milestone, milestone_hash).await;
+            tangle().add_milestone(index.into(), milestone_hash);
+
+            worker.solidify_milestone().await;
+
+            assert_eq!(*tangle().get_solid_milestone_index(), index - 1);
+            assert!(timeout(Duration::from_millis(50), milestones.next()).await.is_err());
+
+            tangle().insert_transaction(approvee, approvee_hash).await;
+
+            while !tangle().is_solid_transaction(&milestone_hash) {
+                sleep(Duration::from_millis(10)).await;
+            }
+
+            worker.solidify_milestone().await;
+
+            let event = timeout(Duration::from_secs(1), milestones.next())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(event.index, index);
+            assert_eq!(event.hash, milestone_hash);
+            assert_eq!(event.confirmed_count, 2);
+        });
+
+        assert_eq!(*tangle().get_solid_milestone_index(), index);
+        assert!(tangle().is_referenced(&approvee_hash));
+
+        bee_tangle::drop();
+    }
+}
@@ -22,7 +22,7 @@ use futures::{
     select,
     stream::StreamExt,
 };
-use log::info;
+use log::{debug, info};
 
 pub(crate) struct TransactionSolidifierWorkerEvent(pub(crate) Hash, pub(crate) MilestoneIndex);
 
@@ -52,7 +52,11 @@ impl TransactionSolidifierWorker {
             true
         } else {
             for missing_hash in missing_hashes {
-                Protocol::request_transaction(missing_hash, index).await;
+                // NOTE: the remaining transactions are requested again the next time the milestone is solidified.
+                if !Protocol::request_transaction(missing_hash, index).await {
+                    debug!("[TransactionSolidifierWorker ] Requesting missing transactions deferred: queue full.");
+                    break;
+                }
             }
 
             false
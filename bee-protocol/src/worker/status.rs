@@ -44,7 +44,13 @@ impl StatusWorker {
             )
         };
 
-        status = format!("{} Requested {}", status, Protocol::get().requested.len());
+        let queue = &Protocol::get().transaction_requester_worker.0;
+        let queued = match queue.peek(|entry| entry.1) {
+            Some(index) => format!("{} queued, next for milestone {}", queue.len(), index),
+            None => String::from("none queued"),
+        };
+
+        status = format!("{} Requested {} ({})", status, Protocol::get().requested.len(), queued);
 
         info!("[StatusWorker ] {}.", status);
     }
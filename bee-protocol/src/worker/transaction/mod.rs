@@ -12,5 +12,5 @@
 mod tiny_hash_cache;
 mod transaction;
 
-pub(crate) use tiny_hash_cache::TinyHashCache;
+pub(crate) use tiny_hash_cache::{CacheEviction, TinyHashCache};
 pub(crate) use transaction::{TransactionWorker, TransactionWorkerEvent};
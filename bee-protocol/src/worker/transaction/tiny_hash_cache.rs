@@ -10,7 +10,7 @@
 // See the License for the specific language governing permissions and limitations under the License.
 
 use std::{
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, VecDeque},
     hash::{BuildHasherDefault, Hasher},
 };
 
@@ -49,41 +49,81 @@ impl Hasher for CustomHasher {
     }
 }
 
+/// The strategy used by a `TinyHashCache` to pick the element to evict when it is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CacheEviction {
+    /// Evict the element that was inserted first.
+    Fifo,
+    /// Evict the element that was inserted or found again least recently.
+    Lru,
+}
+
 pub(crate) struct TinyHashCache {
     max_capacity: usize,
-    cache: HashSet<u64, BuildHasherDefault<CustomHasher>>,
-    elem_order: VecDeque<u64>,
+    policy: CacheEviction,
+    // Maps each cached hash to the tick of its last use.
+    cache: HashMap<u64, u64, BuildHasherDefault<CustomHasher>>,
+    // Uses in chronological order; an entry is stale if its hash has been used again since.
+    elem_order: VecDeque<(u64, u64)>,
+    tick: u64,
 }
 
 impl TinyHashCache {
-    pub fn new(max_capacity: usize) -> Self {
+    pub fn new(max_capacity: usize, policy: CacheEviction) -> Self {
         Self {
             max_capacity,
+            policy,
             cache: Default::default(),
             elem_order: Default::default(),
+            tick: 0,
         }
     }
 
     pub fn insert(&mut self, bytes: &[u8]) -> bool {
         let hash = xx_hash(bytes);
 
-        if self.contains(hash) {
+        self.tick += 1;
+
+        if let Some(last_use) = self.cache.get_mut(&hash) {
+            if self.policy == CacheEviction::Lru {
+                *last_use = self.tick;
+                self.elem_order.push_back((hash, self.tick));
+                self.compact();
+            }
             return false;
         }
 
         if self.cache.len() >= self.max_capacity {
-            let first = self.elem_order.pop_front().unwrap();
-            self.cache.remove(&first);
+            self.evict();
         }
 
-        self.cache.insert(hash.clone());
-        self.elem_order.push_back(hash);
+        self.cache.insert(hash, self.tick);
+        self.elem_order.push_back((hash, self.tick));
 
         true
     }
 
+    // Removes the least recently used element, skipping stale uses.
+    fn evict(&mut self) {
+        while let Some((hash, tick)) = self.elem_order.pop_front() {
+            if self.cache.get(&hash) == Some(&tick) {
+                self.cache.remove(&hash);
+                break;
+            }
+        }
+    }
+
+    // Drops stale uses once they make up more than half of `elem_order`, so that repeated hits can't grow it forever.
+    fn compact(&mut self) {
+        if self.elem_order.len() > 2 * self.max_capacity.max(1) {
+            let cache = &self.cache;
+            self.elem_order.retain(|(hash, tick)| cache.get(hash) == Some(tick));
+        }
+    }
+
+    #[cfg(test)]
     fn contains(&self, hash: u64) -> bool {
-        self.cache.contains(&hash)
+        self.cache.contains_key(&hash)
     }
 
     fn len(&self) -> usize {
@@ -105,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_cache_insert_same_elements() {
-        let mut cache = TinyHashCache::new(10);
+        let mut cache = TinyHashCache::new(10, CacheEviction::Fifo);
 
         let first_buf = &[1, 2, 3];
         let second_buf = &[1, 2, 3];
@@ -117,7 +157,7 @@ mod tests {
 
     #[test]
     fn test_cache_insert_different_elements() {
-        let mut cache = TinyHashCache::new(10);
+        let mut cache = TinyHashCache::new(10, CacheEviction::Fifo);
 
         let first_buf = &[1, 2, 3];
         let second_buf = &[3, 4, 5];
@@ -129,7 +169,7 @@ mod tests {
 
     #[test]
     fn test_cache_max_capacity() {
-        let mut cache = TinyHashCache::new(1);
+        let mut cache = TinyHashCache::new(1, CacheEviction::Fifo);
 
         let first_buf = &[1, 2, 3];
         let second_buf = &[3, 4, 5];
@@ -139,4 +179,47 @@ mod tests {
         assert_eq!(cache.len(), 1);
         assert_eq!(cache.insert(second_buf), false);
     }
+
+    #[test]
+    fn fifo_evicts_first_inserted() {
+        let mut cache = TinyHashCache::new(2, CacheEviction::Fifo);
+
+        assert!(cache.insert(&[1]));
+        assert!(cache.insert(&[2]));
+        assert!(!cache.insert(&[1]));
+        assert!(cache.insert(&[3]));
+
+        assert!(!cache.contains(xx_hash(&[1])));
+        assert!(cache.contains(xx_hash(&[2])));
+        assert!(cache.contains(xx_hash(&[3])));
+    }
+
+    #[test]
+    fn lru_keeps_recently_seen() {
+        let mut cache = TinyHashCache::new(2, CacheEviction::Lru);
+
+        assert!(cache.insert(&[1]));
+        assert!(cache.insert(&[2]));
+        assert!(!cache.insert(&[1]));
+        assert!(cache.insert(&[3]));
+
+        assert!(cache.contains(xx_hash(&[1])));
+        assert!(!cache.contains(xx_hash(&[2])));
+        assert!(cache.contains(xx_hash(&[3])));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn lru_repeated_hits_stay_bounded() {
+        let mut cache = TinyHashCache::new(2, CacheEviction::Lru);
+
+        for _ in 0..100 {
+            cache.insert(&[1]);
+        }
+
+        assert!(cache.elem_order.len() <= 4);
+        assert!(cache.insert(&[2]));
+        assert!(cache.insert(&[3]));
+        assert!(!cache.contains(xx_hash(&[1])));
+    }
 }
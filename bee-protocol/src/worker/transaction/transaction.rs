@@ -11,16 +11,19 @@
 
 use crate::{
     message::{uncompress_transaction_bytes, TransactionBroadcast},
-    protocol::Protocol,
-    worker::transaction::TinyHashCache,
+    milestone::MilestoneIndex,
+    protocol::{Protocol, ProtocolMetrics},
+    worker::transaction::{CacheEviction, TinyHashCache},
 };
 
 use bee_bundle::{Hash, Transaction, TransactionField};
 use bee_crypto::{CurlP81, Sponge};
 use bee_network::EndpointId;
-use bee_tangle::tangle;
-use bee_ternary::{T1B1Buf, T5B1Buf, Trits, T5B1};
+use bee_tangle::{tangle, TransactionRef};
+use bee_ternary::{T1B1Buf, T5B1Buf, TritBuf, Trits, T5B1};
 
+use bytemuck::cast_slice;
+use dashmap::DashMap;
 use futures::{
     channel::{mpsc, oneshot},
     future::FutureExt,
@@ -30,6 +33,43 @@ use futures::{
 };
 use log::{debug, error, info, warn};
 
+use std::time::{Duration, Instant};
+
+/// The minimum interval between two milestone solidification triggers of a `TransactionWorker`.
+const MILESTONE_SOLIDIFICATION_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// The outcome of inserting a received transaction into the tangle.
+pub(crate) enum TransactionInsertion {
+    /// The transaction was not known yet.
+    New(TransactionRef),
+    /// The transaction was already present in the tangle.
+    Duplicate,
+    /// The transaction was not known yet and fulfills a request made at the given milestone index to the given peer.
+    Requested(TransactionRef, MilestoneIndex, EndpointId),
+}
+
+/// The outcome of processing a received transaction broadcast.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ProcessOutcome {
+    /// The transaction was not known yet and has been inserted into the tangle.
+    Inserted,
+    /// The transaction was already present in the tangle.
+    Duplicate,
+    /// The same data was received recently and has not been processed again.
+    AlreadyKnown,
+}
+
+/// The reason why a received transaction broadcast was dropped.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ProcessError {
+    /// The received data could not be decoded from T5B1.
+    DecodeError,
+    /// The decoded trits do not make a valid transaction.
+    InvalidTransaction,
+    /// The weight of the transaction hash is below the minimum weight magnitude.
+    InsufficientWeight(u8),
+}
+
 pub(crate) struct TransactionWorkerEvent {
     pub(crate) from: EndpointId,
     pub(crate) transaction_broadcast: TransactionBroadcast,
@@ -38,13 +78,34 @@ pub(crate) struct TransactionWorkerEvent {
 pub(crate) struct TransactionWorker {
     cache: TinyHashCache,
     curl: CurlP81,
+    mwm: u8,
+    last_milestone_solidification: Option<Instant>,
 }
 
 impl TransactionWorker {
-    pub(crate) fn new(cache_size: usize) -> Self {
+    pub(crate) fn new(cache_size: usize, cache_eviction: CacheEviction, mwm: u8) -> Self {
         Self {
-            cache: TinyHashCache::new(cache_size),
+            cache: TinyHashCache::new(cache_size, cache_eviction),
             curl: CurlP81::new(),
+            mwm,
+            last_milestone_solidification: None,
+        }
+    }
+
+    // Decides whether a new transaction should trigger milestone solidification, from a snapshot of the synced status
+    // and of the emptiness of the requested transactions taken once by the caller. Triggers are debounced so that at
+    // most one fires per `MILESTONE_SOLIDIFICATION_DEBOUNCE`.
+    fn should_trigger_milestone_solidification(&mut self, synced: bool, requested_empty: bool, now: Instant) -> bool {
+        if synced || !requested_empty {
+            return false;
+        }
+
+        match self.last_milestone_solidification {
+            Some(last) if now.duration_since(last) < MILESTONE_SOLIDIFICATION_DEBOUNCE => false,
+            _ => {
+                self.last_milestone_solidification = Some(now);
+                true
+            }
         }
     }
 
@@ -63,7 +124,12 @@ impl TransactionWorker {
             select! {
                 event = receiver_fused.next() => {
                     if let Some(TransactionWorkerEvent{from, transaction_broadcast}) = event {
-                        self.process_transaction_brodcast(from, transaction_broadcast, &mut milestone_validator_worker_tx).await;
+                        let outcome = self.process_transaction_brodcast(from, transaction_broadcast, &mut milestone_validator_worker_tx).await;
+
+                        if let Some(peer) = Protocol::get().peer_manager.handshaked_peers.get(&from) {
+                            record_outcome(&peer.metrics, &outcome);
+                        }
+                        record_outcome(&Protocol::get().metrics, &outcome);
                     }
                 },
                 _ = shutdown_fused => break
@@ -78,36 +144,20 @@ impl TransactionWorker {
         from: EndpointId,
         transaction_broadcast: TransactionBroadcast,
         milestone_validator_worker_tx: &mut mpsc::Sender<Hash>,
-    ) {
+    ) -> Result<ProcessOutcome, ProcessError> {
         debug!("[TransactionWorker ] Processing received data...");
 
         if !self.cache.insert(&transaction_broadcast.transaction) {
             debug!("[TransactionWorker ] Data already received.");
-            return;
+            return Ok(ProcessOutcome::AlreadyKnown);
         }
 
         // convert received transaction bytes into T1B1 buffer
-        let transaction_buf = {
-            let u8_t5b1_buf = uncompress_transaction_bytes(&transaction_broadcast.transaction);
-
-            // transform [u8] to &[i8]
-            let i8_t5b1_slice = unsafe { &*(&u8_t5b1_buf as *const [u8] as *const [i8]) };
-
-            // get T5B1 trits
-            let t5b1_trits_result = Trits::<T5B1>::try_from_raw(i8_t5b1_slice, i8_t5b1_slice.len() * 5 - 1);
-
-            match t5b1_trits_result {
-                Ok(t5b1_trits) => {
-                    // get T5B1 trit_buf
-                    let t5b1_trit_buf = t5b1_trits.to_buf::<T5B1Buf>();
-
-                    // get T1B1 trit_buf from TB51 trit_buf
-                    t5b1_trit_buf.encode::<T1B1Buf>()
-                }
-                Err(_) => {
-                    warn!("[TransactionWorker ] Can not decode T5B1 from received data.");
-                    return;
-                }
+        let transaction_buf = match decode_transaction_bytes(&transaction_broadcast.transaction) {
+            Some(transaction_buf) => transaction_buf,
+            None => {
+                warn!("[TransactionWorker ] Can not decode T5B1 from received data.");
+                return Err(ProcessError::DecodeError);
             }
         };
 
@@ -119,71 +169,130 @@ impl TransactionWorker {
                     "[TransactionWorker ] Can not build transaction from received data: {:?}",
                     e
                 );
-                return;
+                return Err(ProcessError::InvalidTransaction);
             }
         };
 
         // calculate transaction hash
         let hash = Hash::from_inner_unchecked(self.curl.digest(&transaction_buf).unwrap());
 
-        if hash.weight() < Protocol::get().config.mwm {
-            debug!("[TransactionWorker ] Insufficient weight magnitude: {}.", hash.weight());
-            return;
+        // a weight equal to the minimum weight magnitude is sufficient
+        if hash.weight() < self.mwm {
+            debug!(
+                "[TransactionWorker ] Insufficient weight magnitude: {} < {}.",
+                hash.weight(),
+                self.mwm
+            );
+            return Err(ProcessError::InsufficientWeight(hash.weight()));
         }
 
         // store transaction
-        match tangle().insert_transaction(transaction, hash).await {
-            Some(transaction) => {
-                if !tangle().is_synced() && Protocol::get().requested.is_empty() {
+        let transaction = match insert_transaction(transaction, hash, &Protocol::get().requested).await {
+            TransactionInsertion::New(transaction) => {
+                let requested_empty = Protocol::get().requested.is_empty();
+                if self.should_trigger_milestone_solidification(tangle().is_synced(), requested_empty, Instant::now()) {
                     Protocol::trigger_milestone_solidification().await;
                 }
-                match Protocol::get().requested.remove(&hash) {
-                    Some((hash, index)) => {
-                        Protocol::trigger_transaction_solidification(hash, index).await;
-                    }
-                    None => Protocol::broadcast_transaction_message(Some(from), transaction_broadcast).await,
-                };
-
-                if transaction.address().eq(&Protocol::get().config.coordinator.public_key)
-                    || transaction.address().eq(&Protocol::get().config.workers.null_address)
-                {
-                    let tail = {
-                        if transaction.is_tail() {
-                            Some(hash)
-                        } else {
-                            let chain =
-                                tangle().trunk_walk_approvers(hash, |tx_ref| tx_ref.bundle() == transaction.bundle());
-                            match chain.last() {
-                                Some((tx_ref, hash)) => {
-                                    if tx_ref.is_tail() {
-                                        Some(*hash)
-                                    } else {
-                                        None
-                                    }
-                                }
-                                None => None,
-                            }
-                        }
-                    };
-
-                    if let Some(tail) = tail {
-                        if let Err(e) = milestone_validator_worker_tx.send(tail).await {
-                            error!(
-                                "[TransactionWorker ] Sending tail to milestone validation failed: {:?}.",
-                                e
-                            );
-                        }
-                    };
-                }
+                Protocol::broadcast_transaction_message(Some(from), transaction_broadcast).await;
+                transaction
             }
-            None => {
+            TransactionInsertion::Requested(transaction, index, epid) => {
+                Protocol::get().peer_manager.request_fulfilled(&epid);
+                // A retry of the request may still be queued, it is not needed anymore.
+                Protocol::get()
+                    .transaction_requester_worker
+                    .0
+                    .remove(|entry| entry.0 == hash);
+                Protocol::trigger_transaction_solidification(hash, index).await;
+                transaction
+            }
+            TransactionInsertion::Duplicate => {
                 debug!(
                     "[TransactionWorker ] Transaction {} already present in the tangle.",
                     &hash
                 );
+                return Ok(ProcessOutcome::Duplicate);
             }
+        };
+
+        if transaction.address().eq(&Protocol::get().config.coordinator.public_key) || transaction.address().is_null() {
+            if let Some(tail) = complete_bundle_tail(hash, &transaction) {
+                if let Err(e) = milestone_validator_worker_tx.send(tail).await {
+                    error!(
+                        "[TransactionWorker ] Sending tail to milestone validation failed: {:?}.",
+                        e
+                    );
+                }
+            };
+        }
+
+        Ok(ProcessOutcome::Inserted)
+    }
+}
+
+// Increments the counter of `metrics` matching the outcome of processing a transaction broadcast.
+fn record_outcome(metrics: &ProtocolMetrics, outcome: &Result<ProcessOutcome, ProcessError>) {
+    match outcome {
+        Ok(ProcessOutcome::Inserted) => metrics.new_transactions_received_inc(),
+        Ok(ProcessOutcome::Duplicate) | Ok(ProcessOutcome::AlreadyKnown) => metrics.known_transactions_received_inc(),
+        Err(ProcessError::InsufficientWeight(_)) => metrics.insufficient_weight_transactions_received_inc(),
+        Err(ProcessError::DecodeError) | Err(ProcessError::InvalidTransaction) => {
+            metrics.invalid_transactions_received_inc()
+        }
+    };
+}
+
+// Inserts a transaction into the tangle and removes it from the requested transactions if it was requested.
+async fn insert_transaction(
+    transaction: Transaction,
+    hash: Hash,
+    requested: &DashMap<Hash, (MilestoneIndex, EndpointId, Instant)>,
+) -> TransactionInsertion {
+    match tangle().insert_transaction(transaction, hash).await {
+        Some(transaction) => match requested.remove(&hash) {
+            Some((_, (index, epid, _))) => TransactionInsertion::Requested(transaction, index, epid),
+            None => TransactionInsertion::New(transaction),
+        },
+        None => TransactionInsertion::Duplicate,
+    }
+}
+
+// Returns the tail of the bundle of a transaction if the whole bundle is available in the tangle.
+// Incomplete bundles are dropped, their last arriving transaction will trigger the validation.
+fn complete_bundle_tail(hash: Hash, transaction: &TransactionRef) -> Option<Hash> {
+    let tail = if transaction.is_tail() {
+        hash
+    } else {
+        let chain = tangle().trunk_walk_approvers(hash, |tx_ref| tx_ref.bundle() == transaction.bundle());
+        match chain.last() {
+            Some((tx_ref, hash)) if tx_ref.is_tail() => *hash,
+            _ => return None,
         }
+    };
+
+    if tangle().get_bundle(&tail).is_none() {
+        debug!("[TransactionWorker ] Bundle of tail {} is incomplete.", tail);
+        return None;
     }
+
+    Some(tail)
+}
+
+// Uncompresses received transaction bytes and decodes them from T5B1 into a T1B1 buffer.
+fn decode_transaction_bytes(bytes: &[u8]) -> Option<TritBuf<T1B1Buf>> {
+    let u8_t5b1_buf = uncompress_transaction_bytes(bytes);
+
+    // reinterpret the bytes as signed T5B1 bytes
+    let i8_t5b1_slice = cast_slice::<u8, i8>(&u8_t5b1_buf);
+
+    // get T5B1 trits, the last trit of the last byte is padding
+    let t5b1_trits = Trits::<T5B1>::try_from_raw(i8_t5b1_slice, i8_t5b1_slice.len() * 5 - 1).ok()?;
+
+    // get T5B1 trit_buf
+    let t5b1_trit_buf = t5b1_trits.to_buf::<T5B1Buf>();
+
+    // get T1B1 trit_buf from TB51 trit_buf
+    Some(t5b1_trit_buf.encode::<T1B1Buf>())
 }
 
 #[cfg(test)]
@@ -191,24 +300,23 @@ mod tests {
 
     use super::*;
 
-    use crate::ProtocolConfig;
+    use crate::{message::compress_transaction_bytes, util::init_protocol};
 
-    use bee_network::{NetworkConfig, Url};
+    use bee_bundle::TRANSACTION_TRIT_LEN;
+    use bee_network::Url;
+    use bee_ternary::Btrit;
+    use bee_test::transaction::{create_random_attached_tx, create_random_bundle};
 
     use async_std::task::{block_on, spawn};
     use futures::sink::SinkExt;
+    use serial_test::serial;
 
     #[test]
+    #[serial]
     fn test_tx_worker_with_compressed_buffer() {
         bee_tangle::init();
 
-        // build network
-        let network_config = NetworkConfig::build().finish();
-        let (network, _shutdown, _receiver) = bee_network::init(network_config);
-
-        // init protocol
-        let protocol_config = ProtocolConfig::build().finish();
-        block_on(Protocol::init(protocol_config, network));
+        init_protocol();
 
         assert_eq!(tangle().size(), 0);
 
@@ -236,13 +344,221 @@ mod tests {
             shutdown_sender.send(()).unwrap();
         });
 
-        block_on(TransactionWorker::new(10000).run(
+        block_on(
+            TransactionWorker::new(10000, CacheEviction::Fifo, Protocol::get().config.mwm).run(
+                transaction_worker_receiver,
+                shutdown_receiver,
+                milestone_validator_worker_sender,
+            ),
+        );
+
+        assert_eq!(tangle().size(), 1);
+        assert_eq!(tangle().contains_transaction(&Hash::zeros()), true);
+
+        bee_tangle::drop();
+    }
+
+    // Runs a worker with the given minimum weight magnitude on a single all-zero transaction.
+    fn process_zero_transaction(mwm: u8) {
+        let (mut transaction_worker_sender, transaction_worker_receiver) = mpsc::channel(1000);
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        let (milestone_validator_worker_sender, _milestone_validator_worker_receiver) = mpsc::channel(1000);
+
+        spawn(async move {
+            let epid: EndpointId = Url::from_url_str("tcp://[::1]:16000").await.unwrap().into();
+            let event = TransactionWorkerEvent {
+                from: epid,
+                transaction_broadcast: TransactionBroadcast::new(&[0; 1024]),
+            };
+            transaction_worker_sender.send(event).await.unwrap();
+            async_std::task::sleep(std::time::Duration::from_millis(500)).await;
+            shutdown_sender.send(()).unwrap();
+        });
+
+        block_on(TransactionWorker::new(10000, CacheEviction::Fifo, mwm).run(
             transaction_worker_receiver,
             shutdown_receiver,
             milestone_validator_worker_sender,
         ));
+    }
+
+    #[test]
+    #[serial]
+    fn insufficient_weight_is_dropped() {
+        bee_tangle::init();
+
+        init_protocol();
+
+        let transaction_buf = decode_transaction_bytes(&[0; 1024]).unwrap();
+        let hash = Hash::from_inner_unchecked(CurlP81::new().digest(&transaction_buf).unwrap());
+
+        process_zero_transaction(hash.weight() + 1);
+
+        assert_eq!(tangle().size(), 0);
+        assert!(!tangle().contains_transaction(&hash));
+
+        process_zero_transaction(hash.weight());
 
         assert_eq!(tangle().size(), 1);
-        assert_eq!(tangle().contains_transaction(&Hash::zeros()), true);
+        assert!(tangle().contains_transaction(&hash));
+
+        bee_tangle::drop();
+    }
+
+    #[test]
+    #[serial]
+    fn only_complete_bundle_tails_are_forwarded() {
+        bee_tangle::init();
+
+        let mut bundle = create_random_bundle(3);
+        let (head_hash, head) = bundle.pop().unwrap();
+        let (tail_hash, _) = bundle[0];
+        let (middle_hash, _) = bundle[1];
+
+        let transactions = block_on(async {
+            let mut transactions = Vec::new();
+            for (hash, transaction) in bundle {
+                transactions.push(tangle().insert_transaction(transaction, hash).await.unwrap());
+            }
+            transactions
+        });
+
+        assert_eq!(complete_bundle_tail(tail_hash, &transactions[0]), None);
+        assert_eq!(complete_bundle_tail(middle_hash, &transactions[1]), None);
+
+        let head = block_on(tangle().insert_transaction(head, head_hash)).unwrap();
+
+        assert_eq!(complete_bundle_tail(tail_hash, &transactions[0]), Some(tail_hash));
+        assert_eq!(complete_bundle_tail(middle_hash, &transactions[1]), Some(tail_hash));
+        assert_eq!(complete_bundle_tail(head_hash, &head), Some(tail_hash));
+
+        bee_tangle::drop();
+    }
+
+    #[test]
+    fn max_size_transaction_bytes_round_trip() {
+        let (_, transaction) = create_random_attached_tx(Hash::zeros(), Hash::zeros());
+
+        let mut trits = TritBuf::<T1B1Buf>::zeros(Transaction::trit_len());
+        transaction.into_trits_allocated(&mut trits);
+        // The last trit of the nonce is the last meaningful trit of the last T5B1 byte
+        trits.set(TRANSACTION_TRIT_LEN - 1, Btrit::PlusOne);
+        let transaction = Transaction::from_trits(&trits).unwrap();
+
+        let bytes = compress_transaction_bytes(cast_slice(trits.encode::<T5B1Buf>().as_i8_slice()));
+        let transaction_buf = decode_transaction_bytes(&bytes).unwrap();
+
+        assert_eq!(transaction_buf.len(), TRANSACTION_TRIT_LEN);
+        assert_eq!(Transaction::from_trits(&transaction_buf).unwrap(), transaction);
+        assert_eq!(
+            CurlP81::new().digest(&transaction_buf).unwrap(),
+            CurlP81::new().digest(&trits).unwrap()
+        );
+    }
+
+    #[test]
+    fn decode_transaction_bytes_keeps_signed_trytes() {
+        let (_, transaction) = create_random_attached_tx(Hash::zeros(), Hash::zeros());
+
+        let mut trits = TritBuf::<T1B1Buf>::zeros(Transaction::trit_len());
+        transaction.into_trits_allocated(&mut trits);
+        let t5b1_buf = trits.encode::<T5B1Buf>();
+        // Negative T5B1 bytes have their high bit set and must survive the reinterpretation as signed bytes
+        assert!(t5b1_buf.as_i8_slice().iter().any(|byte| *byte < 0));
+
+        let bytes = compress_transaction_bytes(cast_slice(t5b1_buf.as_i8_slice()));
+        let transaction_buf = decode_transaction_bytes(&bytes).unwrap();
+
+        assert_eq!(transaction_buf, trits);
+    }
+
+    #[test]
+    #[serial]
+    fn insert_transaction_outcomes() {
+        bee_tangle::init();
+
+        let requested = DashMap::new();
+
+        let (hash, transaction) = create_random_attached_tx(Hash::zeros(), Hash::zeros());
+        let duplicate = transaction.clone();
+
+        assert!(matches!(
+            block_on(insert_transaction(transaction, hash, &requested)),
+            TransactionInsertion::New(_)
+        ));
+        assert!(matches!(
+            block_on(insert_transaction(duplicate, hash, &requested)),
+            TransactionInsertion::Duplicate
+        ));
+
+        let (hash, transaction) = create_random_attached_tx(Hash::zeros(), Hash::zeros());
+        let epid: EndpointId = block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap().into();
+        requested.insert(hash, (42, epid, Instant::now()));
+
+        assert!(matches!(
+            block_on(insert_transaction(transaction, hash, &requested)),
+            TransactionInsertion::Requested(_, 42, from) if from == epid
+        ));
+        assert!(requested.is_empty());
+
+        bee_tangle::drop();
+    }
+
+    #[test]
+    fn milestone_solidification_is_debounced() {
+        let mut worker = TransactionWorker::new(10, CacheEviction::Fifo, 0);
+        let now = Instant::now();
+
+        assert!(!worker.should_trigger_milestone_solidification(true, true, now));
+        assert!(!worker.should_trigger_milestone_solidification(false, false, now));
+
+        assert!(worker.should_trigger_milestone_solidification(false, true, now));
+        assert!(!worker.should_trigger_milestone_solidification(false, true, now));
+        assert!(!worker.should_trigger_milestone_solidification(
+            false,
+            true,
+            now + MILESTONE_SOLIDIFICATION_DEBOUNCE / 2
+        ));
+        assert!(worker.should_trigger_milestone_solidification(false, true, now + MILESTONE_SOLIDIFICATION_DEBOUNCE));
+        assert!(!worker.should_trigger_milestone_solidification(
+            false,
+            true,
+            now + MILESTONE_SOLIDIFICATION_DEBOUNCE + MILESTONE_SOLIDIFICATION_DEBOUNCE / 2
+        ));
+    }
+
+    #[test]
+    fn process_outcomes() {
+        let mut worker = TransactionWorker::new(10, CacheEviction::Fifo, 244);
+        let (mut milestone_validator_worker_sender, _milestone_validator_worker_receiver) = mpsc::channel(1);
+        let epid: EndpointId = block_on(Url::from_url_str("tcp://[::1]:16000")).unwrap().into();
+
+        let mut process = |bytes: &[u8]| {
+            block_on(worker.process_transaction_brodcast(
+                epid,
+                TransactionBroadcast::new(bytes),
+                &mut milestone_validator_worker_sender,
+            ))
+        };
+
+        assert!(matches!(process(&[0; 1024]), Err(ProcessError::InsufficientWeight(_))));
+        assert_eq!(process(&[0; 1024]), Ok(ProcessOutcome::AlreadyKnown));
+    }
+
+    #[test]
+    fn outcomes_are_recorded() {
+        let metrics = ProtocolMetrics::default();
+
+        record_outcome(&metrics, &Ok(ProcessOutcome::Inserted));
+        record_outcome(&metrics, &Ok(ProcessOutcome::Duplicate));
+        record_outcome(&metrics, &Ok(ProcessOutcome::AlreadyKnown));
+        record_outcome(&metrics, &Err(ProcessError::DecodeError));
+        record_outcome(&metrics, &Err(ProcessError::InvalidTransaction));
+        record_outcome(&metrics, &Err(ProcessError::InsufficientWeight(0)));
+
+        assert_eq!(metrics.new_transactions_received(), 1);
+        assert_eq!(metrics.known_transactions_received(), 2);
+        assert_eq!(metrics.invalid_transactions_received(), 2);
+        assert_eq!(metrics.insufficient_weight_transactions_received(), 1);
     }
 }
@@ -10,23 +10,39 @@
 // See the License for the specific language governing permissions and limitations under the License.
 
 use bee_bundle::{Address, TransactionField};
-use bee_ternary::{T1B1Buf, TryteBuf};
+use bee_ternary::{T1B1Buf, T3B1Buf, TryteBuf};
 
 use std::{
     collections::HashMap,
     fs::File,
-    io::{BufRead, BufReader},
+    io::{BufRead, BufReader, Write},
 };
 
 // TODO export ?
 pub const IOTA_SUPPLY: u64 = 2_779_530_283_277_761;
 
+const ADDRESS_TRYTE_LEN: usize = 81;
+
 #[derive(Debug)]
 pub enum SnapshotStateError {
     IOError(std::io::Error),
-    InvalidAddress,
-    InvalidBalance(std::num::ParseIntError),
-    InvalidSupply(u64, u64),
+    MalformedLine {
+        line: usize,
+    },
+    InvalidAddressLength {
+        line: usize,
+        len: usize,
+    },
+    InvalidAddress {
+        line: usize,
+    },
+    InvalidBalance {
+        line: usize,
+        error: std::num::ParseIntError,
+    },
+    SupplyTooLow(u64),
+    SupplyTooHigh(u64),
+    NegativeBalance(Address),
 }
 
 pub struct SnapshotState {
@@ -36,44 +52,106 @@ pub struct SnapshotState {
 impl SnapshotState {
     pub fn new(path: &str) -> Result<Self, SnapshotStateError> {
         match File::open(path) {
-            Ok(file) => {
-                let reader = BufReader::new(file);
-                let mut supply: u64 = 0;
-                // TODO any possibility to reserve ?
-                let mut state = HashMap::new();
-
-                for line in reader.lines() {
-                    match line {
-                        Ok(line) => {
-                            let tokens: Vec<&str> = line.split(';').collect();
-                            // TODO check size of tokens
-
-                            let hash = match TryteBuf::try_from_str(&tokens[0][..tokens[0].len()]) {
-                                Ok(buf) => Address::try_from_inner(buf.as_trits().encode::<T1B1Buf>())
-                                    .map_err(|_| SnapshotStateError::InvalidAddress),
-                                Err(_) => Err(SnapshotStateError::InvalidAddress),
-                            }?;
-
-                            let balance = tokens[1][..tokens[1].len()]
-                                .parse::<u64>()
-                                .map_err(SnapshotStateError::InvalidBalance)?;
-
-                            state.insert(hash, balance);
-
-                            supply += balance;
-                        }
-                        Err(e) => return Err(SnapshotStateError::IOError(e)),
+            Ok(file) => Self::from_reader(BufReader::new(file)),
+            Err(e) => Err(SnapshotStateError::IOError(e)),
+        }
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, SnapshotStateError> {
+        let mut supply: u64 = 0;
+        // TODO any possibility to reserve ?
+        let mut state = HashMap::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            // Line numbers are reported 1-based
+            let line_number = index + 1;
+
+            match line {
+                Ok(line) => {
+                    let tokens: Vec<&str> = line.split(';').collect();
+
+                    if tokens.len() != 2 {
+                        return Err(SnapshotStateError::MalformedLine { line: line_number });
+                    }
+
+                    if tokens[0].len() != ADDRESS_TRYTE_LEN {
+                        return Err(SnapshotStateError::InvalidAddressLength {
+                            line: line_number,
+                            len: tokens[0].len(),
+                        });
                     }
-                }
 
-                if supply != IOTA_SUPPLY {
-                    return Err(SnapshotStateError::InvalidSupply(supply, IOTA_SUPPLY));
+                    let hash = match TryteBuf::try_from_str(&tokens[0][..tokens[0].len()]) {
+                        Ok(buf) => Address::try_from_inner(buf.as_trits().encode::<T1B1Buf>())
+                            .map_err(|_| SnapshotStateError::InvalidAddress { line: line_number }),
+                        Err(_) => Err(SnapshotStateError::InvalidAddress { line: line_number }),
+                    }?;
+
+                    let balance = tokens[1][..tokens[1].len()].parse::<u64>().map_err(|error| {
+                        SnapshotStateError::InvalidBalance {
+                            line: line_number,
+                            error,
+                        }
+                    })?;
+
+                    state.insert(hash, balance);
+
+                    supply = supply.saturating_add(balance);
                 }
+                Err(e) => return Err(SnapshotStateError::IOError(e)),
+            }
+        }
+
+        if supply < IOTA_SUPPLY {
+            return Err(SnapshotStateError::SupplyTooLow(supply));
+        } else if supply > IOTA_SUPPLY {
+            return Err(SnapshotStateError::SupplyTooHigh(supply));
+        }
+
+        Ok(Self { state })
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<(), SnapshotStateError> {
+        let mut entries = self.state.iter().collect::<Vec<(&Address, &u64)>>();
+
+        // Sorted by address trits, like ledger diffs, so that the output is reproducible
+        entries.sort_unstable_by_key(|(address, _)| *address);
 
-                Ok(Self { state })
+        for (address, balance) in entries {
+            let trytes = address
+                .to_inner()
+                .encode::<T3B1Buf>()
+                .as_trytes()
+                .iter()
+                .map(|tryte| char::from(*tryte))
+                .collect::<String>();
+            writeln!(writer, "{};{}", trytes, balance).map_err(SnapshotStateError::IOError)?;
+        }
+
+        writer.flush().map_err(SnapshotStateError::IOError)
+    }
+
+    pub fn apply_diff(&mut self, diff: &HashMap<Address, i64>) -> Result<(), SnapshotStateError> {
+        // Balances are all computed before any of them is written so that a failing diff leaves the state untouched
+        let mut balances = Vec::with_capacity(diff.len());
+
+        for (address, delta) in diff {
+            let balance = i128::from(self.state.get(address).copied().unwrap_or(0)) + i128::from(*delta);
+
+            if balance < 0 {
+                return Err(SnapshotStateError::NegativeBalance(address.clone()));
+            }
+
+            if balance > 0 || self.state.contains_key(address) {
+                balances.push((address, balance as u64));
             }
-            Err(e) => Err(SnapshotStateError::IOError(e)),
         }
+
+        for (address, balance) in balances {
+            self.state.insert(address.clone(), balance);
+        }
+
+        Ok(())
     }
 
     pub fn state(&self) -> &HashMap<Address, u64> {
@@ -84,3 +162,201 @@ impl SnapshotState {
         self.state
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::io::Cursor;
+
+    const ADDRESS_A: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+    const ADDRESS_B: &str = "BBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBB";
+
+    #[test]
+    fn from_reader_valid() {
+        let lines = format!("{};1000\n{};{}\n", ADDRESS_A, ADDRESS_B, IOTA_SUPPLY - 1000);
+
+        let state = SnapshotState::from_reader(Cursor::new(lines)).unwrap().into_state();
+
+        assert_eq!(state.len(), 2);
+        assert_eq!(state.values().sum::<u64>(), IOTA_SUPPLY);
+    }
+
+    #[test]
+    fn from_reader_invalid_address() {
+        let lines = format!("{};1000\na{};{}\n", ADDRESS_A, &ADDRESS_B[1..], IOTA_SUPPLY - 1000);
+
+        match SnapshotState::from_reader(Cursor::new(lines)) {
+            Err(SnapshotStateError::InvalidAddress { line }) => assert_eq!(line, 2),
+            _ => panic!("Expected an invalid address error."),
+        }
+    }
+
+    #[test]
+    fn from_reader_invalid_address_length() {
+        let lines = format!("{};1000\n{};{}\n", ADDRESS_A, &ADDRESS_B[..80], IOTA_SUPPLY - 1000);
+
+        match SnapshotState::from_reader(Cursor::new(lines)) {
+            Err(SnapshotStateError::InvalidAddressLength { line, len }) => {
+                assert_eq!(line, 2);
+                assert_eq!(len, 80);
+            }
+            _ => panic!("Expected an invalid address length error."),
+        }
+    }
+
+    #[test]
+    fn from_reader_malformed_line() {
+        let lines = format!("{};1000;0\n", ADDRESS_A);
+
+        match SnapshotState::from_reader(Cursor::new(lines)) {
+            Err(SnapshotStateError::MalformedLine { line }) => assert_eq!(line, 1),
+            _ => panic!("Expected a malformed line error."),
+        }
+    }
+
+    #[test]
+    fn from_reader_invalid_balance() {
+        let lines = format!("{};-1000\n", ADDRESS_A);
+
+        match SnapshotState::from_reader(Cursor::new(lines)) {
+            Err(SnapshotStateError::InvalidBalance { line, .. }) => assert_eq!(line, 1),
+            _ => panic!("Expected an invalid balance error."),
+        }
+    }
+
+    #[test]
+    fn from_reader_supply_too_low() {
+        let lines = format!("{};1000\n", ADDRESS_A);
+
+        match SnapshotState::from_reader(Cursor::new(lines)) {
+            Err(SnapshotStateError::SupplyTooLow(supply)) => assert_eq!(supply, 1000),
+            _ => panic!("Expected a supply too low error."),
+        }
+    }
+
+    #[test]
+    fn from_reader_supply_too_high() {
+        let lines = format!("{};1000\n{};{}\n", ADDRESS_A, ADDRESS_B, IOTA_SUPPLY);
+
+        match SnapshotState::from_reader(Cursor::new(lines)) {
+            Err(SnapshotStateError::SupplyTooHigh(supply)) => assert_eq!(supply, IOTA_SUPPLY + 1000),
+            _ => panic!("Expected a supply too high error."),
+        }
+    }
+
+    fn address(trytes: &str) -> Address {
+        Address::try_from_inner(TryteBuf::try_from_str(trytes).unwrap().as_trits().encode::<T1B1Buf>()).unwrap()
+    }
+
+    #[test]
+    fn apply_diff_valid() {
+        let lines = format!("{};1000\n{};{}\n", ADDRESS_A, ADDRESS_B, IOTA_SUPPLY - 1000);
+        let mut state = SnapshotState::from_reader(Cursor::new(lines)).unwrap();
+        let address_c = address(&ADDRESS_A.replace('A', "C"));
+
+        let mut diff = HashMap::new();
+        diff.insert(address(ADDRESS_A), -1000);
+        diff.insert(address(ADDRESS_B), -500);
+        diff.insert(address_c.clone(), 1500);
+
+        state.apply_diff(&diff).unwrap();
+
+        assert_eq!(state.state().get(&address(ADDRESS_A)), Some(&0));
+        assert_eq!(state.state().get(&address(ADDRESS_B)), Some(&(IOTA_SUPPLY - 1500)));
+        assert_eq!(state.state().get(&address_c), Some(&1500));
+        assert_eq!(state.state().values().sum::<u64>(), IOTA_SUPPLY);
+    }
+
+    #[test]
+    fn apply_diff_negative_balance() {
+        let lines = format!("{};1000\n{};{}\n", ADDRESS_A, ADDRESS_B, IOTA_SUPPLY - 1000);
+        let mut state = SnapshotState::from_reader(Cursor::new(lines)).unwrap();
+
+        let mut diff = HashMap::new();
+        diff.insert(address(ADDRESS_A), -1001);
+
+        match state.apply_diff(&diff) {
+            Err(SnapshotStateError::NegativeBalance(negative)) => assert_eq!(negative, address(ADDRESS_A)),
+            _ => panic!("Expected a negative balance error."),
+        }
+        assert_eq!(state.state().get(&address(ADDRESS_A)), Some(&1000));
+    }
+
+    #[test]
+    fn write_to_from_reader_round_trip() {
+        let lines = format!("{};{}\n{};1000\n", ADDRESS_B, IOTA_SUPPLY - 1000, ADDRESS_A);
+        let state = SnapshotState::from_reader(Cursor::new(lines)).unwrap();
+
+        let mut written = Vec::new();
+        state.write_to(&mut written).unwrap();
+
+        assert_eq!(
+            String::from_utf8(written.clone()).unwrap(),
+            format!("{};{}\n{};1000\n", ADDRESS_B, IOTA_SUPPLY - 1000, ADDRESS_A)
+        );
+        assert_eq!(
+            SnapshotState::from_reader(Cursor::new(written)).unwrap().into_state(),
+            state.into_state()
+        );
+    }
+
+    #[test]
+    fn write_to_sorted_by_address_trits() {
+        let address = |c: char| ADDRESS_A.replace('A', &c.to_string());
+        let lines = format!(
+            "{};{}\n{};1000\n{};1000\n",
+            address('A'),
+            IOTA_SUPPLY - 2000,
+            address('9'),
+            address('Z')
+        );
+        let state = SnapshotState::from_reader(Cursor::new(lines)).unwrap();
+
+        // Z, 9 and A start with the trits -1, 0 and 1, which is the reverse of their tryte order
+        assert_eq!(
+            String::from_utf8(written(&state)).unwrap(),
+            format!(
+                "{};1000\n{};1000\n{};{}\n",
+                address('Z'),
+                address('9'),
+                address('A'),
+                IOTA_SUPPLY - 2000
+            )
+        );
+    }
+
+    fn written(state: &SnapshotState) -> Vec<u8> {
+        let mut written = Vec::new();
+        state.write_to(&mut written).unwrap();
+        written
+    }
+
+    #[test]
+    fn write_to_is_deterministic() {
+        let addresses: Vec<String> = "ABCDEFGHIJKLMNOPQRSTUVWXYZ9"
+            .chars()
+            .map(|c| ADDRESS_A.replace('A', &c.to_string()))
+            .collect();
+        let lines: Vec<String> = addresses
+            .iter()
+            .enumerate()
+            .map(|(i, address)| {
+                let balance = if i == 0 {
+                    IOTA_SUPPLY - 1000 * (addresses.len() as u64 - 1)
+                } else {
+                    1000
+                };
+                format!("{};{}\n", address, balance)
+            })
+            .collect();
+
+        let state = SnapshotState::from_reader(Cursor::new(lines.concat())).unwrap();
+        let reversed =
+            SnapshotState::from_reader(Cursor::new(lines.iter().rev().cloned().collect::<String>())).unwrap();
+
+        assert_eq!(written(&state), written(&state));
+        assert_eq!(written(&state), written(&reversed));
+    }
+}
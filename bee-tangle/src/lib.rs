@@ -15,8 +15,8 @@
 #![allow(dead_code, unused_imports, unused_variables)]
 
 pub use milestone::MilestoneIndex;
-pub use tangle::Tangle;
-pub use vertex::TransactionRef;
+pub use tangle::{EdgeKind, Tangle};
+pub use vertex::{TransactionRef, WeakTransactionRef};
 
 mod milestone;
 mod solidifier;
@@ -40,12 +40,17 @@ use std::{
 static TANGLE: AtomicPtr<Tangle> = AtomicPtr::new(ptr::null_mut());
 static INITIALIZED: AtomicBool = AtomicBool::new(false);
 
-const SOLIDIFIER_CHAN_CAPACITY: usize = 1000;
+const DEFAULT_SOLIDIFIER_CHAN_CAPACITY: usize = 1000;
 
 /// Initializes the Tangle singleton.
 pub fn init() {
+    init_with_solidifier_capacity(DEFAULT_SOLIDIFIER_CHAN_CAPACITY);
+}
+
+/// Initializes the Tangle singleton with a solidifier channel holding at most `capacity` pending notifications.
+pub fn init_with_solidifier_capacity(capacity: usize) {
     if !INITIALIZED.compare_and_swap(false, true, Ordering::Relaxed) {
-        let (sender, receiver) = flume::bounded::<Option<Hash>>(SOLIDIFIER_CHAN_CAPACITY);
+        let (sender, receiver) = flume::bounded::<Option<Hash>>(capacity);
 
         let drop_barrier = async_std::sync::Arc::new(Barrier::new(2));
 
@@ -98,6 +103,14 @@ mod tests {
         drop();
     }
 
+    #[test]
+    #[serial]
+    fn init_with_solidifier_capacity_get_and_drop() {
+        init_with_solidifier_capacity(1);
+        let _ = tangle();
+        drop();
+    }
+
     #[test]
     #[should_panic]
     #[serial]
@@ -44,8 +44,7 @@ impl SolidifierState {
             if !already_solid.contains(&hash) {
                 if let Some(v) = tangle().vertices.get(&hash).map(|r| r.value().get_ref_to_inner()) {
                     if tangle().is_solid_transaction(v.trunk()) && tangle().is_solid_transaction(v.branch()) {
-                        // NOTE: unwrap should be safe since we just added it to the Tangle
-                        tangle().vertices.get_mut(&hash).unwrap().set_solid();
+                        tangle().mark_solid(&hash);
                         already_solid.insert(hash);
 
                         if let Some(approvers) = tangle().approvers.get(&hash) {
@@ -64,6 +63,7 @@ impl SolidifierState {
     pub async fn run(mut self) {
         while let Ok(hash) = self.solidifier_recv.recv_async().await {
             if let Some(hash) = hash {
+                tangle().pending_solidifications.remove(&hash);
                 self.propagate(hash);
             } else {
                 self.drop_barrier.wait().await;
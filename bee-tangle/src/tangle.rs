@@ -16,21 +16,48 @@ use crate::{
     vertex::{TransactionRef, Vertex},
 };
 
-use bee_bundle::{Hash, Transaction};
+use bee_bundle::{
+    Address, Hash, Transaction, TransactionField, ADDRESS_TRIT_LEN, NONCE_TRIT_LEN, PAYLOAD_TRIT_LEN, TAG_TRIT_LEN,
+};
 
 use std::{
-    collections::HashSet,
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    mem::size_of,
     sync::atomic::{AtomicU32, Ordering},
 };
 
 use async_std::{
     sync::{Arc, Barrier},
-    task::block_on,
+    task::{block_on, yield_now},
 };
 
 use dashmap::{mapref::entry::Entry, DashMap, DashSet};
 
-use flume::Sender;
+use flume::{Sender, TrySendError};
+use log::warn;
+
+/// The kind of edge linking an approver to the transaction it approves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeKind {
+    /// The approver references the transaction through its trunk.
+    Trunk,
+    /// The approver references the transaction through its branch.
+    Branch,
+    /// The approver references the transaction through both its trunk and its branch.
+    Both,
+}
+
+// Approximate number of bytes used by a vertex: its key, the vertex itself, the shared transaction with its reference
+// counts, and the heap-allocated trit fields of the transaction, one byte per trit.
+const VERTEX_SIZE: usize = size_of::<Hash>()
+    + size_of::<Vertex>()
+    + 2 * size_of::<usize>()
+    + size_of::<Transaction>()
+    + PAYLOAD_TRIT_LEN
+    + ADDRESS_TRIT_LEN
+    + 2 * TAG_TRIT_LEN
+    + NONCE_TRIT_LEN;
 
 /// A datastructure based on a directed acyclic graph (DAG).
 pub struct Tangle {
@@ -49,6 +76,12 @@ pub struct Tangle {
     /// The sender side of a channel between the Tangle and the (gossip) solidifier.
     solidifier_send: Sender<Option<Hash>>,
 
+    /// A set of hashes that were sent to the solidifier but not yet received by it.
+    pub(crate) pending_solidifications: DashSet<Hash>,
+
+    solid_count: AtomicU32,
+    unsolid_count: AtomicU32,
+
     solid_milestone_index: AtomicU32,
     snapshot_milestone_index: AtomicU32,
     last_milestone_index: AtomicU32,
@@ -63,8 +96,11 @@ impl Tangle {
             vertices: DashMap::new(),
             approvers: DashMap::new(),
             solidifier_send,
+            pending_solidifications: DashSet::new(),
             solid_entry_points: DashSet::new(),
             milestones: DashMap::new(),
+            solid_count: AtomicU32::new(0),
+            unsolid_count: AtomicU32::new(0),
             solid_milestone_index: AtomicU32::new(0),
             snapshot_milestone_index: AtomicU32::new(0),
             last_milestone_index: AtomicU32::new(0),
@@ -77,43 +113,184 @@ impl Tangle {
     /// Note: The method assumes that `hash` -> `transaction` is injective, otherwise unexpected behavior could
     /// occur.
     pub async fn insert_transaction(&'static self, transaction: Transaction, hash: Hash) -> Option<TransactionRef> {
-        match self.approvers.entry(*transaction.trunk()) {
+        self.add_approver(transaction.trunk(), &hash);
+        self.add_approver(transaction.branch(), &hash);
+
+        let vertex = Vertex::from(transaction, hash);
+
+        let tx_ref = vertex.get_ref_to_inner();
+
+        // NOTE: the new vertex is counted before being inserted so that it can't be marked solid uncounted.
+        self.unsolid_count.fetch_add(1, Ordering::Relaxed);
+
+        // TODO: not sure if we want replacement of vertices
+        let replaced = self.vertices.insert(hash, vertex);
+
+        if let Some(replaced) = &replaced {
+            self.uncount_vertex(replaced);
+        }
+
+        if replaced.is_none() {
+            let mut notified = self.notify_solidifier(hash);
+
+            if let Err(TrySendError::Full(_)) = notified {
+                // Gives a stalled solidifier a chance to catch up before retrying once.
+                yield_now().await;
+                notified = self.notify_solidifier(hash);
+            }
+
+            if let Err(e) = notified {
+                warn!("[Tangle ] Notifying the solidifier about {} failed: {:?}.", hash, e);
+            }
+
+            Some(tx_ref)
+        } else {
+            None
+        }
+    }
+
+    // Sends `hash` to the solidifier, unless a notification for it is already pending, in which case the solidifier
+    // is going to look at it anyway.
+    fn notify_solidifier(&self, hash: Hash) -> Result<(), TrySendError<()>> {
+        if !self.pending_solidifications.insert(hash) {
+            return Ok(());
+        }
+
+        self.solidifier_send
+            .try_send(Some(hash))
+            .inspect_err(|_| {
+                self.pending_solidifications.remove(&hash);
+            })
+            .map_err(|e| match e {
+                TrySendError::Full(_) => TrySendError::Full(()),
+                TrySendError::Disconnected(_) => TrySendError::Disconnected(()),
+            })
+    }
+
+    /// Removes a transaction from the Tangle, and returns it if it was present.
+    ///
+    /// The hash of the removed transaction is also removed from the approver lists of its trunk and branch. The
+    /// approvers of the removed transaction are left untouched, so it is henceforth treated like any other
+    /// transaction that is referenced but missing.
+    pub fn remove_transaction(&'static self, hash: &Hash) -> Option<TransactionRef> {
+        let (_, vertex) = self.vertices.remove(hash)?;
+        let transaction = vertex.get_ref_to_inner();
+
+        self.uncount_vertex(&vertex);
+
+        self.remove_approver(transaction.trunk(), hash);
+
+        if transaction.trunk() != transaction.branch() {
+            self.remove_approver(transaction.branch(), hash);
+        }
+
+        Some(transaction)
+    }
+
+    fn uncount_vertex(&self, vertex: &Vertex) {
+        if vertex.is_solid() {
+            self.solid_count.fetch_sub(1, Ordering::Relaxed);
+        } else {
+            self.unsolid_count.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    fn add_approver(&'static self, approvee: &Hash, approver: &Hash) {
+        // NOTE: approver lists are short, so a linear check is enough to keep each approver listed once, even when
+        // trunk and branch are the same or a transaction is inserted again.
+        match self.approvers.entry(*approvee) {
             Entry::Occupied(mut entry) => {
                 let values = entry.get_mut();
-                values.push(hash);
+                if !values.contains(approver) {
+                    values.push(*approver);
+                }
             }
             Entry::Vacant(entry) => {
-                entry.insert(vec![hash]);
+                entry.insert(vec![*approver]);
             }
         }
+    }
 
-        if transaction.trunk() != transaction.branch() {
-            match self.approvers.entry(*transaction.branch()) {
-                Entry::Occupied(mut entry) => {
-                    let values = entry.get_mut();
-                    values.push(hash);
-                }
-                Entry::Vacant(entry) => {
-                    entry.insert(vec![hash]);
-                }
+    fn remove_approver(&'static self, approvee: &Hash, approver: &Hash) {
+        // NOTE: going through the entry API keeps the shard locked for the whole update, so concurrent insertions
+        // can't be lost.
+        if let Entry::Occupied(mut entry) = self.approvers.entry(*approvee) {
+            entry.get_mut().retain(|hash| hash != approver);
+
+            if entry.get().is_empty() {
+                entry.remove();
             }
         }
+    }
 
-        let vertex = Vertex::from(transaction, hash);
+    /// Removes all transactions of the cone of the milestone at `index` that are not reachable from a more recent
+    /// milestone.
+    ///
+    /// Walks from more recent milestones stop at transactions confirmed by `index` or an older milestone, as those
+    /// belong to the pruned cones.
+    pub fn prune_below_milestone(&'static self, index: MilestoneIndex) {
+        let root = match self.get_milestone_hash(index) {
+            Some(hash) => hash,
+            None => return,
+        };
+
+        let pruned = self.collect_approvees(root, |_| true);
+
+        // NOTE: collected first so that no reference into `milestones` is held while walking.
+        let recent_roots = self
+            .milestones
+            .iter()
+            .filter(|entry| *entry.key() > index)
+            .map(|entry| *entry.value())
+            .collect::<Vec<Hash>>();
+
+        let mut reachable = HashSet::new();
+
+        for recent_root in recent_roots {
+            reachable.extend(self.collect_approvees(recent_root, |vertex| {
+                vertex
+                    .get_confirmation_index()
+                    .is_none_or(|confirmed| confirmed > index)
+            }));
+        }
 
-        let tx_ref = vertex.get_ref_to_inner();
+        for hash in pruned.difference(&reachable) {
+            self.remove_transaction(hash);
+        }
 
-        // TODO: not sure if we want replacement of vertices
-        if self.vertices.insert(hash, vertex).is_none() {
-            match self.solidifier_send.send(Some(hash)) {
-                Ok(()) => (),
-                Err(e) => todo!("log warning"),
-            }
+        self.milestones
+            .retain(|milestone_index, hash| *milestone_index > index || self.vertices.contains_key(hash));
+    }
 
-            Some(tx_ref)
-        } else {
-            None
+    /// Collects the hashes of all transactions present in the Tangle that are approved, directly or indirectly, by
+    /// `root` (including `root` itself) and satisfy `filter`. The walk doesn't go past transactions that don't.
+    fn collect_approvees<Filter>(&'static self, root: Hash, filter: Filter) -> HashSet<Hash>
+    where
+        Filter: Fn(&Vertex) -> bool,
+    {
+        let mut non_analyzed_hashes = vec![root];
+        let mut analyzed_hashes = HashSet::new();
+        let mut collected = HashSet::new();
+
+        while let Some(hash) = non_analyzed_hashes.pop() {
+            if analyzed_hashes.insert(hash) {
+                if let Some(vertex) = self.vertices.get(&hash) {
+                    let vertex = vertex.value();
+
+                    if filter(vertex) {
+                        let transaction = vertex.get_ref_to_inner();
+
+                        if transaction.branch() != transaction.trunk() {
+                            non_analyzed_hashes.push(*transaction.branch());
+                        }
+                        non_analyzed_hashes.push(*transaction.trunk());
+                        collected.insert(hash);
+                    }
+                }
+            }
         }
+
+        collected
     }
 
     pub(crate) fn shutdown(&self) {
@@ -127,6 +304,80 @@ impl Tangle {
         self.vertices.get(hash).map(|v| v.get_ref_to_inner())
     }
 
+    /// Returns references to the transactions associated with `hashes`, in the same order, with `None` for those that
+    /// are not available in the local Tangle.
+    pub fn get_transactions(&self, hashes: &[Hash]) -> Vec<Option<TransactionRef>> {
+        hashes
+            .iter()
+            .map(|hash| self.vertices.get(hash).map(|v| v.get_ref_to_inner()))
+            .collect()
+    }
+
+    /// Returns the transactions of the bundle starting at `tail`, from tail to head, if they are all available in the
+    /// local Tangle and consistently linked through their trunks.
+    pub fn get_bundle(&self, tail: &Hash) -> Option<Vec<TransactionRef>> {
+        let transaction = self.vertices.get(tail)?.get_ref_to_inner();
+
+        if !transaction.is_tail() {
+            return None;
+        }
+
+        let last_index = *transaction.last_index().to_inner();
+        let mut bundle = vec![transaction];
+
+        while bundle.len() <= last_index {
+            let previous = &bundle[bundle.len() - 1];
+            let transaction = self.vertices.get(previous.trunk())?.get_ref_to_inner();
+
+            if transaction.bundle() != previous.bundle() || *transaction.index().to_inner() != bundle.len() {
+                return None;
+            }
+
+            bundle.push(transaction);
+        }
+
+        Some(bundle)
+    }
+
+    /// Returns the net balance changes per address of the bundle starting at `tail`, if it is complete in the local
+    /// Tangle. Addresses of zero-valued transactions are left out, as in `Bundle::ledger_diff`.
+    ///
+    /// A bundle whose tail has been marked with `set_conflicting` is excluded from the ledger, so its diff is empty.
+    pub fn get_bundle_diff(&self, tail: Hash) -> Option<HashMap<Address, i64>> {
+        let mut diff = HashMap::new();
+        let bundle = self.get_bundle(&tail)?;
+
+        if self.is_conflicting(&tail) {
+            return Some(diff);
+        }
+
+        for transaction in bundle {
+            let value = *transaction.value().to_inner();
+
+            if value != 0 {
+                *diff.entry(transaction.address().clone()).or_insert(0) += value;
+            }
+        }
+
+        Some(diff)
+    }
+
+    /// Marks the transaction associated with `hash` as conflicting, i.e. confirmed but excluded from the ledger because
+    /// applying its bundle would overspend. Tagging the tail marks the whole bundle.
+    pub fn set_conflicting(&self, hash: &Hash) {
+        if let Some(mut vertex) = self.vertices.get_mut(hash) {
+            vertex.set_conflicting();
+        }
+    }
+
+    /// Returns whether the transaction associated with `hash` has been marked as conflicting.
+    pub fn is_conflicting(&self, hash: &Hash) -> bool {
+        self.vertices
+            .get(hash)
+            .map(|r| r.value().is_conflicting())
+            .unwrap_or(false)
+    }
+
     /// Returns whether the transaction is stored in the Tangle.
     pub fn contains_transaction(&'static self, hash: &Hash) -> bool {
         self.vertices.contains_key(hash)
@@ -145,6 +396,85 @@ impl Tangle {
         }
     }
 
+    /// Marks the transaction associated with `hash` as solid, and returns whether its solidity changed.
+    ///
+    /// The approvers of a newly solid transaction are sent to the solidifier so that solidity keeps propagating.
+    pub fn set_solid(&self, hash: &Hash) -> bool {
+        let changed = self.mark_solid(hash);
+
+        if changed {
+            // NOTE: cloned so that no reference into `approvers` is held while sending.
+            if let Some(approvers) = self.approvers.get(hash).map(|r| r.value().clone()) {
+                for approver in approvers {
+                    if self.vertices.get(&approver).is_some_and(|r| r.value().is_solid()) {
+                        continue;
+                    }
+
+                    if let Err(e) = self.notify_solidifier(approver) {
+                        warn!("[Tangle ] Notifying the solidifier about {} failed: {:?}.", approver, e);
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Sets the solid flag of the vertex associated with `hash` without notifying the solidifier, and returns
+    /// whether its solidity changed.
+    pub(crate) fn mark_solid(&self, hash: &Hash) -> bool {
+        match self.vertices.get_mut(hash) {
+            Some(mut vertex) if !vertex.is_solid() => {
+                vertex.set_solid();
+                self.solid_count.fetch_add(1, Ordering::Relaxed);
+                self.unsolid_count.fetch_sub(1, Ordering::Relaxed);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the number of solid and unsolid transactions stored in the Tangle, in that order.
+    pub fn solidity_counts(&self) -> (u32, u32) {
+        (
+            self.solid_count.load(Ordering::Relaxed),
+            self.unsolid_count.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Marks the transaction associated with `hash` as confirmed by the milestone at `index`.
+    pub fn confirm_transaction(&'static self, hash: Hash, index: MilestoneIndex) {
+        if let Some(mut vertex) = self.vertices.get_mut(&hash) {
+            vertex.set_confirmation_index(index);
+        }
+    }
+
+    /// Returns whether the transaction associated with `hash` is referenced by a milestone, i.e. belongs to the past
+    /// cone of a milestone.
+    ///
+    /// This is the case once it has been marked with `confirm_transaction`, directly or through `confirm_cone`, no
+    /// matter the index of the milestone that confirmed it.
+    pub fn is_referenced(&self, hash: &Hash) -> bool {
+        self.vertices
+            .get(hash)
+            .map(|r| r.value().get_confirmation_index().is_some())
+            .unwrap_or(false)
+    }
+
+    /// Marks all transactions of the cone of `root` that are available in the local Tangle and not yet confirmed as
+    /// confirmed by the milestone at `index`, and returns their number.
+    ///
+    /// The walk stops at already confirmed transactions, as their cones have been confirmed by an older milestone.
+    pub fn confirm_cone(&'static self, root: Hash, index: MilestoneIndex) -> usize {
+        let unconfirmed = self.collect_approvees(root, |vertex| vertex.get_confirmation_index().is_none());
+
+        for hash in &unconfirmed {
+            self.confirm_transaction(*hash, index);
+        }
+
+        unconfirmed.len()
+    }
+
     /// Adds the `hash` of a milestone identified by its milestone `index`.
     pub fn add_milestone(&'static self, index: MilestoneIndex, hash: Hash) {
         self.milestones.insert(index, hash);
@@ -166,9 +496,14 @@ impl Tangle {
         }
     }
 
-    /// Returns a [`VertexRef`] linked to the specified milestone, if it's available in the local Tangle.
+    /// Returns a [`TransactionRef`] linked to the last milestone, if it's available in the local Tangle.
     pub fn get_latest_milestone(&'static self) -> Option<TransactionRef> {
-        todo!("get the last milestone index, get the transaction hash from it, and query the Tangle for it")
+        self.get_milestone(self.get_last_milestone_index())
+    }
+
+    /// Returns a [`TransactionRef`] linked to the solid milestone, if it's available in the local Tangle.
+    pub fn get_latest_solid_milestone(&'static self) -> Option<TransactionRef> {
+        self.get_milestone(self.get_solid_milestone_index())
     }
 
     /// Returns the hash of a milestone.
@@ -239,6 +574,46 @@ impl Tangle {
         self.vertices.len()
     }
 
+    /// Returns an approximation of the number of bytes used by the Tangle to store its vertices, approvers, milestones
+    /// and solid entry points.
+    ///
+    /// The estimate ignores the overhead of the underlying maps and is only meant for capacity planning.
+    pub fn estimated_memory_bytes(&self) -> usize {
+        let approver_edges: usize = self.approvers.iter().map(|entry| entry.value().len()).sum();
+
+        self.vertices.len() * VERTEX_SIZE
+            + self.approvers.len() * (size_of::<Hash>() + size_of::<Vec<Hash>>())
+            + approver_edges * size_of::<Hash>()
+            + self.milestones.len() * (size_of::<MilestoneIndex>() + size_of::<Hash>())
+            + self.solid_entry_points.len() * size_of::<Hash>()
+    }
+
+    /// Returns the approvers of the transaction identified by `hash` along with the kind of edge linking them to it.
+    ///
+    /// Approvers that are not (or no longer) part of the Tangle are skipped.
+    pub fn approver_edges(&self, hash: &Hash) -> Vec<(Hash, EdgeKind)> {
+        let mut edges = Vec::new();
+
+        if let Some(approvers_ref) = self.approvers.get(hash) {
+            for approver_hash in approvers_ref.value() {
+                if let Some(approver_ref) = self.vertices.get(approver_hash) {
+                    let approver = approver_ref.value().get_ref_to_inner();
+
+                    let kind = match (approver.trunk() == hash, approver.branch() == hash) {
+                        (true, true) => EdgeKind::Both,
+                        (true, false) => EdgeKind::Trunk,
+                        (false, true) => EdgeKind::Branch,
+                        (false, false) => continue,
+                    };
+
+                    edges.push((*approver_hash, kind));
+                }
+            }
+        }
+
+        edges
+    }
+
     /// Starts a walk beginning at a `start` vertex identified by its associated transaction hash
     /// traversing its children/approvers for as long as those satisfy a given `filter`.
     ///
@@ -338,7 +713,10 @@ impl Tangle {
                         map(&transaction);
 
                         if should_follow(vertex) {
-                            non_analyzed_hashes.push(*transaction.branch());
+                            // NOTE: a transaction whose trunk is its branch only has one approvee to visit.
+                            if transaction.branch() != transaction.trunk() {
+                                non_analyzed_hashes.push(*transaction.branch());
+                            }
                             non_analyzed_hashes.push(*transaction.trunk());
                         }
                     }
@@ -353,7 +731,33 @@ impl Tangle {
         }
     }
 
+    /// Returns the number of solid transactions and the total number of transactions in the cone of `root`.
+    ///
+    /// Missing transactions that are not solid entry points are counted as non-solid, solid entry points are not
+    /// counted at all.
+    pub fn cone_solid_ratio(&'static self, root: Hash) -> (usize, usize) {
+        let solid_count = Cell::new(0);
+        let total_count = Cell::new(0);
+
+        self.walk_approvees_depth_first(
+            root,
+            |_| total_count.set(total_count.get() + 1),
+            |vertex| {
+                if vertex.is_solid() {
+                    solid_count.set(solid_count.get() + 1);
+                }
+                true
+            },
+            |_| total_count.set(total_count.get() + 1),
+        );
+
+        (solid_count.get(), total_count.get())
+    }
+
     /// Walks all approvers in a post order DFS way through trunk then branch.
+    ///
+    /// Vertices for which `should_follow` returns `false` (e.g. transactions already confirmed by a previous
+    /// milestone) are neither mapped nor traversed.
     pub fn walk_approvers_post_order_dfs<Mapping, Follow, Missing>(
         &'static self,
         root: Hash,
@@ -376,15 +780,17 @@ impl Tangle {
                     let vertex = vertex.value();
                     let transaction = vertex.get_ref_to_inner();
 
-                    // TODO add follow
-                    if analyzed_hashes.contains(transaction.trunk()) && analyzed_hashes.contains(transaction.branch()) {
+                    if !should_follow(vertex) {
+                        analyzed_hashes.insert(*hash);
+                        non_analyzed_hashes.pop();
+                    } else if analyzed_hashes.contains(transaction.trunk())
+                        && analyzed_hashes.contains(transaction.branch())
+                    {
                         map(hash, &transaction);
                         analyzed_hashes.insert(hash.clone());
                         non_analyzed_hashes.pop();
-                    // TODO add follow
                     } else if !analyzed_hashes.contains(transaction.trunk()) {
                         non_analyzed_hashes.push(*transaction.trunk());
-                    // TODO add follow
                     } else if !analyzed_hashes.contains(transaction.branch()) {
                         non_analyzed_hashes.push(*transaction.branch());
                     }
@@ -413,8 +819,10 @@ mod tests {
 
     use bee_bundle::{TransactionField, Value};
     use bee_test::{
-        field::rand_trits_field,
-        transaction::{create_random_attached_tx, create_random_tx},
+        field::{rand_trits_field, rand_value_address},
+        transaction::{
+            clone_tx, create_random_attached_tx, create_random_bundle, create_random_tx, create_random_value_bundle,
+        },
     };
 
     use async_std::{sync::channel, task::block_on};
@@ -435,6 +843,131 @@ mod tests {
         drop();
     }
 
+    #[test]
+    fn insert_with_full_solidifier_channel() {
+        let (sender, receiver) = flume::bounded(1);
+        let tangle: &'static Tangle = Box::leak(Tangle::new(sender, Arc::new(Barrier::new(1))).into());
+
+        let (a_hash, a) = create_random_tx();
+        let (b_hash, b) = create_random_tx();
+
+        assert!(block_on(tangle.insert_transaction(a, a_hash)).is_some());
+        assert!(block_on(tangle.insert_transaction(b, b_hash)).is_some());
+
+        assert_eq!(receiver.try_recv().unwrap(), Some(a_hash));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn insert_with_disconnected_solidifier() {
+        let (sender, receiver) = flume::bounded(1);
+        std::mem::drop(receiver);
+        let tangle: &'static Tangle = Box::leak(Tangle::new(sender, Arc::new(Barrier::new(1))).into());
+
+        let (hash, transaction) = create_random_tx();
+
+        assert!(block_on(tangle.insert_transaction(transaction, hash)).is_some());
+        assert!(tangle.contains_transaction(&hash));
+    }
+
+    #[test]
+    fn solidifier_notifications_are_coalesced() {
+        let (sender, receiver) = flume::bounded(10);
+        let tangle: &'static Tangle = Box::leak(Tangle::new(sender, Arc::new(Barrier::new(1))).into());
+
+        // a <- b, a <- c, (b, c) <- d
+        let (a_hash, a) = create_random_tx();
+        let (b_hash, b) = create_random_attached_tx(rand_trits_field::<Hash>(), a_hash);
+        let (c_hash, c) = create_random_attached_tx(rand_trits_field::<Hash>(), a_hash);
+        let (d_hash, d) = create_random_attached_tx(b_hash, c_hash);
+
+        block_on(async {
+            tangle.insert_transaction(a, a_hash).await;
+            tangle.insert_transaction(b, b_hash).await;
+            tangle.insert_transaction(c, c_hash).await;
+            tangle.insert_transaction(d, d_hash).await;
+        });
+
+        // While `b` and `c` are still pending from their insertion, `a` becoming solid doesn't send them again.
+        tangle.set_solid(&a_hash);
+
+        // Stands in for the solidifier, which clears the pending flag of each hash it receives.
+        let drain = || {
+            let hashes: Vec<Hash> = receiver.try_iter().map(Option::unwrap).collect();
+            for hash in hashes.iter() {
+                tangle.pending_solidifications.remove(hash);
+            }
+            hashes
+        };
+
+        assert_eq!(drain(), vec![a_hash, b_hash, c_hash, d_hash]);
+
+        // `d` is only sent once although both of its approvees become solid.
+        tangle.set_solid(&b_hash);
+        tangle.set_solid(&c_hash);
+        assert_eq!(drain(), vec![d_hash]);
+
+        // Approvers that are already solid are not sent.
+        let (e_hash, e) = create_random_attached_tx(d_hash, d_hash);
+        block_on(tangle.insert_transaction(e, e_hash));
+        assert_eq!(drain(), vec![e_hash]);
+        tangle.mark_solid(&e_hash);
+        tangle.set_solid(&d_hash);
+        assert!(drain().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn solidity_counts() {
+        init();
+        let tangle = tangle();
+
+        let (a_hash, a) = create_random_tx();
+        let (b_hash, b) = create_random_tx();
+        let (c_hash, c) = create_random_tx();
+
+        block_on(async {
+            tangle.insert_transaction(a, a_hash).await;
+            tangle.insert_transaction(b, b_hash).await;
+            tangle.insert_transaction(c, c_hash).await;
+        });
+
+        assert_eq!(tangle.solidity_counts(), (0, 3));
+
+        tangle.set_solid(&a_hash);
+        tangle.set_solid(&b_hash);
+        tangle.set_solid(&b_hash);
+
+        assert_eq!(tangle.solidity_counts(), (2, 1));
+
+        tangle.remove_transaction(&a_hash);
+        assert_eq!(tangle.solidity_counts(), (1, 1));
+
+        tangle.remove_transaction(&c_hash);
+        assert_eq!(tangle.solidity_counts(), (1, 0));
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn set_solid_and_is_solid() {
+        init();
+        let tangle = tangle();
+
+        let (hash, transaction) = create_random_tx();
+
+        block_on(tangle.insert_transaction(transaction, hash));
+        assert!(!tangle.is_solid_transaction(&hash));
+
+        assert!(tangle.set_solid(&hash));
+        assert!(tangle.is_solid_transaction(&hash));
+
+        assert!(!tangle.set_solid(&hash));
+
+        drop();
+    }
+
     #[test]
     #[serial]
     fn update_and_get_snapshot_milestone_index() {
@@ -471,6 +1004,180 @@ mod tests {
         drop();
     }
 
+    #[test]
+    #[serial]
+    fn get_latest_and_latest_solid_milestones() {
+        init();
+        let (Transactions { d, e, .. }, Hashes { d_hash, e_hash, .. }) = create_test_tangle();
+        let tangle = tangle();
+
+        assert!(tangle.get_latest_solid_milestone().is_none());
+
+        tangle.add_milestone(1.into(), d_hash);
+        tangle.add_milestone(2.into(), e_hash);
+        tangle.update_solid_milestone_index(1.into());
+        tangle.update_last_milestone_index(2.into());
+
+        assert_eq!(d.address(), tangle.get_latest_solid_milestone().unwrap().address());
+        assert_eq!(e.address(), tangle.get_latest_milestone().unwrap().address());
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn get_transactions() {
+        init();
+        let tangle = tangle();
+
+        let (a_hash, a) = create_random_tx();
+        let (b_hash, b) = create_random_tx();
+        let (c_hash, _) = create_random_tx();
+
+        block_on(async {
+            tangle.insert_transaction(a.clone(), a_hash).await;
+            tangle.insert_transaction(b.clone(), b_hash).await;
+        });
+
+        let transactions = tangle.get_transactions(&[c_hash, b_hash, a_hash, c_hash]);
+
+        assert_eq!(transactions.len(), 4);
+        assert!(transactions[0].is_none());
+        assert_eq!(*transactions[1].as_ref().unwrap().address(), *b.address());
+        assert_eq!(*transactions[2].as_ref().unwrap().address(), *a.address());
+        assert!(transactions[3].is_none());
+        assert!(tangle.get_transactions(&[]).is_empty());
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn get_bundle() {
+        init();
+        let tangle = tangle();
+
+        let mut bundle = create_random_bundle(3);
+        let (head_hash, head) = bundle.pop().unwrap();
+        let tail_hash = bundle[0].0;
+
+        block_on(async {
+            for (hash, transaction) in bundle {
+                tangle.insert_transaction(transaction, hash).await;
+            }
+        });
+
+        assert!(tangle.get_bundle(&tail_hash).is_none());
+
+        block_on(tangle.insert_transaction(head, head_hash));
+
+        let transactions = tangle.get_bundle(&tail_hash).unwrap();
+        assert_eq!(transactions.len(), 3);
+        for (index, transaction) in transactions.iter().enumerate() {
+            assert_eq!(*transaction.index().to_inner(), index);
+        }
+        assert!(tangle.get_bundle(&head_hash).is_none());
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn get_bundle_diff() {
+        init();
+        let tangle = tangle();
+
+        let sender = rand_value_address();
+        let receiver = rand_value_address();
+        let remainder = rand_value_address();
+
+        let mut bundle = create_random_value_bundle(&[
+            (receiver.clone(), 60),
+            (sender.clone(), -50),
+            (sender.clone(), -50),
+            (remainder.clone(), 40),
+            (rand_trits_field::<Address>(), 0),
+        ]);
+        let (head_hash, head) = bundle.pop().unwrap();
+        let tail_hash = bundle[0].0;
+
+        block_on(async {
+            for (hash, transaction) in bundle {
+                tangle.insert_transaction(transaction, hash).await;
+            }
+        });
+
+        assert!(tangle.get_bundle_diff(tail_hash).is_none());
+
+        block_on(tangle.insert_transaction(head, head_hash));
+
+        let diff = tangle.get_bundle_diff(tail_hash).unwrap();
+        assert_eq!(diff.len(), 3);
+        assert_eq!(diff[&receiver], 60);
+        assert_eq!(diff[&sender], -100);
+        assert_eq!(diff[&remainder], 40);
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn conflicting_bundle_is_excluded_from_diff() {
+        init();
+        let tangle = tangle();
+
+        let sender = rand_value_address();
+        let receiver = rand_value_address();
+
+        let bundle = create_random_value_bundle(&[(receiver, 100), (sender, -100)]);
+        let tail_hash = bundle[0].0;
+
+        block_on(async {
+            for (hash, transaction) in bundle {
+                tangle.insert_transaction(transaction, hash).await;
+            }
+        });
+
+        assert!(!tangle.is_conflicting(&tail_hash));
+        assert_eq!(tangle.get_bundle_diff(tail_hash).unwrap().len(), 2);
+
+        tangle.set_conflicting(&tail_hash);
+
+        assert!(tangle.is_conflicting(&tail_hash));
+        assert!(tangle.get_bundle_diff(tail_hash).unwrap().is_empty());
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn approver_edges() {
+        init();
+        let tangle = tangle();
+
+        let (a_hash, a) = create_random_tx();
+        let (b_hash, b) = create_random_attached_tx(rand_trits_field::<Hash>(), a_hash);
+        let (c_hash, c) = create_random_attached_tx(a_hash, rand_trits_field::<Hash>());
+        let (d_hash, d) = create_random_attached_tx(a_hash, a_hash);
+
+        block_on(async {
+            tangle.insert_transaction(a, a_hash).await;
+            tangle.insert_transaction(b, b_hash).await;
+            tangle.insert_transaction(c, c_hash).await;
+            tangle.insert_transaction(d, d_hash).await;
+        });
+
+        let edges = tangle.approver_edges(&a_hash);
+
+        assert_eq!(edges.len(), 3);
+        assert!(edges.contains(&(b_hash, EdgeKind::Trunk)));
+        assert!(edges.contains(&(c_hash, EdgeKind::Branch)));
+        assert!(edges.contains(&(d_hash, EdgeKind::Both)));
+        assert!(tangle.approver_edges(&b_hash).is_empty());
+
+        drop();
+    }
+
     #[test]
     #[serial]
     fn walk_trunk_approvers() {
@@ -535,6 +1242,230 @@ mod tests {
         drop();
     }
 
+    #[test]
+    #[serial]
+    fn remove_transaction() {
+        init();
+        let (
+            Transactions { d, .. },
+            Hashes {
+                a_hash, c_hash, d_hash, ..
+            },
+        ) = create_test_tangle();
+        let tangle = tangle();
+
+        let removed = tangle.remove_transaction(&d_hash).unwrap();
+
+        assert_eq!(d.address(), removed.address());
+        assert_eq!(4, tangle.size());
+        assert!(!tangle.contains_transaction(&d_hash));
+        assert_eq!(1, tangle.num_approvers(&a_hash));
+        assert_eq!(1, tangle.num_approvers(&c_hash));
+        // 'e' still references 'd'
+        assert_eq!(1, tangle.num_approvers(&d_hash));
+
+        assert!(tangle.remove_transaction(&d_hash).is_none());
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn walks_visit_shared_trunk_and_branch_once() {
+        init();
+        let tangle = tangle();
+
+        let (a_hash, a) = create_random_tx();
+        let (b_hash, b) = create_random_attached_tx(a_hash, a_hash);
+        let (missing_hash, _) = create_random_tx();
+        let (c_hash, c) = create_random_attached_tx(missing_hash, missing_hash);
+
+        block_on(async {
+            tangle.insert_transaction(a, a_hash).await;
+            tangle.insert_transaction(b, b_hash).await;
+            tangle.insert_transaction(c, c_hash).await;
+        });
+
+        assert_eq!(1, tangle.num_approvers(&a_hash));
+        assert_eq!(1, tangle.num_approvers(&missing_hash));
+
+        let mut mapped = 0;
+        tangle.walk_approvees_depth_first(b_hash, |_| mapped += 1, |_| true, |_| ());
+        assert_eq!(2, mapped);
+
+        let mut missing = Vec::new();
+        tangle.walk_approvees_depth_first(c_hash, |_| (), |_| true, |hash| missing.push(*hash));
+        assert_eq!(vec![missing_hash], missing);
+
+        let mut mapped = Vec::new();
+        tangle.walk_approvers_post_order_dfs(b_hash, |hash, _| mapped.push(*hash), |_| true, |_| ());
+        assert_eq!(vec![a_hash, b_hash], mapped);
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn approvers_are_not_duplicated() {
+        init();
+        let tangle = tangle();
+
+        let (a_hash, a) = create_random_tx();
+        let (b_hash, b) = create_random_attached_tx(a_hash, a_hash);
+
+        block_on(async {
+            tangle.insert_transaction(a, a_hash).await;
+            tangle.insert_transaction(clone_tx(&b), b_hash).await;
+        });
+
+        assert_eq!(1, tangle.num_approvers(&a_hash));
+
+        block_on(tangle.insert_transaction(b, b_hash));
+
+        assert_eq!(1, tangle.num_approvers(&a_hash));
+        assert_eq!(vec![b_hash], *tangle.approvers.get(&a_hash).unwrap().value());
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn is_referenced() {
+        init();
+        let (_, Hashes { d_hash, e_hash, .. }) = create_test_tangle();
+        let tangle = tangle();
+
+        tangle.confirm_transaction(d_hash, 1.into());
+
+        assert!(tangle.is_referenced(&d_hash));
+        assert!(!tangle.is_referenced(&e_hash));
+        assert!(!tangle.is_referenced(&Hash::zeros()));
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn confirm_cone() {
+        init();
+        let (
+            _,
+            Hashes {
+                a_hash,
+                b_hash,
+                c_hash,
+                d_hash,
+                e_hash,
+            },
+        ) = create_test_tangle();
+        let tangle = tangle();
+
+        assert_eq!(4, tangle.confirm_cone(d_hash, 1.into()));
+        assert_eq!(1, tangle.confirm_cone(e_hash, 2.into()));
+        assert_eq!(0, tangle.confirm_cone(e_hash, 3.into()));
+
+        for hash in &[a_hash, b_hash, c_hash, d_hash] {
+            assert_eq!(
+                Some(1.into()),
+                tangle.vertices.get(hash).unwrap().get_confirmation_index()
+            );
+        }
+        assert_eq!(
+            Some(2.into()),
+            tangle.vertices.get(&e_hash).unwrap().get_confirmation_index()
+        );
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn prune_below_milestone() {
+        init();
+        let (
+            _,
+            Hashes {
+                a_hash,
+                b_hash,
+                c_hash,
+                d_hash,
+                e_hash,
+            },
+        ) = create_test_tangle();
+        let tangle = tangle();
+
+        tangle.add_milestone(1.into(), d_hash);
+        tangle.add_milestone(2.into(), e_hash);
+
+        for hash in &[a_hash, b_hash, c_hash, d_hash] {
+            tangle.confirm_transaction(*hash, 1.into());
+        }
+        tangle.confirm_transaction(e_hash, 2.into());
+
+        tangle.prune_below_milestone(1.into());
+
+        assert_eq!(1, tangle.size());
+        assert!(tangle.contains_transaction(&e_hash));
+        assert!(!tangle.contains_milestone(1.into()));
+        assert!(tangle.contains_milestone(2.into()));
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn cone_solid_ratio() {
+        init();
+        let (
+            _,
+            Hashes {
+                a_hash, b_hash, e_hash, ..
+            },
+        ) = create_test_tangle();
+        let tangle = tangle();
+
+        // 5 transactions, and the 4 missing trunks and branches of 'a' and 'b'.
+        assert_eq!((0, 9), tangle.cone_solid_ratio(e_hash));
+
+        tangle.vertices.get_mut(&a_hash).unwrap().set_solid();
+        tangle.vertices.get_mut(&b_hash).unwrap().set_solid();
+
+        assert_eq!((2, 9), tangle.cone_solid_ratio(e_hash));
+
+        drop();
+    }
+
+    #[test]
+    #[serial]
+    fn estimated_memory_bytes() {
+        init();
+        let tangle = tangle();
+
+        let mut estimate = tangle.estimated_memory_bytes();
+        assert_eq!(estimate, 0);
+
+        let (mut trunk, tx) = create_random_tx();
+        block_on(tangle.insert_transaction(tx, trunk));
+
+        for _ in 0..10 {
+            assert!(tangle.estimated_memory_bytes() > estimate);
+            estimate = tangle.estimated_memory_bytes();
+
+            let (hash, tx) = create_random_attached_tx(trunk, trunk);
+            block_on(tangle.insert_transaction(tx, hash));
+            trunk = hash;
+        }
+
+        tangle.add_milestone(1.into(), trunk);
+        assert!(tangle.estimated_memory_bytes() > estimate);
+        estimate = tangle.estimated_memory_bytes();
+
+        tangle.add_solid_entry_point(Hash::zeros());
+        assert!(tangle.estimated_memory_bytes() > estimate);
+
+        drop();
+    }
+
     struct Transactions {
         pub a: Transaction,
         pub b: Transaction,
@@ -650,35 +1581,6 @@ mod tests {
         let (y_hash, y) = create_random_attached_tx(v_hash, u_hash);
         let (z_hash, z) = create_random_attached_tx(s_hash, v_hash);
 
-        // Confirms transactions
-        // TODO uncomment when confirmation index
-        // tangle.confirm_transaction(a_hash, 1);
-        // tangle.confirm_transaction(b_hash, 1);
-        // tangle.confirm_transaction(c_hash, 1);
-        // tangle.confirm_transaction(d_hash, 2);
-        // tangle.confirm_transaction(e_hash, 1);
-        // tangle.confirm_transaction(f_hash, 1);
-        // tangle.confirm_transaction(g_hash, 2);
-        // tangle.confirm_transaction(h_hash, 1);
-        // tangle.confirm_transaction(i_hash, 2);
-        // tangle.confirm_transaction(j_hash, 2);
-        // tangle.confirm_transaction(k_hash, 2);
-        // tangle.confirm_transaction(l_hash, 2);
-        // tangle.confirm_transaction(m_hash, 2);
-        // tangle.confirm_transaction(n_hash, 2);
-        // tangle.confirm_transaction(o_hash, 2);
-        // tangle.confirm_transaction(p_hash, 3);
-        // tangle.confirm_transaction(q_hash, 3);
-        // tangle.confirm_transaction(r_hash, 2);
-        // tangle.confirm_transaction(s_hash, 2);
-        // tangle.confirm_transaction(t_hash, 3);
-        // tangle.confirm_transaction(u_hash, 3);
-        // tangle.confirm_transaction(v_hash, 2);
-        // tangle.confirm_transaction(w_hash, 3);
-        // tangle.confirm_transaction(x_hash, 3);
-        // tangle.confirm_transaction(y_hash, 3);
-        // tangle.confirm_transaction(z_hash, 3);
-
         // Constructs the graph
         block_on(async {
             tangle.insert_transaction(a, a_hash).await;
@@ -709,6 +1611,34 @@ mod tests {
             tangle.insert_transaction(z, z_hash).await;
         });
 
+        // Confirms transactions
+        tangle.confirm_transaction(a_hash, 1.into());
+        tangle.confirm_transaction(b_hash, 1.into());
+        tangle.confirm_transaction(c_hash, 1.into());
+        tangle.confirm_transaction(d_hash, 2.into());
+        tangle.confirm_transaction(e_hash, 1.into());
+        tangle.confirm_transaction(f_hash, 1.into());
+        tangle.confirm_transaction(g_hash, 2.into());
+        tangle.confirm_transaction(h_hash, 1.into());
+        tangle.confirm_transaction(i_hash, 2.into());
+        tangle.confirm_transaction(j_hash, 2.into());
+        tangle.confirm_transaction(k_hash, 2.into());
+        tangle.confirm_transaction(l_hash, 2.into());
+        tangle.confirm_transaction(m_hash, 2.into());
+        tangle.confirm_transaction(n_hash, 2.into());
+        tangle.confirm_transaction(o_hash, 2.into());
+        tangle.confirm_transaction(p_hash, 3.into());
+        tangle.confirm_transaction(q_hash, 3.into());
+        tangle.confirm_transaction(r_hash, 2.into());
+        tangle.confirm_transaction(s_hash, 2.into());
+        tangle.confirm_transaction(t_hash, 3.into());
+        tangle.confirm_transaction(u_hash, 3.into());
+        tangle.confirm_transaction(v_hash, 2.into());
+        tangle.confirm_transaction(w_hash, 3.into());
+        tangle.confirm_transaction(x_hash, 3.into());
+        tangle.confirm_transaction(y_hash, 3.into());
+        tangle.confirm_transaction(z_hash, 3.into());
+
         let mut hashes = Vec::new();
 
         tangle.walk_approvers_post_order_dfs(
@@ -716,45 +1646,23 @@ mod tests {
             |hash, _transaction| {
                 hashes.push(*hash);
             },
-            |_| true,
+            |vertex| vertex.get_confirmation_index().is_none_or(|index| *index > 1),
             |_| (),
         );
 
-        // TODO Remove when we have confirmation index
-        assert_eq!(hashes.len(), 18);
-        assert_eq!(hashes[0], a_hash);
-        assert_eq!(hashes[1], b_hash);
-        assert_eq!(hashes[2], d_hash);
-        assert_eq!(hashes[3], e_hash);
-        assert_eq!(hashes[4], g_hash);
-        assert_eq!(hashes[5], c_hash);
-        assert_eq!(hashes[6], f_hash);
-        assert_eq!(hashes[7], h_hash);
-        assert_eq!(hashes[8], j_hash);
-        assert_eq!(hashes[9], l_hash);
-        assert_eq!(hashes[10], m_hash);
-        assert_eq!(hashes[11], r_hash);
-        assert_eq!(hashes[12], i_hash);
-        assert_eq!(hashes[13], k_hash);
-        assert_eq!(hashes[14], n_hash);
-        assert_eq!(hashes[15], o_hash);
-        assert_eq!(hashes[16], s_hash);
-        assert_eq!(hashes[17], v_hash);
-
-        // TODO uncomment when we have confirmation index
-        // assert_eq!(hashes.len(), 12);
-        // assert_eq!(hashes[0], d_hash);
-        // assert_eq!(hashes[1], g_hash);
-        // assert_eq!(hashes[2], j_hash);
-        // assert_eq!(hashes[3], l_hash);
-        // assert_eq!(hashes[4], m_hash);
-        // assert_eq!(hashes[5], r_hash);
-        // assert_eq!(hashes[6], i_hash);
-        // assert_eq!(hashes[7], k_hash);
-        // assert_eq!(hashes[8], n_hash);
-        // assert_eq!(hashes[9], o_hash);
-        // assert_eq!(hashes[10], s_hash);
-        // assert_eq!(hashes[11], v_hash);
+        assert_eq!(hashes.len(), 12);
+        assert_eq!(hashes[0], d_hash);
+        assert_eq!(hashes[1], g_hash);
+        assert_eq!(hashes[2], j_hash);
+        assert_eq!(hashes[3], l_hash);
+        assert_eq!(hashes[4], m_hash);
+        assert_eq!(hashes[5], r_hash);
+        assert_eq!(hashes[6], i_hash);
+        assert_eq!(hashes[7], k_hash);
+        assert_eq!(hashes[8], n_hash);
+        assert_eq!(hashes[9], o_hash);
+        assert_eq!(hashes[10], s_hash);
+        assert_eq!(hashes[11], v_hash);
 
         drop();
     }
@@ -9,7 +9,7 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use crate::tangle::Tangle;
+use crate::{milestone::MilestoneIndex, tangle::Tangle};
 
 use bitflags::bitflags;
 
@@ -17,12 +17,19 @@ use std::ops::Deref;
 
 use bee_bundle::{Hash, Transaction};
 
-use async_std::sync::Arc;
+use async_std::sync::{Arc, Weak};
 
 /// A wrapper around `bee_bundle::Transaction` that allows sharing it safely across threads.
 #[derive(Clone)]
 pub struct TransactionRef(Arc<Transaction>);
 
+impl TransactionRef {
+    /// Creates a [`WeakTransactionRef`] to the same transaction, that doesn't keep it alive.
+    pub fn downgrade(&self) -> WeakTransactionRef {
+        WeakTransactionRef(Arc::downgrade(&self.0))
+    }
+}
+
 impl Deref for TransactionRef {
     type Target = Transaction;
 
@@ -31,12 +38,27 @@ impl Deref for TransactionRef {
     }
 }
 
+/// A non-owning reference to a `bee_bundle::Transaction`, obtained through [`TransactionRef::downgrade`].
+#[derive(Clone)]
+pub struct WeakTransactionRef(Weak<Transaction>);
+
+impl WeakTransactionRef {
+    /// Attempts to upgrade to a [`TransactionRef`].
+    ///
+    /// Returns `None` once the transaction has been removed from the Tangle and no other [`TransactionRef`] to it
+    /// is alive.
+    pub fn upgrade(&self) -> Option<TransactionRef> {
+        self.0.upgrade().map(TransactionRef)
+    }
+}
+
 bitflags! {
     pub(crate) struct Flags: u8 {
         const SOLID = 0b0000_0001;
         const TAIL = 0b0000_0010;
         const REQUESTED = 0b0000_0100;
         const MILESTONE = 0b0000_1000;
+        const CONFLICTING = 0b0001_0000;
     }
 }
 
@@ -44,6 +66,7 @@ pub struct Vertex {
     id: Hash,
     inner: TransactionRef,
     flags: Flags,
+    confirmation_index: Option<MilestoneIndex>,
 }
 
 impl Vertex {
@@ -58,6 +81,7 @@ impl Vertex {
             id: hash,
             inner: TransactionRef(Arc::new(transaction)),
             flags,
+            confirmation_index: None,
         }
     }
 
@@ -96,6 +120,22 @@ impl Vertex {
     pub fn set_milestone(&mut self) {
         self.flags.insert(Flags::MILESTONE);
     }
+
+    pub fn is_conflicting(&self) -> bool {
+        self.flags.contains(Flags::CONFLICTING)
+    }
+
+    pub fn set_conflicting(&mut self) {
+        self.flags.insert(Flags::CONFLICTING);
+    }
+
+    pub fn get_confirmation_index(&self) -> Option<MilestoneIndex> {
+        self.confirmation_index
+    }
+
+    pub fn set_confirmation_index(&mut self, index: MilestoneIndex) {
+        self.confirmation_index = Some(index);
+    }
 }
 
 #[cfg(test)]
@@ -113,4 +153,39 @@ mod tests {
         vtx.set_solid();
         assert!(vtx.is_solid())
     }
+
+    #[test]
+    fn set_and_is_conflicting() {
+        let (hash, tx) = create_random_tx();
+
+        let mut vtx = Vertex::from(tx, hash);
+        assert!(!vtx.is_conflicting());
+
+        vtx.set_conflicting();
+        assert!(vtx.is_conflicting())
+    }
+
+    #[test]
+    fn downgrade_and_upgrade() {
+        let (hash, tx) = create_random_tx();
+
+        let vtx = Vertex::from(tx, hash);
+        let weak = vtx.get_ref_to_inner().downgrade();
+
+        assert!(weak.upgrade().is_some());
+
+        std::mem::drop(vtx);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn set_and_get_confirmation_index() {
+        let (hash, tx) = create_random_tx();
+
+        let mut vtx = Vertex::from(tx, hash);
+        assert!(vtx.get_confirmation_index().is_none());
+
+        vtx.set_confirmation_index(1_368_160.into());
+        assert_eq!(Some(1_368_160.into()), vtx.get_confirmation_index());
+    }
 }
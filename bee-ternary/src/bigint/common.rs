@@ -63,4 +63,15 @@ impl BinaryRepresentation for U32Repr {
 pub enum Error {
     BinaryExceedsTernaryRange,
     TernaryExceedsBinaryRange,
+    NonZeroMostSignificantTrit,
+    UnsignedExceedsSignedRange,
+}
+
+/// Error returned when a slice does not have the length of the binary representation it is converted into.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LengthError {
+    /// The number of digits of the representation.
+    pub expected: usize,
+    /// The number of digits of the slice.
+    pub actual: usize,
 }
@@ -63,6 +63,21 @@ impl<E, T> I384<E, T> {
     pub fn inner_mut(&mut self) -> &mut T {
         &mut self.inner
     }
+
+    pub(crate) fn from_inner(inner: T) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Reinterprets the two's complement representation as an unsigned integer.
+    ///
+    /// Negative values map to the upper half of the unsigned range, e.g. `-1` becomes `U384::max()`, and can thus not
+    /// be converted back with `U384::try_to_signed`.
+    pub fn to_unsigned(self) -> U384<E, T> {
+        U384::from_inner(self.inner)
+    }
 }
 
 impl<E: fmt::Debug, R: BinaryRepresentation, D> fmt::Debug for I384<E, R>
@@ -881,6 +896,8 @@ impl TryFrom<T243<Btrit>> for I384<LittleEndian, U32Repr> {
     }
 }
 
+impl_shifts!((I384), true);
+
 impl_toggle_endianness!((I384), U8Repr, U32Repr);
 
 #[cfg(test)]
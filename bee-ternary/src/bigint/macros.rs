@@ -179,6 +179,8 @@ macro_rules! def_and_impl_ternary {
             }
         }
 
+        /// Compares the numeric values of the integers, which for both balanced and unbalanced trits amounts to
+        /// comparing trits starting from the most significant one.
         impl<T: Trit> PartialOrd for $ident<T> {
             fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
                 use Ordering::Equal;
@@ -203,6 +205,22 @@ macro_rules! impl_const_functions {
                     _phantom: PhantomData,
                 }
             }
+
+            /// Copies the digits of `slice` into a new integer, failing if `slice` does not have exactly as many
+            /// digits as the representation.
+            pub fn try_from_slice(
+                slice: &[<$repr as BinaryRepresentation>::T],
+            ) -> Result<Self, $crate::bigint::common::LengthError> {
+                let mut this = Self::zero();
+                if slice.len() != this.inner.len() {
+                    return Err($crate::bigint::common::LengthError {
+                        expected: this.inner.len(),
+                        actual: slice.len(),
+                    });
+                }
+                this.inner.copy_from_slice(slice);
+                Ok(this)
+            }
         }
     };
 
@@ -234,12 +252,103 @@ macro_rules! impl_constants {
     };
 }
 
+macro_rules! impl_shifts {
+    ( @inner
+      ( $($root:tt)* ),
+      $arithmetic:expr,
+      $endianness:ty,
+      $repr:ty,
+      $big_endian:expr
+    ) => {
+        impl $($root)* < $endianness, $repr > {
+            /// Shifts the integer `bits` bits to the left in place, discarding the bits shifted out of its range.
+            pub fn shl_inplace(&mut self, bits: usize) {
+                if $big_endian {
+                    self.inner.reverse();
+                }
+                $crate::bigint::utils::shl_digits(&mut self.inner, bits);
+                if $big_endian {
+                    self.inner.reverse();
+                }
+            }
+
+            /// Shifts the integer `bits` bits to the right in place. Signed integers are shifted arithmetically,
+            /// preserving their sign.
+            pub fn shr_inplace(&mut self, bits: usize) {
+                if $big_endian {
+                    self.inner.reverse();
+                }
+                $crate::bigint::utils::shr_digits(&mut self.inner, bits, $arithmetic);
+                if $big_endian {
+                    self.inner.reverse();
+                }
+            }
+        }
+    };
+
+    ( ( $($root:tt)* ), $arithmetic:expr ) => {
+        impl_shifts!(@inner ($($root)*), $arithmetic, BigEndian, U8Repr, true);
+        impl_shifts!(@inner ($($root)*), $arithmetic, LittleEndian, U8Repr, false);
+        impl_shifts!(@inner ($($root)*), $arithmetic, BigEndian, U32Repr, true);
+        impl_shifts!(@inner ($($root)*), $arithmetic, LittleEndian, U32Repr, false);
+    };
+}
+
+macro_rules! impl_checked_arithmetic {
+    ( @inner
+      ( $($root:tt)* ),
+      $endianness:ty,
+      $repr:ty,
+      $big_endian:expr
+    ) => {
+        impl $($root)* < $endianness, $repr > {
+            /// Adds `other` to `self`, returning `None` if the sum exceeds the range of the integer.
+            pub fn checked_add(mut self, mut other: Self) -> Option<Self> {
+                if $big_endian {
+                    self.inner.reverse();
+                    other.inner.reverse();
+                }
+                if $crate::bigint::utils::add_digits(&mut self.inner, &other.inner) {
+                    return None;
+                }
+                if $big_endian {
+                    self.inner.reverse();
+                }
+                Some(self)
+            }
+
+            /// Subtracts `other` from `self`, returning `None` if `other` is greater than `self`.
+            pub fn checked_sub(mut self, mut other: Self) -> Option<Self> {
+                if $big_endian {
+                    self.inner.reverse();
+                    other.inner.reverse();
+                }
+                if $crate::bigint::utils::sub_digits(&mut self.inner, &other.inner) {
+                    return None;
+                }
+                if $big_endian {
+                    self.inner.reverse();
+                }
+                Some(self)
+            }
+        }
+    };
+
+    ( ( $($root:tt)* ) ) => {
+        impl_checked_arithmetic!(@inner ($($root)*), BigEndian, U8Repr, true);
+        impl_checked_arithmetic!(@inner ($($root)*), LittleEndian, U8Repr, false);
+        impl_checked_arithmetic!(@inner ($($root)*), BigEndian, U32Repr, true);
+        impl_checked_arithmetic!(@inner ($($root)*), LittleEndian, U32Repr, false);
+    };
+}
+
 macro_rules! impl_toggle_endianness {
     ( @inner
       ( $($root:tt)* ),
       $repr:ty,
       $src_endian:ty,
-      $dst_endian:ty
+      $dst_endian:ty,
+      $into_dst:ident
     ) => {
         impl From< $($root)* < $src_endian, $repr >> for $($root)* <$dst_endian, $repr> {
             fn from(value: $($root)*<$src_endian, $repr>) -> Self {
@@ -251,11 +360,18 @@ macro_rules! impl_toggle_endianness {
                 }
             }
         }
+
+        impl $($root)* < $src_endian, $repr > {
+            /// Converts the integer to the other endianness by reversing the order of its digits.
+            pub fn $into_dst(self) -> $($root)* < $dst_endian, $repr > {
+                self.into()
+            }
+        }
     };
 
     ( ( $($root:tt)* ), $head:ty $(,)?) => {
-        impl_toggle_endianness!(@inner ($($root)*), $head, LittleEndian, BigEndian);
-        impl_toggle_endianness!(@inner ($($root)*), $head, BigEndian, LittleEndian);
+        impl_toggle_endianness!(@inner ($($root)*), $head, LittleEndian, BigEndian, into_big_endian);
+        impl_toggle_endianness!(@inner ($($root)*), $head, BigEndian, LittleEndian, into_little_endian);
     };
 
     ( ( $($root:tt)* ), $head:ty, $( $tail:ty ),+ $(,)?) => {
@@ -22,7 +22,7 @@ use std::cmp::Ordering;
 
 use crate::{
     bigint::{
-        common::{BigEndian, LittleEndian, U32Repr, U8Repr},
+        common::{BigEndian, Error, LittleEndian, U32Repr, U8Repr},
         I384, T242, U384,
     },
     raw::RawEncoding,
@@ -42,6 +42,17 @@ impl<T: Trit> T243<T> {
         trit_buf.pop();
         T242::from_trit_buf(trit_buf)
     }
+
+    /// Converts into a `T242`, failing if the most significant trit, which is dropped, is not zero.
+    pub fn try_into_t242(self) -> Result<T242<T>, Error> {
+        let mst = self.inner_ref().get(self.inner_ref().len() - 1);
+
+        if mst != Some(T::zero()) {
+            return Err(Error::NonZeroMostSignificantTrit);
+        }
+
+        Ok(self.into_t242())
+    }
 }
 
 impl T243<Utrit> {
@@ -14,7 +14,10 @@ use std::{cmp::Ordering, convert::TryFrom, fmt, marker::PhantomData};
 
 use crate::{
     bigint::{
-        common::{BigEndian, BinaryRepresentation, Error, LittleEndian, U32Repr, U8Repr},
+        common::{
+            BigEndian, BinaryRepresentation, Error, LittleEndian, U32Repr, U8Repr, BINARY_LEN_IN_U32 as LEN_IN_U32,
+            BINARY_LEN_IN_U8 as LEN_IN_U8,
+        },
         t243,
         utils::{OverflowingAddExt, SplitInteger},
         I384, T242, T243,
@@ -40,8 +43,40 @@ impl<E, T> U384<E, T> {
     pub fn inner_ref(&self) -> &T {
         &self.inner
     }
+
+    pub(crate) fn from_inner(inner: T) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
 }
 
+macro_rules! impl_try_to_signed {
+    ( $( ( $endianness:ty, $repr:ty ) => ( $msd:expr, $sign_bit:expr ) ),+ $(,)? ) => {
+        $(
+            impl U384<$endianness, $repr> {
+                /// Reinterprets the integer as a two's complement signed integer, failing if it is greater than
+                /// `I384::max()`.
+                pub fn try_to_signed(self) -> Result<I384<$endianness, $repr>, Error> {
+                    if self.inner[$msd] & $sign_bit != 0 {
+                        return Err(Error::UnsignedExceedsSignedRange);
+                    }
+
+                    Ok(I384::from_inner(self.inner))
+                }
+            }
+        )+
+    };
+}
+
+impl_try_to_signed!(
+    (BigEndian, U8Repr) => (0, 0x80),
+    (LittleEndian, U8Repr) => (LEN_IN_U8 - 1, 0x80),
+    (BigEndian, U32Repr) => (0, 0x8000_0000),
+    (LittleEndian, U32Repr) => (LEN_IN_U32 - 1, 0x8000_0000),
+);
+
 impl<E: fmt::Debug, R: BinaryRepresentation, D> fmt::Debug for U384<E, R>
 where
     E: fmt::Debug,
@@ -455,6 +490,10 @@ impl TryFrom<T243<Utrit>> for U384<LittleEndian, U32Repr> {
     }
 }
 
+impl_checked_arithmetic!((U384));
+
+impl_shifts!((U384), false);
+
 impl_toggle_endianness!((U384), U8Repr, U32Repr);
 
 #[cfg(test)]
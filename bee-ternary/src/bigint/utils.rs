@@ -9,6 +9,8 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
+use std::ops::{BitOr, Shl, Shr};
+
 pub(crate) trait SplitInteger: Copy {
     type High;
     type Low;
@@ -57,6 +59,138 @@ impl OverflowingAddExt for u32 {
         (sum, first_overflow | second_overflow)
     }
 }
+/// A digit of the binary representation of a bigint.
+pub(crate) trait Digit: Copy + Shl<u32, Output = Self> + Shr<u32, Output = Self> + BitOr<Output = Self> {
+    const BITS: usize;
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    fn is_sign_bit_set(self) -> bool;
+
+    fn overflowing_add(self, other: Self) -> (Self, bool);
+
+    fn overflowing_sub(self, other: Self) -> (Self, bool);
+}
+
+impl Digit for u8 {
+    const BITS: usize = 8;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    const MAX: Self = u8::max_value();
+
+    fn is_sign_bit_set(self) -> bool {
+        self & 0x80 != 0
+    }
+
+    fn overflowing_add(self, other: Self) -> (Self, bool) {
+        u8::overflowing_add(self, other)
+    }
+
+    fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        u8::overflowing_sub(self, other)
+    }
+}
+
+impl Digit for u32 {
+    const BITS: usize = 32;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    const MAX: Self = u32::max_value();
+
+    fn is_sign_bit_set(self) -> bool {
+        self & 0x8000_0000 != 0
+    }
+
+    fn overflowing_add(self, other: Self) -> (Self, bool) {
+        u32::overflowing_add(self, other)
+    }
+
+    fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        u32::overflowing_sub(self, other)
+    }
+}
+
+/// Adds `other` onto `digits` in place, both ordered from the least to the most significant digit, and returns
+/// whether the addition overflowed.
+pub(crate) fn add_digits<D: Digit>(digits: &mut [D], other: &[D]) -> bool {
+    let mut carry = false;
+
+    for (digit, other) in digits.iter_mut().zip(other) {
+        let (sum, first_overflow) = digit.overflowing_add(*other);
+        let (sum, second_overflow) = sum.overflowing_add(if carry { D::ONE } else { D::ZERO });
+        *digit = sum;
+        carry = first_overflow | second_overflow;
+    }
+
+    carry
+}
+
+/// Subtracts `other` from `digits` in place, both ordered from the least to the most significant digit, and returns
+/// whether the subtraction underflowed.
+pub(crate) fn sub_digits<D: Digit>(digits: &mut [D], other: &[D]) -> bool {
+    let mut borrow = false;
+
+    for (digit, other) in digits.iter_mut().zip(other) {
+        let (difference, first_underflow) = digit.overflowing_sub(*other);
+        let (difference, second_underflow) = difference.overflowing_sub(if borrow { D::ONE } else { D::ZERO });
+        *digit = difference;
+        borrow = first_underflow | second_underflow;
+    }
+
+    borrow
+}
+
+/// Shifts the bits of `digits`, ordered from the least to the most significant one, `bits` positions to the left.
+/// Bits shifted out of the most significant digit are discarded.
+pub(crate) fn shl_digits<D: Digit>(digits: &mut [D], bits: usize) {
+    let digit_shift = bits / D::BITS;
+    let bit_shift = (bits % D::BITS) as u32;
+
+    // Going from the most significant digit down only reads digits that have not been overwritten yet.
+    for i in (0..digits.len()).rev() {
+        let high = if i >= digit_shift {
+            digits[i - digit_shift]
+        } else {
+            D::ZERO
+        };
+        let low = if i > digit_shift {
+            digits[i - digit_shift - 1]
+        } else {
+            D::ZERO
+        };
+
+        digits[i] = if bit_shift == 0 {
+            high
+        } else {
+            (high << bit_shift) | (low >> (D::BITS as u32 - bit_shift))
+        };
+    }
+}
+
+/// Shifts the bits of `digits`, ordered from the least to the most significant one, `bits` positions to the right.
+/// If `arithmetic` is set, the vacated bits are filled with the sign bit instead of zeros.
+pub(crate) fn shr_digits<D: Digit>(digits: &mut [D], bits: usize, arithmetic: bool) {
+    let digit_shift = bits / D::BITS;
+    let bit_shift = (bits % D::BITS) as u32;
+    let fill = match digits.last() {
+        Some(digit) if arithmetic && digit.is_sign_bit_set() => D::MAX,
+        _ => D::ZERO,
+    };
+
+    // Going from the least significant digit up only reads digits that have not been overwritten yet.
+    for i in 0..digits.len() {
+        let low = digits.get(i + digit_shift).copied().unwrap_or(fill);
+        let high = digits.get(i + digit_shift + 1).copied().unwrap_or(fill);
+
+        digits[i] = if bit_shift == 0 {
+            low
+        } else {
+            (low >> bit_shift) | (high << (D::BITS as u32 - bit_shift))
+        };
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SplitInteger;
@@ -44,12 +44,13 @@ pub use crate::{
     t4b1::{T4B1Buf, T4B1},
     t5b1::{T5B1Buf, T5B1},
     trit::{Btrit, ShiftTernary, Trit, Utrit},
-    tryte::{Tryte, TryteBuf, MAX_TRYTE_VALUE, MIN_TRYTE_VALUE},
+    tryte::{char_is_tryte, tryte_to_trits, Tryte, TryteBuf, MAX_TRYTE_VALUE, MIN_TRYTE_VALUE},
 };
 
 #[derive(Debug)]
 pub enum Error {
     InvalidRepr,
+    InvalidLength,
 }
 
 #[derive(Hash)]
@@ -106,6 +107,14 @@ where
         self.0.as_i8_slice_mut()
     }
 
+    /// Returns the value of each trit as an `i8`, whatever the encoding.
+    ///
+    /// Unlike `as_i8_slice`, which exposes the encoded bytes (e.g. 5 trits per byte for `T5B1`), this decodes every
+    /// trit, so the result always holds `len()` values.
+    pub fn to_i8_vec(&self) -> Vec<i8> {
+        self.trits().map(Into::into).collect()
+    }
+
     pub unsafe fn get_unchecked(&self, index: usize) -> T::Trit {
         debug_assert!(index < self.len());
         self.0.get_unchecked(index)
@@ -192,6 +201,41 @@ where
     }
 }
 
+impl<T: RawEncoding<Trit = Btrit> + ?Sized> Trits<T> {
+    /// Adds `other` to these trits in place, both being read as little-endian balanced ternary numbers of the same
+    /// length, and returns the final carry.
+    pub fn add_inplace<U: RawEncoding<Trit = Btrit> + ?Sized>(&mut self, other: &Trits<U>) -> Btrit {
+        assert!(self.len() == other.len());
+        let mut carry = Btrit::Zero;
+        for (i, trit) in other.trits().enumerate() {
+            unsafe {
+                let (sum, next_carry) = self.get_unchecked(i).add(trit, carry);
+                self.set_unchecked(i, sum);
+                carry = next_carry;
+            }
+        }
+        carry
+    }
+
+    /// Decodes bytes from these trits, each byte being encoded by two trytes as done by `TritBuf::from_bytes_as_trytes`.
+    ///
+    /// Fails with `Error::InvalidLength` if the trits don't make whole pairs of trytes, and with `Error::InvalidRepr` if
+    /// a pair of trytes encodes a value above 255.
+    pub fn bytes_from_trytes(&self) -> Result<Vec<u8>, Error> {
+        if self.len() % 6 != 0 {
+            return Err(Error::InvalidLength);
+        }
+
+        // Maps each little-endian tryte onto its index in `9ABCDEFGHIJKLMNOPQRSTUVWXYZ`.
+        let index = |trits: &[i8]| ((trits[0] + trits[1] * 3 + trits[2] * 9 + 27) % 27) as u16;
+
+        self.to_i8_vec()
+            .chunks(6)
+            .map(|pair| u8::try_from(index(&pair[..3]) + index(&pair[3..]) * 27).map_err(|_| Error::InvalidRepr))
+            .collect()
+    }
+}
+
 impl<T: Trit> Trits<T1B1<T>> {
     pub fn as_raw_slice(&self) -> &[T] {
         self.0.as_raw_slice()
@@ -261,6 +305,8 @@ impl Trits<T3B1> {
     }
 }
 
+/// Trit slices are compared trit by trit, so slices of different encodings, or sliced at different offsets within
+/// their bytes, are equal as long as they hold the same trits.
 impl<T, U> cmp::PartialEq<Trits<U>> for Trits<T>
 where
     T: RawEncoding + ?Sized,
@@ -359,6 +405,14 @@ impl<T: RawEncodingBuf> TritBuf<T> {
         Self(T::from_trits(trits))
     }
 
+    /// Creates a buffer from a slice of `i8`s, failing if any of them is not a valid trit.
+    pub fn from_i8_slice(trits: &[i8]) -> Result<Self, Error> {
+        trits
+            .iter()
+            .map(|t| <T::Slice as RawEncoding>::Trit::try_from(*t).map_err(|_| Error::InvalidRepr))
+            .collect()
+    }
+
     // TODO: Is this a good API feature? No, it's not. Kill it with fire.
     #[deprecated]
     pub fn from_i8_unchecked(trits: &[i8]) -> Self {
@@ -377,6 +431,17 @@ impl<T: RawEncodingBuf> TritBuf<T> {
         self.0.pop()
     }
 
+    /// Shorten this buffer to `len` trits, keeping the first `len` trits. Has no effect if the buffer is already
+    /// shorter.
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    /// Resize this buffer to `len` trits, either truncating it or extending it with `fill`.
+    pub fn resize(&mut self, len: usize, fill: <T::Slice as RawEncoding>::Trit) {
+        self.0.resize(len, fill);
+    }
+
     pub fn as_slice(&self) -> &Trits<T::Slice> {
         unsafe { &*(self.0.as_slice() as *const T::Slice as *const Trits<T::Slice>) }
     }
@@ -386,6 +451,31 @@ impl<T: RawEncodingBuf> TritBuf<T> {
     }
 }
 
+impl<T: RawEncodingBuf> TritBuf<T>
+where
+    T::Slice: RawEncoding<Trit = Btrit>,
+{
+    /// Encodes each byte of `data` as two trytes, the first one holding the byte modulo 27 and the second one the byte
+    /// divided by 27, both given by their index in `9ABCDEFGHIJKLMNOPQRSTUVWXYZ`.
+    ///
+    /// This is the encoding used to store arbitrary data, such as ASCII strings, in transactions.
+    pub fn from_bytes_as_trytes(data: &[u8]) -> Self {
+        let mut buf = Self::with_capacity(data.len() * 6);
+
+        for byte in data {
+            for index in &[byte % 27, byte / 27] {
+                // The index is below 27, so the balanced value is a valid tryte.
+                let tryte = Tryte::try_from(if *index > 13 { *index as i8 - 27 } else { *index as i8 }).unwrap();
+                for trit in tryte.as_trits().trits() {
+                    buf.push(trit);
+                }
+            }
+        }
+
+        buf
+    }
+}
+
 impl<T> TritBuf<T1B1Buf<T>>
 where
     T: Trit,
@@ -16,6 +16,10 @@ pub trait RawEncoding {
     type Trit: Trit;
     type Buf: RawEncodingBuf<Slice = Self>;
 
+    /// Whether human-readable serialization formats represent this encoding as a tryte string rather than as a
+    /// sequence of trits.
+    const TRYTE_STRING: bool = false;
+
     /// Get an empty slice of this encoding
     fn empty() -> &'static Self;
 
@@ -38,6 +42,24 @@ pub trait RawEncoding {
     /// Get a mutable slice of this slice
     unsafe fn slice_unchecked_mut(&mut self, range: Range<usize>) -> &mut Self;
 
+    /// Get the trit at the given index, or `None` if it is out of bounds
+    fn get(&self, index: usize) -> Option<Self::Trit> {
+        if index < self.len() {
+            unsafe { Some(self.get_unchecked(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Get a slice of this slice, or `None` if the range is out of bounds
+    fn slice(&self, range: Range<usize>) -> Option<&Self> {
+        if range.start <= range.end && range.end <= self.len() {
+            unsafe { Some(self.slice_unchecked(range)) }
+        } else {
+            None
+        }
+    }
+
     /// Decide whether a byte is a valid series of trits in this encoding
     fn is_valid(repr: &i8) -> bool;
 
@@ -74,6 +96,21 @@ pub trait RawEncodingBuf {
     /// Pop a trit from the back of this buffer
     fn pop(&mut self) -> Option<<Self::Slice as RawEncoding>::Trit>;
 
+    /// Shorten this buffer to the given number of trits, doing nothing if it is already shorter
+    fn truncate(&mut self, len: usize) {
+        while self.as_slice().len() > len {
+            self.pop();
+        }
+    }
+
+    /// Resize this buffer to the given number of trits, filling any new space with the given trit
+    fn resize(&mut self, len: usize, fill: <Self::Slice as RawEncoding>::Trit) {
+        self.truncate(len);
+        while self.as_slice().len() < len {
+            self.push(fill);
+        }
+    }
+
     /// View the trits in this buffer as a slice
     fn as_slice(&self) -> &Self::Slice;
 
@@ -9,10 +9,16 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use crate::{Btrit, RawEncoding, RawEncodingBuf, TritBuf, Trits, Utrit};
+use crate::{
+    bigint::{
+        common::{BigEndian, BinaryRepresentation, LittleEndian, U32Repr, U8Repr},
+        t242, t243, I384, T242, T243, U384,
+    },
+    tryte_to_trits, Btrit, RawEncoding, RawEncodingBuf, T1B1Buf, Trit, TritBuf, Trits, Tryte, Utrit,
+};
 use serde::{
     de::{Error, SeqAccess, Unexpected, Visitor},
-    ser::SerializeSeq,
+    ser::{self, SerializeSeq},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 use std::{convert::TryFrom, fmt, marker::PhantomData};
@@ -45,11 +51,34 @@ where
     }
 }
 
+// Serializes a buffer of balanced trits whose length is a multiple of 3 as a tryte string.
+fn serialize_trytes<T: RawEncodingBuf, S: Serializer>(buf: &TritBuf<T>, serializer: S) -> Result<S::Ok, S::Error> {
+    if buf.len() % 3 != 0 {
+        return Err(ser::Error::custom("the length of the trits is not a multiple of 3"));
+    }
+
+    let trits = buf.trits().map(Into::<i8>::into).collect::<Vec<_>>();
+    let trytes = trits
+        .chunks(3)
+        .map(|trits| {
+            Tryte::try_from(trits[0] + trits[1] * 3 + trits[2] * 9)
+                .map(char::from)
+                .map_err(|_| ser::Error::custom("the trits are not balanced"))
+        })
+        .collect::<Result<String, _>>()?;
+
+    serializer.serialize_str(&trytes)
+}
+
 impl<T: RawEncodingBuf> Serialize for TritBuf<T>
 where
     <T::Slice as RawEncoding>::Trit: Serialize,
 {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() && <T::Slice as RawEncoding>::TRYTE_STRING {
+            return serialize_trytes(self, serializer);
+        }
+
         let mut seq = serializer.serialize_seq(Some(self.len()))?;
         for trit in self.trits() {
             seq.serialize_element(&trit)?;
@@ -58,6 +87,37 @@ where
     }
 }
 
+impl<E, R: BinaryRepresentation> Serialize for I384<E, R>
+where
+    R::T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.inner_ref().iter())
+    }
+}
+
+impl<E, R: BinaryRepresentation> Serialize for U384<E, R>
+where
+    R::T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.inner_ref().iter())
+    }
+}
+
+// Ternary big integers are always sequences of trits, 242 trits not making a tryte string.
+impl<T: Trit + Serialize> Serialize for T242<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.inner_ref().trits())
+    }
+}
+
+impl<T: Trit + Serialize> Serialize for T243<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.inner_ref().trits())
+    }
+}
+
 // Deserialisation
 
 struct BtritVisitor;
@@ -165,11 +225,87 @@ where
     }
 }
 
+struct TrytesVisitor<T>(PhantomData<T>);
+
+impl<'de, T: RawEncodingBuf> Visitor<'de> for TrytesVisitor<T> {
+    type Value = TritBuf<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a tryte string")
+    }
+
+    fn visit_str<E: Error>(self, trytes: &str) -> Result<Self::Value, E> {
+        let mut buf = TritBuf::with_capacity(trytes.len() * 3);
+
+        for c in trytes.chars() {
+            let trits = tryte_to_trits(c).ok_or_else(|| E::invalid_value(Unexpected::Char(c), &self))?;
+            for trit in trits.iter() {
+                let trit = <T::Slice as RawEncoding>::Trit::try_from(i8::from(*trit))
+                    .map_err(|_| E::invalid_value(Unexpected::Char(c), &self))?;
+                buf.push(trit);
+            }
+        }
+
+        Ok(buf)
+    }
+}
+
 impl<'de, T: RawEncodingBuf> Deserialize<'de> for TritBuf<T>
 where
     <T::Slice as RawEncoding>::Trit: Deserialize<'de>,
 {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        deserializer.deserialize_seq(TritBufVisitor::<T>(PhantomData))
+        if deserializer.is_human_readable() && <T::Slice as RawEncoding>::TRYTE_STRING {
+            deserializer.deserialize_str(TrytesVisitor::<T>(PhantomData))
+        } else {
+            deserializer.deserialize_seq(TritBufVisitor::<T>(PhantomData))
+        }
     }
 }
+
+macro_rules! impl_deserialize_binary {
+    ( $( $t:ty ),+ $(,)? ) => {
+        $(
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let digits = Vec::deserialize(deserializer)?;
+
+                    Self::try_from_slice(&digits).map_err(|e| {
+                        D::Error::invalid_length(e.actual, &format!("{} digits", e.expected).as_str())
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_deserialize_binary!(
+    I384<BigEndian, U8Repr>,
+    I384<BigEndian, U32Repr>,
+    I384<LittleEndian, U8Repr>,
+    I384<LittleEndian, U32Repr>,
+    U384<BigEndian, U8Repr>,
+    U384<BigEndian, U32Repr>,
+    U384<LittleEndian, U8Repr>,
+    U384<LittleEndian, U32Repr>,
+);
+
+macro_rules! impl_deserialize_ternary {
+    ( $( $t:ident => $len:expr ),+ $(,)? ) => {
+        $(
+            impl<'de, T: Trit + Deserialize<'de>> Deserialize<'de> for $t<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let buf = deserializer.deserialize_seq(TritBufVisitor::<T1B1Buf<T>>(PhantomData))?;
+
+                    if buf.len() != $len {
+                        return Err(D::Error::invalid_length(buf.len(), &format!("{} trits", $len).as_str()));
+                    }
+
+                    Ok(Self::from_trit_buf(buf))
+                }
+            }
+        )+
+    };
+}
+
+impl_deserialize_ternary!(T242 => t242::LEN, T243 => t243::LEN);
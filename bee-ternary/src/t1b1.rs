@@ -49,6 +49,9 @@ where
     type Trit = T;
     type Buf = T1B1Buf<T>;
 
+    // Unpacked balanced trits, as used for hashes and addresses, are the ones read and written as trytes.
+    const TRYTE_STRING: bool = T::BALANCED;
+
     fn empty() -> &'static Self {
         unsafe { &*Self::make(&[] as *const _, 0, 0) }
     }
@@ -166,6 +169,14 @@ where
         self.inner.pop()
     }
 
+    fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    fn resize(&mut self, len: usize, fill: <Self::Slice as RawEncoding>::Trit) {
+        self.inner.resize(len, fill);
+    }
+
     fn as_slice(&self) -> &Self::Slice {
         unsafe { &*Self::Slice::make(self.inner.as_ptr() as _, 0, self.inner.len()) }
     }
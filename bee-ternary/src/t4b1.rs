@@ -67,12 +67,12 @@ impl RawEncoding for T4B1 {
 
     fn as_i8_slice(&self) -> &[i8] {
         assert!(self.len_offset().1 == 0);
-        unsafe { &*(Self::make(self.ptr(0), 0, (self.len() as f32 / TPB as f32).ceil() as usize) as *const _) }
+        unsafe { std::slice::from_raw_parts(self.ptr(0) as *const _, (self.len() + TPB - 1) / TPB) }
     }
 
     unsafe fn as_i8_slice_mut(&mut self) -> &mut [i8] {
         assert!(self.len_offset().1 == 0);
-        &mut *(Self::make(self.ptr(0), 0, (self.len() as f32 / TPB as f32).ceil() as usize) as *mut _)
+        std::slice::from_raw_parts_mut(self.ptr(0) as *mut _, (self.len() + TPB - 1) / TPB)
     }
 
     unsafe fn get_unchecked(&self, index: usize) -> Self::Trit {
@@ -151,6 +151,23 @@ impl RawEncodingBuf for T4B1Buf {
         val
     }
 
+    fn truncate(&mut self, len: usize) {
+        if len >= self.1 {
+            return;
+        }
+
+        self.0.truncate((len + TPB - 1) / TPB);
+        // Zero the leftover trits in the last byte so the raw bytes match those of a freshly pushed buffer
+        if len % TPB != 0 {
+            if let Some(b) = self.0.last_mut() {
+                for elem in len % TPB..TPB {
+                    *b = insert(*b, elem, Btrit::Zero);
+                }
+            }
+        }
+        self.1 = len;
+    }
+
     fn as_slice(&self) -> &Self::Slice {
         unsafe { &*Self::Slice::make(self.0.as_ptr() as _, 0, self.1) }
     }
@@ -9,7 +9,7 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use crate::{Btrit, RawEncoding, RawEncodingBuf, ShiftTernary, Utrit};
+use crate::{Btrit, Error, RawEncoding, RawEncodingBuf, ShiftTernary, Utrit};
 use std::ops::Range;
 
 const TPB: usize = 5;
@@ -53,6 +53,36 @@ fn insert(x: i8, elem: usize, trit: Btrit) -> i8 {
     }
 }
 
+/// Packs balanced trits into bytes, 5 trits per byte starting from the least significant one. The unused trits of the
+/// last byte are zero.
+pub fn pack_trits(trits: &[Btrit]) -> Vec<i8> {
+    trits
+        .chunks(TPB)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0, |byte, (elem, trit)| insert(byte, elem, *trit))
+        })
+        .collect()
+}
+
+/// Unpacks the first `num_trits` balanced trits out of bytes packed with 5 trits per byte.
+///
+/// Fails with `Error::InvalidRepr` if a byte is not in the range `-121..=121` and with `Error::InvalidLength` if the
+/// bytes do not hold `num_trits` trits.
+pub fn unpack_trits(bytes: &[i8], num_trits: usize) -> Result<Vec<Btrit>, Error> {
+    if !bytes.iter().all(T5B1::is_valid) {
+        return Err(Error::InvalidRepr);
+    }
+
+    if num_trits > bytes.len() * TPB {
+        return Err(Error::InvalidLength);
+    }
+
+    Ok((0..num_trits).map(|i| extract(bytes[i / TPB], i % TPB)).collect())
+}
+
 impl RawEncoding for T5B1 {
     type Trit = Btrit;
     type Buf = T5B1Buf;
@@ -159,6 +189,23 @@ impl RawEncodingBuf for T5B1Buf {
         val
     }
 
+    fn truncate(&mut self, len: usize) {
+        if len >= self.1 {
+            return;
+        }
+
+        self.0.truncate((len + TPB - 1) / TPB);
+        // Zero the leftover trits in the last byte so the raw bytes match those of a freshly pushed buffer
+        if len % TPB != 0 {
+            if let Some(b) = self.0.last_mut() {
+                for elem in len % TPB..TPB {
+                    *b = insert(*b, elem, Btrit::Zero);
+                }
+            }
+        }
+        self.1 = len;
+    }
+
     fn as_slice(&self) -> &Self::Slice {
         unsafe { &*Self::Slice::make(self.0.as_ptr() as _, 0, self.1) }
     }
@@ -20,6 +20,23 @@ pub enum Btrit {
     PlusOne = 1,
 }
 
+impl Btrit {
+    /// Adds `other` and `carry` to this trit, returning the resulting sum trit and carry trit.
+    pub fn add(self, other: Btrit, carry: Btrit) -> (Btrit, Btrit) {
+        use Btrit::*;
+        match self as i8 + other as i8 + carry as i8 {
+            -3 => (Zero, NegOne),
+            -2 => (PlusOne, NegOne),
+            -1 => (NegOne, Zero),
+            0 => (Zero, Zero),
+            1 => (PlusOne, Zero),
+            2 => (NegOne, PlusOne),
+            3 => (Zero, PlusOne),
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl fmt::Display for Btrit {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", *self as i8)
@@ -46,6 +63,8 @@ impl ShiftTernary for Btrit {
 }
 
 impl Trit for Btrit {
+    const BALANCED: bool = true;
+
     fn checked_increment(self) -> Option<Self> {
         match self {
             Btrit::NegOne => Some(Btrit::Zero),
@@ -22,6 +22,9 @@ use std::fmt;
 pub trait Trit:
     Copy + Sized + fmt::Debug + hash::Hash + Into<i8> + Ord + PartialEq + ShiftTernary + TryFrom<i8>
 {
+    /// Whether the trit is balanced, ranging from -1 to 1, rather than from 0 to 2.
+    const BALANCED: bool;
+
     fn checked_increment(self) -> Option<Self>;
 
     fn zero() -> Self;
@@ -48,6 +48,8 @@ impl ShiftTernary for Utrit {
 }
 
 impl Trit for Utrit {
+    const BALANCED: bool = false;
+
     fn checked_increment(self) -> Option<Self> {
         match self {
             Zero => Some(One),
@@ -62,6 +64,12 @@ impl Trit for Utrit {
 }
 
 impl Utrit {
+    /// Adds `other` and `carry` to this trit, returning the resulting sum trit and carry trit.
+    pub fn add(self, other: Utrit, carry: Utrit) -> (Utrit, Utrit) {
+        let sum = self.into_u8() + other.into_u8() + carry.into_u8();
+        (Utrit::from_u8(sum % 3), Utrit::from_u8(sum / 3))
+    }
+
     pub(crate) fn from_u8(x: u8) -> Self {
         match x {
             0 => Zero,
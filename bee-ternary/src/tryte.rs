@@ -9,7 +9,7 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use crate::{Error, Trits, T3B1};
+use crate::{Btrit, Error, Trits, T3B1};
 use std::{
     convert::TryFrom,
     fmt,
@@ -85,14 +85,28 @@ impl fmt::Display for Tryte {
     }
 }
 
+/// Returns whether `c` belongs to the tryte alphabet, i.e. `9` or `A` to `Z`.
+pub fn char_is_tryte(c: char) -> bool {
+    matches!(c, '9' | 'A'..='Z')
+}
+
+/// Converts a tryte character into its three balanced trits, least significant first, or `None` if `c` is not part
+/// of the tryte alphabet.
+pub fn tryte_to_trits(c: char) -> Option<[Btrit; 3]> {
+    let tryte = Tryte::try_from(c).ok()?;
+    let trits = tryte.as_trits();
+
+    Some([trits.get(0)?, trits.get(1)?, trits.get(2)?])
+}
+
 impl TryFrom<char> for Tryte {
     type Error = Error;
 
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '9' => Ok(Tryte::Nine),
-            'N'..='Z' => Ok(unsafe { std::mem::transmute((c as u8 - b'N') as i8 - 13) }),
-            'A'..='M' => Ok(unsafe { std::mem::transmute((c as u8 - b'A') as i8 + 1) }),
+            'N'..='Z' => Tryte::try_from((c as u8 - b'N') as i8 - 13),
+            'A'..='M' => Tryte::try_from((c as u8 - b'A') as i8 + 1),
             _ => Err(Error::InvalidRepr),
         }
     }
@@ -10,7 +10,7 @@
 // See the License for the specific language governing permissions and limitations under the License.
 
 use bee_ternary::bigint::{
-    common::{LittleEndian, U32Repr},
+    common::{BigEndian, LittleEndian, U32Repr, U8Repr},
     I384, U384,
 };
 
@@ -34,3 +34,191 @@ fn shift_i384_max_is_u384_max() {
     let max_u384 = max_i384.shift_into_u384();
     assert_eq!(max_u384, U384::<LittleEndian, U32Repr>::max());
 }
+
+#[test]
+fn i384_max_to_unsigned_roundtrip() {
+    let max_i384 = I384::<LittleEndian, U32Repr>::max();
+    let roundtripped = max_i384.to_unsigned().try_to_signed();
+    assert!(roundtripped.is_ok());
+    assert_eq!(roundtripped.unwrap(), max_i384);
+
+    let max_i384 = I384::<BigEndian, U8Repr>::max();
+    let roundtripped = max_i384.to_unsigned().try_to_signed();
+    assert!(roundtripped.is_ok());
+    assert_eq!(roundtripped.unwrap(), max_i384);
+}
+
+#[test]
+fn u384_above_i384_max_try_to_signed_fails() {
+    let mut above_max = I384::<LittleEndian, U32Repr>::max().to_unsigned();
+    above_max.add_inplace(U384::<LittleEndian, U32Repr>::one());
+    assert!(above_max.try_to_signed().is_err());
+
+    assert!(I384::<BigEndian, U8Repr>::min().to_unsigned().try_to_signed().is_err());
+}
+
+#[test]
+fn i384_neg_one_to_unsigned_is_u384_max() {
+    let neg_one = I384::<LittleEndian, U32Repr>::neg_one().to_unsigned();
+    assert_eq!(neg_one, U384::<LittleEndian, U32Repr>::max());
+    assert!(neg_one.try_to_signed().is_err());
+
+    let neg_one = I384::<BigEndian, U8Repr>::neg_one().to_unsigned();
+    assert_eq!(neg_one.inner_ref(), U384::<BigEndian, U8Repr>::max().inner_ref());
+    assert!(neg_one.try_to_signed().is_err());
+}
+
+#[test]
+fn i384_try_from_slice() {
+    let bytes = I384::<BigEndian, U8Repr>::max().inner_ref().to_vec();
+    let from_slice = I384::<BigEndian, U8Repr>::try_from_slice(&bytes);
+    assert!(from_slice.is_ok());
+    assert_eq!(from_slice.unwrap(), I384::<BigEndian, U8Repr>::max());
+
+    let digits = I384::<LittleEndian, U32Repr>::neg_one().inner_ref().to_vec();
+    let from_slice = I384::<LittleEndian, U32Repr>::try_from_slice(&digits);
+    assert!(from_slice.is_ok());
+    assert_eq!(from_slice.unwrap(), I384::<LittleEndian, U32Repr>::neg_one());
+}
+
+#[test]
+fn i384_try_from_slice_with_wrong_length_fails() {
+    let error = I384::<BigEndian, U8Repr>::try_from_slice(&[0u8; 47]).unwrap_err();
+    assert_eq!(error.expected, 48);
+    assert_eq!(error.actual, 47);
+
+    let error = U384::<LittleEndian, U32Repr>::try_from_slice(&[0u32; 13]).unwrap_err();
+    assert_eq!(error.expected, 12);
+    assert_eq!(error.actual, 13);
+}
+
+#[test]
+fn u384_shl_then_shr_recovers_value() {
+    let mut value = U384::<LittleEndian, U32Repr>::two();
+    value.shl_inplace(100);
+    assert_ne!(value, U384::<LittleEndian, U32Repr>::two());
+    value.shr_inplace(100);
+    assert_eq!(value, U384::<LittleEndian, U32Repr>::two());
+
+    let mut value = U384::<BigEndian, U8Repr>::two();
+    value.shl_inplace(381);
+    value.shr_inplace(381);
+    assert_eq!(value.inner_ref(), U384::<BigEndian, U8Repr>::two().inner_ref());
+}
+
+#[test]
+fn u384_shifts_across_digit_boundaries() {
+    let mut value = U384::<LittleEndian, U32Repr>::one();
+    value.shl_inplace(33);
+    assert_eq!(value.inner_ref()[0], 0);
+    assert_eq!(value.inner_ref()[1], 2);
+    value.shr_inplace(2);
+    assert_eq!(value.inner_ref()[0], 0x8000_0000);
+    assert_eq!(value.inner_ref()[1], 0);
+
+    let mut value = U384::<BigEndian, U8Repr>::one();
+    value.shl_inplace(15);
+    assert_eq!(value.inner_ref()[46], 0x80);
+    assert_eq!(value.inner_ref()[47], 0);
+
+    let mut value = U384::<BigEndian, U32Repr>::max();
+    value.shr_inplace(383);
+    assert_eq!(value.inner_ref(), U384::<BigEndian, U32Repr>::one().inner_ref());
+    value.shl_inplace(384);
+    assert_eq!(value.inner_ref(), U384::<BigEndian, U32Repr>::zero().inner_ref());
+}
+
+#[test]
+fn i384_shr_is_arithmetic() {
+    let mut value = I384::<LittleEndian, U32Repr>::neg_two();
+    value.shr_inplace(1);
+    assert_eq!(value, I384::<LittleEndian, U32Repr>::neg_one());
+
+    let mut value = I384::<BigEndian, U8Repr>::min();
+    value.shr_inplace(383);
+    assert_eq!(value, I384::<BigEndian, U8Repr>::neg_one());
+
+    let mut value = I384::<LittleEndian, U8Repr>::max();
+    value.shr_inplace(382);
+    assert_eq!(value, I384::<LittleEndian, U8Repr>::one());
+
+    let mut value = I384::<BigEndian, U32Repr>::neg_one();
+    value.shl_inplace(1);
+    assert_eq!(value, I384::<BigEndian, U32Repr>::neg_two());
+}
+
+#[test]
+fn i384_endianness_roundtrip() {
+    let mut bytes = [0u8; 48];
+    bytes[47] = 1;
+    bytes[0] = 0x80;
+    let big_endian = I384::<BigEndian, U8Repr>::from_array(bytes);
+
+    let little_endian = big_endian.into_little_endian();
+    assert_eq!(little_endian.inner_ref()[0], 1);
+    assert_eq!(little_endian.inner_ref()[47], 0x80);
+    assert_eq!(little_endian.into_big_endian(), big_endian);
+
+    let one = I384::<LittleEndian, U32Repr>::one();
+    assert_eq!(one.into_big_endian(), I384::<BigEndian, U32Repr>::one());
+}
+
+#[test]
+fn u384_endianness_roundtrip() {
+    let max = U384::<BigEndian, U32Repr>::max();
+    assert_eq!(max.into_little_endian(), U384::<LittleEndian, U32Repr>::max());
+
+    let two = U384::<LittleEndian, U8Repr>::two().into_big_endian();
+    assert_eq!(two.inner_ref(), U384::<BigEndian, U8Repr>::two().inner_ref());
+    assert_eq!(
+        two.into_little_endian().inner_ref(),
+        U384::<LittleEndian, U8Repr>::two().inner_ref()
+    );
+}
+
+#[test]
+fn u384_checked_add() {
+    let sum = U384::<LittleEndian, U32Repr>::one().checked_add(U384::<LittleEndian, U32Repr>::one());
+    assert_eq!(sum, Some(U384::<LittleEndian, U32Repr>::two()));
+
+    let mut digits = [0u32; 12];
+    digits[0] = u32::max_value();
+    let sum = U384::<LittleEndian, U32Repr>::from_array(digits).checked_add(U384::<LittleEndian, U32Repr>::one());
+    assert_eq!(sum.unwrap().inner_ref()[..2], [0, 1]);
+
+    let mut bytes = [0u8; 48];
+    bytes[47] = u8::max_value();
+    let sum = U384::<BigEndian, U8Repr>::from_array(bytes).checked_add(U384::<BigEndian, U8Repr>::one());
+    assert_eq!(sum.unwrap().inner_ref()[46..], [1, 0]);
+
+    assert!(U384::<LittleEndian, U32Repr>::max()
+        .checked_add(U384::<LittleEndian, U32Repr>::one())
+        .is_none());
+    assert!(U384::<BigEndian, U8Repr>::max()
+        .checked_add(U384::<BigEndian, U8Repr>::one())
+        .is_none());
+}
+
+#[test]
+fn u384_checked_sub() {
+    let difference = U384::<LittleEndian, U32Repr>::two().checked_sub(U384::<LittleEndian, U32Repr>::one());
+    assert_eq!(difference, Some(U384::<LittleEndian, U32Repr>::one()));
+
+    let mut bytes = [0u8; 48];
+    bytes[46] = 1;
+    let difference = U384::<BigEndian, U8Repr>::from_array(bytes).checked_sub(U384::<BigEndian, U8Repr>::one());
+    assert_eq!(difference.unwrap().inner_ref()[46..], [0, u8::max_value()]);
+
+    let difference = U384::<BigEndian, U32Repr>::max().checked_sub(U384::<BigEndian, U32Repr>::max());
+    assert_eq!(
+        difference.unwrap().inner_ref(),
+        U384::<BigEndian, U32Repr>::zero().inner_ref()
+    );
+
+    assert!(U384::<LittleEndian, U32Repr>::zero()
+        .checked_sub(U384::<LittleEndian, U32Repr>::one())
+        .is_none());
+    assert!(U384::<LittleEndian, U8Repr>::one()
+        .checked_sub(U384::<LittleEndian, U8Repr>::two())
+        .is_none());
+}
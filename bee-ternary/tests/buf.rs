@@ -9,7 +9,7 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 
 mod common;
 use self::common::*;
@@ -119,6 +119,65 @@ where
     });
 }
 
+fn to_buf_generic<T: raw::RawEncodingBuf + Clone, U: raw::RawEncodingBuf>()
+where
+    U::Slice: raw::RawEncoding<Trit = <T::Slice as raw::RawEncoding>::Trit>,
+{
+    fuzz(49, || {
+        let a = gen_buf::<T>(1..100).0;
+        let start = thread_rng().gen_range(0, a.len());
+        let end = thread_rng().gen_range(start, a.len() + 1);
+
+        let b = a[start..end].to_buf::<U>();
+
+        assert_eq!(&a[start..end], &*b);
+        assert_eq!(a[start..end].to_buf::<T>(), b);
+    });
+}
+
+fn add_generic<T: raw::RawEncodingBuf>()
+where
+    T::Slice: raw::RawEncoding<Trit = Btrit>,
+{
+    fn value(trits: &[i8]) -> i64 {
+        trits.iter().rev().fold(0, |acc, trit| acc * 3 + *trit as i64)
+    }
+
+    fuzz(100, || {
+        let (mut a, a_trits) = gen_buf::<T>(20..21);
+        let (b, b_trits) = gen_buf::<T>(20..21);
+
+        let carry = a.add_inplace(&b);
+        let sum_trits = a.trits().map(i8::from).collect::<Vec<_>>();
+
+        assert_eq!(
+            value(&sum_trits) + i8::from(carry) as i64 * 3i64.pow(20),
+            value(&a_trits) + value(&b_trits)
+        );
+    });
+}
+
+fn truncate_resize_generic<T: raw::RawEncodingBuf>()
+where
+    T::Slice: raw::RawEncoding<Trit = Btrit>,
+{
+    fuzz(100, || {
+        let (mut a, mut b) = gen_buf::<T>(1..100);
+
+        let len = thread_rng().gen_range(0, b.len());
+        a.truncate(len);
+        b.truncate(len);
+        assert_eq!(a.len(), len);
+        assert_eq!(a, TritBuf::<T>::from_i8_slice(&b).unwrap());
+
+        let len = len + thread_rng().gen_range(1, 100);
+        a.resize(len, Btrit::PlusOne);
+        b.resize(len, 1);
+        assert_eq!(a.len(), len);
+        assert_eq!(a, TritBuf::<T>::from_i8_slice(&b).unwrap());
+    });
+}
+
 #[test]
 fn create() {
     create_generic::<T1B1Buf<Btrit>>();
@@ -175,3 +234,133 @@ fn encode() {
     encode_generic::<T5B1Buf, T2B1Buf>();
     encode_generic::<T5B1Buf, T3B1Buf>();
 }
+
+#[test]
+fn to_buf() {
+    to_buf_generic::<T1B1Buf<Btrit>, T3B1Buf>();
+    to_buf_generic::<T3B1Buf, T1B1Buf<Btrit>>();
+    to_buf_generic::<T3B1Buf, T3B1Buf>();
+    to_buf_generic::<T3B1Buf, T5B1Buf>();
+    to_buf_generic::<T5B1Buf, T3B1Buf>();
+    to_buf_generic::<T5B1Buf, T1B1Buf<Btrit>>();
+}
+
+#[test]
+fn add() {
+    add_generic::<T1B1Buf<Btrit>>();
+    add_generic::<T2B1Buf>();
+    add_generic::<T3B1Buf>();
+    add_generic::<T4B1Buf>();
+    add_generic::<T5B1Buf>();
+}
+
+#[test]
+fn truncate_resize() {
+    truncate_resize_generic::<T1B1Buf<Btrit>>();
+    truncate_resize_generic::<T2B1Buf>();
+    truncate_resize_generic::<T3B1Buf>();
+    truncate_resize_generic::<T4B1Buf>();
+    truncate_resize_generic::<T5B1Buf>();
+}
+
+#[test]
+fn tryte_alphabet() {
+    for (i, c) in "NOPQRSTUVWXYZ9ABCDEFGHIJKLM".chars().enumerate() {
+        let trits = tryte_to_trits(c).unwrap();
+        let value = trits.iter().rev().fold(0i8, |acc, trit| acc * 3 + i8::from(*trit));
+
+        assert!(char_is_tryte(c));
+        assert_eq!(value, i as i8 + MIN_TRYTE_VALUE);
+        assert_eq!(char::from(Tryte::try_from(c).unwrap()), c);
+    }
+
+    for c in "abz08@[ \u{e9}".chars() {
+        assert!(!char_is_tryte(c));
+        assert!(tryte_to_trits(c).is_none());
+        assert!(Tryte::try_from(c).is_err());
+    }
+}
+
+fn as_i8_slice_generic<T: raw::RawEncodingBuf>(trits_per_byte: usize) {
+    fuzz(100, || {
+        let mut a = gen_buf::<T>(0..1000).0;
+        let len = (a.len() + trits_per_byte - 1) / trits_per_byte;
+        assert_eq!(a.as_i8_slice().len(), len);
+        assert_eq!(unsafe { a.as_i8_slice_mut() }.len(), len);
+    });
+}
+
+#[test]
+fn as_i8_slice() {
+    as_i8_slice_generic::<T2B1Buf>(2);
+    as_i8_slice_generic::<T3B1Buf>(3);
+    as_i8_slice_generic::<T4B1Buf>(4);
+}
+
+#[test]
+fn t3b1_as_trytes() {
+    let trytes = TryteBuf::try_from_str("ABZ9NM").unwrap();
+    let buf = trytes.as_trits().encode::<T1B1Buf>().encode::<T3B1Buf>();
+
+    assert_eq!(buf.as_i8_slice().len(), trytes.len());
+    assert_eq!(buf.as_trytes(), &*trytes);
+}
+
+#[test]
+fn t3b1_try_from_raw_range() {
+    assert!(Trits::<T3B1>::try_from_raw(&[-13, 0, 13], 9).is_ok());
+    assert!(Trits::<T3B1>::try_from_raw(&[14], 3).is_err());
+    assert!(Trits::<T3B1>::try_from_raw(&[-14], 3).is_err());
+}
+
+#[test]
+fn from_i8_slice_valid() {
+    let buf = TritBuf::<T1B1Buf>::from_i8_slice(&[-1, 0, 1, 1, 0, -1]).unwrap();
+
+    assert_eq!(buf.len(), 6);
+    assert_eq!(buf.as_i8_slice(), &[-1, 0, 1, 1, 0, -1]);
+}
+
+#[test]
+fn from_i8_slice_invalid() {
+    assert!(TritBuf::<T1B1Buf>::from_i8_slice(&[-1, 0, 2, 1]).is_err());
+}
+
+#[test]
+fn from_bytes_as_trytes() {
+    let buf = TritBuf::<T1B1Buf>::from_bytes_as_trytes(b"Hello World!");
+    let expected = TryteBuf::try_from_str("RBTC9D9DCDEAFCCDFD9DSCFA").unwrap();
+
+    assert_eq!(buf.encode::<T3B1Buf>().as_slice(), expected.as_trits());
+}
+
+#[test]
+fn bytes_from_trytes_roundtrip() {
+    let data = (0..=255).collect::<Vec<u8>>();
+
+    assert_eq!(
+        TritBuf::<T1B1Buf>::from_bytes_as_trytes(&data)
+            .bytes_from_trytes()
+            .unwrap(),
+        data
+    );
+    assert_eq!(
+        TritBuf::<T5B1Buf>::from_bytes_as_trytes(b"IOTA")
+            .bytes_from_trytes()
+            .unwrap(),
+        b"IOTA"
+    );
+}
+
+#[test]
+fn bytes_from_trytes_invalid() {
+    let tryte_trits = |trytes| TryteBuf::try_from_str(trytes).unwrap().as_trits().encode::<T1B1Buf>();
+
+    assert!(matches!(
+        tryte_trits("RBT").bytes_from_trytes(),
+        Err(Error::InvalidLength)
+    ));
+    // 26 + 26 * 27 is above 255.
+    assert!(matches!(tryte_trits("ZZ").bytes_from_trytes(), Err(Error::InvalidRepr)));
+    assert_eq!(tryte_trits("IC").bytes_from_trytes().unwrap(), b"Z");
+}
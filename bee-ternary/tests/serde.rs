@@ -14,9 +14,19 @@
 mod common;
 use self::common::*;
 
-use bee_ternary::{raw::*, *};
+use bee_ternary::{
+    bigint::{
+        common::{BigEndian, LittleEndian, U32Repr, U8Repr},
+        I384, T242, T243, U384,
+    },
+    raw::*,
+    *,
+};
+use rand::prelude::*;
 use serde::{de::DeserializeOwned, *};
 
+use std::ops::Range;
+
 fn serialize_generic<T: raw::RawEncodingBuf>()
 where
     <T::Slice as RawEncoding>::Trit: Serialize,
@@ -69,9 +79,34 @@ where
     );
 }
 
+// Human-readable formats represent unpacked balanced trits as trytes.
+fn gen_trytes(len: Range<usize>) -> (TritBuf<T1B1Buf>, String) {
+    const ALPHABET: &[u8] = b"9ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let len = thread_rng().gen_range(len.start, len.end);
+    let trytes = (0..len)
+        .map(|_| ALPHABET[thread_rng().gen_range(0, ALPHABET.len())] as char)
+        .collect::<String>();
+
+    (TryteBuf::try_from_str(&trytes).unwrap().as_trits().encode(), trytes)
+}
+
+fn serialize_generic_trytes() {
+    let (a, a_trytes) = gen_trytes(0..333);
+    assert_eq!(serde_json::to_string(&a).unwrap(), format!("\"{}\"", a_trytes));
+}
+
+fn deserialize_generic_trytes() {
+    let (a, a_trytes) = gen_trytes(0..333);
+    assert_eq!(
+        serde_json::from_str::<TritBuf<T1B1Buf>>(&format!("\"{}\"", a_trytes)).unwrap(),
+        a
+    );
+}
+
 #[test]
 fn serialize() {
-    serialize_generic::<T1B1Buf<Btrit>>();
+    serialize_generic_trytes();
     serialize_generic_unbalanced::<T1B1Buf<Utrit>>();
     serialize_generic::<T2B1Buf>();
     serialize_generic::<T3B1Buf>();
@@ -81,10 +116,80 @@ fn serialize() {
 
 #[test]
 fn deserialize() {
-    deserialize_generic::<T1B1Buf<Btrit>>();
+    deserialize_generic_trytes();
     deserialize_generic_unbalanced::<T1B1Buf<Utrit>>();
     deserialize_generic::<T2B1Buf>();
     deserialize_generic::<T3B1Buf>();
     deserialize_generic::<T4B1Buf>();
     deserialize_generic::<T5B1Buf>();
 }
+
+#[test]
+fn json_trytes_round_trip() {
+    let buf = TryteBuf::try_from_str("ABZ9NM").unwrap().as_trits().encode::<T1B1Buf>();
+
+    let json = serde_json::to_string(&buf).unwrap();
+    assert_eq!(json, "\"ABZ9NM\"");
+    assert_eq!(serde_json::from_str::<TritBuf<T1B1Buf>>(&json).unwrap(), buf);
+}
+
+#[test]
+fn json_trytes_invalid() {
+    let buf = TritBuf::<T1B1Buf>::from_trits(&[Btrit::PlusOne, Btrit::Zero, Btrit::NegOne, Btrit::PlusOne]);
+
+    assert!(serde_json::to_string(&buf).is_err());
+    assert!(serde_json::from_str::<TritBuf<T1B1Buf>>("\"AB1\"").is_err());
+    assert!(serde_json::from_str::<TritBuf<T1B1Buf>>("\"abc\"").is_err());
+    assert!(serde_json::from_str::<TritBuf<T1B1Buf>>("[1,0,-1]").is_err());
+}
+
+#[test]
+fn binary_keeps_trit_sequence() {
+    let (a, a_i8) = gen_buf::<T1B1Buf>(0..1000);
+
+    assert_eq!(bincode::serialize(&a).unwrap(), bincode::serialize(&a_i8).unwrap());
+    assert_eq!(
+        bincode::deserialize::<TritBuf<T1B1Buf>>(&bincode::serialize(&a).unwrap()).unwrap(),
+        a
+    );
+}
+
+#[test]
+fn bigint_binary_round_trip() {
+    let i384 = I384::<BigEndian, U8Repr>::min();
+    let json = serde_json::to_string(&i384).unwrap();
+    assert_eq!(serde_json::from_str::<I384<BigEndian, U8Repr>>(&json).unwrap(), i384);
+    let bytes = bincode::serialize(&i384).unwrap();
+    assert_eq!(bincode::deserialize::<I384<BigEndian, U8Repr>>(&bytes).unwrap(), i384);
+
+    let u384 = U384::<LittleEndian, U32Repr>::max();
+    let json = serde_json::to_string(&u384).unwrap();
+    assert_eq!(
+        serde_json::from_str::<U384<LittleEndian, U32Repr>>(&json).unwrap(),
+        u384
+    );
+    let bytes = bincode::serialize(&u384).unwrap();
+    assert_eq!(
+        bincode::deserialize::<U384<LittleEndian, U32Repr>>(&bytes).unwrap(),
+        u384
+    );
+
+    assert!(serde_json::from_str::<U384<LittleEndian, U32Repr>>("[1,2,3]").is_err());
+}
+
+#[test]
+fn bigint_ternary_round_trip() {
+    let t242 = T242::<Btrit>::from_i384_ignoring_mst(I384::<BigEndian, U8Repr>::min());
+    let json = serde_json::to_string(&t242).unwrap();
+    assert_eq!(serde_json::from_str::<T242<Btrit>>(&json).unwrap(), t242);
+    let bytes = bincode::serialize(&t242).unwrap();
+    assert_eq!(bincode::deserialize::<T242<Btrit>>(&bytes).unwrap(), t242);
+
+    let t243 = t242.into_t243();
+    let json = serde_json::to_string(&t243).unwrap();
+    assert_eq!(serde_json::from_str::<T243<Btrit>>(&json).unwrap(), t243);
+    let bytes = bincode::serialize(&t243).unwrap();
+    assert_eq!(bincode::deserialize::<T243<Btrit>>(&bytes).unwrap(), t243);
+
+    assert!(serde_json::from_str::<T243<Btrit>>("\"ABC\"").is_err());
+}
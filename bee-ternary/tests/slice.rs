@@ -14,6 +14,7 @@ use std::convert::{TryFrom, TryInto};
 mod common;
 use self::common::*;
 
+use bee_ternary::raw::RawEncoding;
 use bee_ternary::*;
 use rand::prelude::*;
 
@@ -196,6 +197,28 @@ fn set_panic_generic_unbalanced<T: raw::RawEncodingBuf + Clone>() {
     a.set(len, <T::Slice as raw::RawEncoding>::Trit::zero());
 }
 
+fn raw_get_and_slice_generic<T: raw::RawEncodingBuf>() {
+    fuzz(100, || {
+        let trits = gen_buf::<T>(1..1000).0.trits().collect::<Vec<_>>();
+        let buf = T::from_trits(&trits);
+        let raw = buf.as_slice();
+
+        let index = thread_rng().gen_range(0, trits.len());
+        assert_eq!(raw.get(index), Some(trits[index]));
+        assert_eq!(raw.get(trits.len()), None);
+
+        let start = thread_rng().gen_range(0, trits.len());
+        let end = thread_rng().gen_range(start, trits.len() + 1);
+        let slice = raw.slice(start..end).unwrap();
+        assert_eq!(slice.len(), end - start);
+        if end > start {
+            assert_eq!(slice.get(0), Some(trits[start]));
+        }
+
+        assert!(raw.slice(start..trits.len() + 1).is_none());
+    });
+}
+
 #[test]
 fn get() {
     get_generic::<T1B1Buf<Btrit>>();
@@ -206,6 +229,15 @@ fn get() {
     get_generic::<T5B1Buf>();
 }
 
+#[test]
+fn raw_get_and_slice() {
+    raw_get_and_slice_generic::<T1B1Buf<Btrit>>();
+    raw_get_and_slice_generic::<T2B1Buf>();
+    raw_get_and_slice_generic::<T3B1Buf>();
+    raw_get_and_slice_generic::<T4B1Buf>();
+    raw_get_and_slice_generic::<T5B1Buf>();
+}
+
 #[test]
 fn set() {
     set_generic::<T1B1Buf<Btrit>>();
@@ -252,3 +284,19 @@ fn chunks_mut() {
         }
     });
 }
+
+#[test]
+fn to_i8_vec() {
+    let trits = [-1, 0, 1, 1, -1, 0, 1];
+
+    let t1b1 = TritBuf::<T1B1Buf>::from_i8_slice(&trits).unwrap();
+    assert_eq!(t1b1.to_i8_vec(), trits);
+    assert_eq!(t1b1.to_i8_vec(), t1b1.as_i8_slice());
+
+    let t5b1 = TritBuf::<T5B1Buf>::from_i8_slice(&trits).unwrap();
+    assert_eq!(t5b1.to_i8_vec(), trits);
+    assert_eq!(t5b1.as_i8_slice().len(), 2);
+    assert_ne!(t5b1.to_i8_vec(), t5b1.as_i8_slice());
+
+    assert_eq!(t5b1[1..6].to_i8_vec(), &trits[1..6]);
+}
@@ -0,0 +1,77 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use bee_ternary::{
+    t5b1::{pack_trits, unpack_trits},
+    Btrit, Error, T1B1Buf, T5B1Buf, TritBuf,
+};
+
+#[test]
+fn pack_unpack_roundtrip() {
+    let trits = [
+        Btrit::PlusOne,
+        Btrit::NegOne,
+        Btrit::Zero,
+        Btrit::PlusOne,
+        Btrit::PlusOne,
+        Btrit::NegOne,
+        Btrit::Zero,
+    ];
+
+    let bytes = pack_trits(&trits);
+
+    assert_eq!(bytes.len(), 2);
+    assert_eq!(bytes, TritBuf::<T5B1Buf>::from_trits(&trits).as_i8_slice());
+    assert_eq!(unpack_trits(&bytes, trits.len()).unwrap(), trits);
+}
+
+#[test]
+fn pack_max_and_min() {
+    assert_eq!(pack_trits(&[Btrit::PlusOne; 5]), vec![121]);
+    assert_eq!(pack_trits(&[Btrit::NegOne; 5]), vec![-121]);
+    assert!(pack_trits(&[]).is_empty());
+}
+
+#[test]
+fn unpack_out_of_range_byte_fails() {
+    assert!(matches!(unpack_trits(&[0, 122], 10), Err(Error::InvalidRepr)));
+    assert!(matches!(unpack_trits(&[-122], 5), Err(Error::InvalidRepr)));
+}
+
+#[test]
+fn unpack_too_many_trits_fails() {
+    assert!(matches!(unpack_trits(&[0, 0], 11), Err(Error::InvalidLength)));
+}
+
+#[test]
+fn eq_across_offsets() {
+    let trits = (0..23)
+        .map(|i| match i % 3 {
+            0 => Btrit::NegOne,
+            1 => Btrit::PlusOne,
+            _ => Btrit::Zero,
+        })
+        .collect::<Vec<_>>();
+    let buf = TritBuf::<T5B1Buf>::from_trits(&trits);
+
+    for start in 0..8 {
+        for end in start..trits.len() {
+            let view = &buf[start..end];
+            let fresh = TritBuf::<T5B1Buf>::from_trits(&trits[start..end]);
+
+            assert_eq!(view, fresh.as_slice());
+            assert_eq!(view, TritBuf::<T1B1Buf>::from_trits(&trits[start..end]).as_slice());
+        }
+    }
+
+    assert_ne!(&buf[1..6], &buf[0..5]);
+    assert_ne!(&buf[0..5], &buf[0..6]);
+}
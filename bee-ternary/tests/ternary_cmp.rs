@@ -0,0 +1,59 @@
+// Copyright 2020 IOTA Stiftung
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except in compliance with
+// the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+// an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations under the License.
+
+use std::collections::BTreeMap;
+
+use bee_ternary::{
+    bigint::{T242, T243},
+    Btrit, Utrit,
+};
+
+#[test]
+fn t243_btrit_ordering() {
+    assert!(T243::<Btrit>::neg_one() < T243::<Btrit>::zero());
+    assert!(T243::<Btrit>::zero() < T243::<Btrit>::one());
+    assert!(T243::<Btrit>::neg_two() < T243::<Btrit>::neg_one());
+    // `two` is encoded as `[-1, 1, 0, ...]`, so it would be smaller than `one` in lexicographic trit order.
+    assert!(T243::<Btrit>::one() < T243::<Btrit>::two());
+    assert!(T243::<Btrit>::min() < T243::<Btrit>::neg_two());
+    assert!(T243::<Btrit>::two() < T243::<Btrit>::max());
+    assert_eq!(
+        T243::<Btrit>::one().cmp(&T243::<Btrit>::one()),
+        std::cmp::Ordering::Equal
+    );
+}
+
+#[test]
+fn t242_btrit_ordering() {
+    assert!(T242::<Btrit>::neg_one() < T242::<Btrit>::zero());
+    assert!(T242::<Btrit>::zero() < T242::<Btrit>::one());
+    assert!(T242::<Btrit>::one() < T242::<Btrit>::two());
+    assert!(T242::<Btrit>::min() < T242::<Btrit>::max());
+}
+
+#[test]
+fn t243_utrit_ordering() {
+    assert!(T243::<Utrit>::zero() < T243::<Utrit>::one());
+    assert!(T243::<Utrit>::one() < T243::<Utrit>::two());
+    assert!(T243::<Utrit>::two() < T243::<Utrit>::half_max());
+    assert!(T243::<Utrit>::half_max() < T243::<Utrit>::max());
+}
+
+#[test]
+fn t243_as_btree_map_key() {
+    let mut map = BTreeMap::new();
+    map.insert(T243::<Btrit>::two(), 2);
+    map.insert(T243::<Btrit>::neg_one(), -1);
+    map.insert(T243::<Btrit>::one(), 1);
+    map.insert(T243::<Btrit>::zero(), 0);
+
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![-1, 0, 1, 2]);
+}
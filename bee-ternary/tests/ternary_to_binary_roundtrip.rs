@@ -124,3 +124,22 @@ ternary_roundtrip!(
     [two_is_two, two],
     [max_is_max, max],
 );
+
+#[test]
+fn t242_t243_roundtrip() {
+    let original = T242::<Btrit>::min();
+    let roundtripped = original.clone().into_t243().try_into_t242();
+    assert!(roundtripped.is_ok());
+    assert_eq!(roundtripped.unwrap(), original);
+
+    let original = T242::<Utrit>::max();
+    let roundtripped = original.clone().into_t243().try_into_t242();
+    assert!(roundtripped.is_ok());
+    assert_eq!(roundtripped.unwrap(), original);
+}
+
+#[test]
+fn t243_with_non_zero_mst_into_t242_fails() {
+    assert!(T243::<Btrit>::max().try_into_t242().is_err());
+    assert!(T243::<Utrit>::max().try_into_t242().is_err());
+}
@@ -55,3 +55,35 @@ fn convert_incorrect_2() {
 fn convert_incorrect_3() {
     Btrit::try_from(2i8).unwrap();
 }
+
+#[test]
+fn add_balanced_exhaustive() {
+    let trits = [Btrit::NegOne, Btrit::Zero, Btrit::PlusOne];
+    for &a in trits.iter() {
+        for &b in trits.iter() {
+            for &carry in trits.iter() {
+                let (sum, next_carry) = a.add(b, carry);
+                assert_eq!(
+                    i8::from(sum) + 3 * i8::from(next_carry),
+                    i8::from(a) + i8::from(b) + i8::from(carry)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn add_unbalanced_exhaustive() {
+    let trits = [Utrit::Zero, Utrit::One, Utrit::Two];
+    for &a in trits.iter() {
+        for &b in trits.iter() {
+            for &carry in trits.iter() {
+                let (sum, next_carry) = a.add(b, carry);
+                assert_eq!(
+                    i8::from(sum) + 3 * i8::from(next_carry),
+                    i8::from(a) + i8::from(b) + i8::from(carry)
+                );
+            }
+        }
+    }
+}
@@ -9,8 +9,8 @@
 // an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and limitations under the License.
 
-use bee_bundle::TransactionField;
-use bee_ternary::{T1B1Buf, TritBuf, Trits, T1B1};
+use bee_bundle::{Address, TransactionField};
+use bee_ternary::{Btrit, T1B1Buf, TritBuf, Trits, T1B1};
 
 use rand::Rng;
 
@@ -35,3 +35,10 @@ where
         .to_buf::<T1B1Buf>();
     T::from_inner_unchecked(trits)
 }
+
+/// Generates a random address that can be used in value transactions, i.e. whose last trit is zero.
+pub fn rand_value_address() -> Address {
+    let mut trits = rand_trits_field::<Address>().to_inner().to_buf::<T1B1Buf>();
+    trits.set(Address::trit_len() - 1, Btrit::Zero);
+    Address::from_inner_unchecked(trits)
+}
@@ -57,6 +57,52 @@ pub fn create_random_tx() -> (bee_bundle::Hash, bee_bundle::Transaction) {
     (rand_trits_field::<Hash>(), builder.build().unwrap())
 }
 
+/// Creates a bundle of `length` random transactions, from tail to head, each linked to the next one by its trunk.
+pub fn create_random_bundle(length: usize) -> Vec<(Hash, Transaction)> {
+    let entries = (0..length)
+        .map(|_| (rand_trits_field::<Address>(), 0))
+        .collect::<Vec<(Address, i64)>>();
+
+    create_random_value_bundle(&entries)
+}
+
+/// Creates a bundle of random transactions with the given addresses and values, from tail to head, each linked to the
+/// next one by its trunk.
+pub fn create_random_value_bundle(entries: &[(Address, i64)]) -> Vec<(Hash, Transaction)> {
+    let length = entries.len();
+    let bundle = rand_trits_field::<Hash>();
+    let hashes = (0..length).map(|_| rand_trits_field::<Hash>()).collect::<Vec<Hash>>();
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, (address, value))| {
+            let trunk = match hashes.get(index + 1) {
+                Some(next) => *next,
+                None => rand_trits_field::<Hash>(),
+            };
+            let builder = TransactionBuilder::new()
+                .with_payload(rand_trits_field::<Payload>())
+                .with_address(address.clone())
+                .with_value(Value::from_inner_unchecked(*value))
+                .with_obsolete_tag(rand_trits_field::<Tag>())
+                .with_timestamp(Timestamp::from_inner_unchecked(0))
+                .with_index(Index::from_inner_unchecked(index))
+                .with_last_index(Index::from_inner_unchecked(length - 1))
+                .with_tag(rand_trits_field::<Tag>())
+                .with_attachment_ts(Timestamp::from_inner_unchecked(0))
+                .with_bundle(bundle)
+                .with_trunk(trunk)
+                .with_branch(rand_trits_field::<Hash>())
+                .with_attachment_lbts(Timestamp::from_inner_unchecked(0))
+                .with_attachment_ubts(Timestamp::from_inner_unchecked(0))
+                .with_nonce(rand_trits_field::<Nonce>());
+
+            (hashes[index], builder.build().unwrap())
+        })
+        .collect()
+}
+
 pub fn create_random_attached_tx(branch: Hash, trunk: Hash) -> (Hash, Transaction) {
     let builder = TransactionBuilder::new()
         .with_payload(rand_trits_field::<Payload>())